@@ -0,0 +1,42 @@
+//! Generates `preflop::EQUITY_VS_RANDOM` by exact enumeration
+//!
+//! A canonical hand's equity against one random hand is the mean of its
+//! all-in equities against each of the 1,225 possible opposing combos, every
+//! one computed over all 1,712,304 boards. Suit relabelling makes every
+//! combo of a canonical hand equivalent, so one combo of each is enumerated.
+//!
+//! Run in release mode; a full run takes about half an hour on one core:
+//!
+//! ```text
+//! cargo run --release --example equity_vs_random
+//! ```
+
+mod exact;
+
+use exact::{canonical_hands, Matchups};
+use holdem_core::Evaluator;
+
+fn main() {
+    let matchups = Matchups::new(&Evaluator::instance());
+
+    let mut equities = Vec::new();
+    for hole in canonical_hands() {
+        let against = matchups.against_every_combo(&hole);
+        let equity = against.iter().map(|(_, equity)| equity).sum::<f64>() / against.len() as f64;
+        let notation = hole.notation();
+        eprintln!("{notation}: {equity:.6}");
+        equities.push((notation, equity));
+    }
+
+    equities.sort_by(|a, b| b.1.total_cmp(&a.1));
+    println!("#[rustfmt::skip]");
+    println!("pub const EQUITY_VS_RANDOM: [(&str, f64); CANONICAL_HAND_COUNT] = [");
+    for row in equities.chunks(5) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|(hand, equity)| format!("(\"{hand}\", {equity:.4})"))
+            .collect();
+        println!("    {},", cells.join(", "));
+    }
+    println!("];");
+}
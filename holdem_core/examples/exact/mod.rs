@@ -0,0 +1,260 @@
+//! Exact heads-up preflop equities, shared by the table generators
+//!
+//! Every matchup is enumerated over all 1,712,304 boards. Hand values come
+//! from the crate's evaluator, tabulated by rank multiset and by flush rank
+//! mask so that the enumeration itself only does table lookups.
+
+use holdem_core::hole_cards::HoleCards;
+use holdem_core::{Card, Evaluator};
+use std::collections::HashMap;
+
+/// Counts of each rank packed in base 5, ranks 2 to A from the low digit
+type Multiset = u64;
+
+/// Hand values of rank multisets, reached by adding one rank at a time
+struct RankTables {
+    /// `next[state * 13 + rank]`: the state after adding a card of `rank`
+    next: Vec<u32>,
+    /// Best non-flush value of each seven-card state
+    value: Vec<u32>,
+    /// Best flush value of every 13-bit rank mask with five or more ranks
+    flush: Vec<u32>,
+}
+
+fn build_tables(evaluator: &Evaluator) -> RankTables {
+    let mut ids: HashMap<Multiset, u32> = HashMap::new();
+    let mut states: Vec<(Multiset, [u8; 13], usize)> = Vec::new();
+    ids.insert(0, 0);
+    states.push((0, [0; 13], 0));
+
+    // Breadth first, so every state's successors are numbered after it
+    let mut next = Vec::new();
+    let mut index = 0;
+    while index < states.len() {
+        let (key, counts, size) = states[index];
+        for rank in 0..13 {
+            if size == 7 || counts[rank] == 4 {
+                next.push(u32::MAX);
+                continue;
+            }
+            let successor = key + 5u64.pow(rank as u32);
+            let id = *ids.entry(successor).or_insert_with(|| {
+                let mut counts = counts;
+                counts[rank] += 1;
+                states.push((successor, counts, size + 1));
+                (states.len() - 1) as u32
+            });
+            next.push(id);
+        }
+        index += 1;
+    }
+
+    // Suits are spread so that no five share one, leaving the best
+    // non-flush hand
+    let value = states
+        .iter()
+        .map(|(_, counts, size)| {
+            if *size < 7 {
+                return 0;
+            }
+            let mut used = [0usize; 4];
+            let mut cards = Vec::with_capacity(7);
+            for (rank, &count) in counts.iter().enumerate() {
+                let mut suits: Vec<usize> = (0..4).collect();
+                suits.sort_by_key(|&suit| used[suit]);
+                for &suit in &suits[..count as usize] {
+                    used[suit] += 1;
+                    cards.push(Card::new(rank as u8, suit as u8).unwrap());
+                }
+            }
+            evaluator.evaluate_cards(&cards).as_u32()
+        })
+        .collect();
+
+    let flush = (0..1u32 << 13)
+        .map(|mask| {
+            let ranks: Vec<u8> = (0..13u8).filter(|rank| mask & 1 << rank != 0).collect();
+            if ranks.len() < 5 {
+                return 0;
+            }
+            let mut best = 0;
+            for skip in 0..1u32 << ranks.len() {
+                if skip.count_ones() as usize != ranks.len() - 5 {
+                    continue;
+                }
+                let five: Vec<Card> = ranks
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| skip & 1 << i == 0)
+                    .map(|(_, &rank)| Card::new(rank, 0).unwrap())
+                    .collect();
+                best = best.max(evaluator.evaluate_cards(&five).as_u32());
+            }
+            best
+        })
+        .collect();
+
+    RankTables { next, value, flush }
+}
+
+/// Wins plus half the ties of `hero` against `villain` over every board
+fn exact_equity(tables: &RankTables, hero: [usize; 2], villain: [usize; 2]) -> f64 {
+    let live: Vec<usize> = (0..52)
+        .filter(|card| !hero.contains(card) && !villain.contains(card))
+        .collect();
+    let step = |state: u32, card: usize| tables.next[state as usize * 13 + card / 4];
+    let start = |cards: [usize; 2]| step(step(0, cards[0]), cards[1]);
+    let suit_mask = |cards: [usize; 2], suit: usize| {
+        cards
+            .iter()
+            .filter(|&&card| card % 4 == suit)
+            .fold(0u32, |mask, card| mask | 1 << (card / 4))
+    };
+    let hero_masks: [u32; 4] = std::array::from_fn(|suit| suit_mask(hero, suit));
+    let villain_masks: [u32; 4] = std::array::from_fn(|suit| suit_mask(villain, suit));
+
+    // Board ranks per suit, 16 bits each
+    let bit = |card: usize| 1u64 << ((card % 4) * 16 + card / 4);
+    let (mut points, mut total) = (0u64, 0u64);
+    let n = live.len();
+    let (h0, v0) = (start(hero), start(villain));
+    for a in 0..n {
+        let (ca, ha, va) = (live[a], step(h0, live[a]), step(v0, live[a]));
+        for b in a + 1..n {
+            let (cb, hb, vb) = (live[b], step(ha, live[b]), step(va, live[b]));
+            for c in b + 1..n {
+                let (cc, hc, vc) = (live[c], step(hb, live[c]), step(vb, live[c]));
+                let three = bit(ca) | bit(cb) | bit(cc);
+                for d in c + 1..n {
+                    let (hd, vd) = (step(hc, live[d]), step(vc, live[d]));
+                    let four = three | bit(live[d]);
+                    for &ce in &live[d + 1..] {
+                        let board = four | bit(ce);
+                        let mut hero_value = tables.value[step(hd, ce) as usize];
+                        let mut villain_value = tables.value[step(vd, ce) as usize];
+                        for suit in 0..4 {
+                            let suited = (board >> (suit * 16)) as u32 & 0x1FFF;
+                            if suited.count_ones() >= 3 {
+                                let hero_flush = suited | hero_masks[suit];
+                                if hero_flush.count_ones() >= 5 {
+                                    hero_value = hero_value.max(tables.flush[hero_flush as usize]);
+                                }
+                                let villain_flush = suited | villain_masks[suit];
+                                if villain_flush.count_ones() >= 5 {
+                                    villain_value =
+                                        villain_value.max(tables.flush[villain_flush as usize]);
+                                }
+                            }
+                        }
+                        points += match hero_value.cmp(&villain_value) {
+                            std::cmp::Ordering::Greater => 2,
+                            std::cmp::Ordering::Equal => 1,
+                            std::cmp::Ordering::Less => 0,
+                        };
+                        total += 2;
+                    }
+                }
+            }
+        }
+    }
+    points as f64 / total as f64
+}
+
+/// All 24 ways to relabel the four suits
+fn suit_permutations() -> Vec<[usize; 4]> {
+    let mut permutations = Vec::new();
+    for a in 0..4 {
+        for b in (0..4).filter(|&b| b != a) {
+            for c in (0..4).filter(|&c| c != a && c != b) {
+                permutations.push([a, b, c, 6 - a - b - c]);
+            }
+        }
+    }
+    permutations
+}
+
+/// Deck index of a card, rank major as in the crate's card masks
+fn index(card: &Card) -> usize {
+    card.rank as usize * 4 + card.suit as usize
+}
+
+fn card(index: usize) -> Card {
+    Card::new((index / 4) as u8, (index % 4) as u8).unwrap()
+}
+
+/// Exact all-in equities between specific starting hands
+pub struct Matchups {
+    tables: RankTables,
+    permutations: Vec<[usize; 4]>,
+}
+
+impl Matchups {
+    pub fn new(evaluator: &Evaluator) -> Self {
+        Self {
+            tables: build_tables(evaluator),
+            permutations: suit_permutations(),
+        }
+    }
+
+    /// Equity of `hole` against each of the 1,225 combos it leaves, in deck
+    /// order
+    pub fn against_every_combo(&self, hole: &HoleCards) -> Vec<(HoleCards, f64)> {
+        let hero = [index(&hole.cards[0]), index(&hole.cards[1])];
+        let relabel = |card: usize, permutation: &[usize; 4]| card / 4 * 4 + permutation[card % 4];
+
+        // Relabellings that fix the hero's cards give villain combos of
+        // equal equity, so each class is enumerated once
+        let symmetries: Vec<&[usize; 4]> = self
+            .permutations
+            .iter()
+            .filter(|permutation| {
+                let mut mapped = hero.map(|card| relabel(card, permutation));
+                mapped.sort_unstable();
+                let mut sorted = hero;
+                sorted.sort_unstable();
+                mapped == sorted
+            })
+            .collect();
+
+        let mut cache: HashMap<[usize; 2], f64> = HashMap::new();
+        let mut equities = Vec::with_capacity(1225);
+        for first in 0..52 {
+            for second in first + 1..52 {
+                if hero.contains(&first) || hero.contains(&second) {
+                    continue;
+                }
+                let canonical = symmetries
+                    .iter()
+                    .map(|permutation| {
+                        let mut combo = [relabel(first, permutation), relabel(second, permutation)];
+                        combo.sort_unstable();
+                        combo
+                    })
+                    .min()
+                    .unwrap();
+                let equity = *cache
+                    .entry(canonical)
+                    .or_insert_with(|| exact_equity(&self.tables, hero, canonical));
+                let villain = HoleCards::new(card(first), card(second)).unwrap();
+                equities.push((villain, equity));
+            }
+        }
+        equities
+    }
+}
+
+/// One combo of each of the 169 canonical hands, by high card then low
+/// card from AA down to 22
+pub fn canonical_hands() -> Vec<HoleCards> {
+    let mut hands = Vec::new();
+    for high in (0..13u8).rev() {
+        for low in (0..=high).rev() {
+            let high_card = Card::new(high, 0).unwrap();
+            if low != high {
+                hands.push(HoleCards::new(high_card, Card::new(low, 0).unwrap()).unwrap());
+            }
+            hands.push(HoleCards::new(high_card, Card::new(low, 1).unwrap()).unwrap());
+        }
+    }
+    hands
+}
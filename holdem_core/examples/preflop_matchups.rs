@@ -0,0 +1,82 @@
+//! Generates `src/pushfold/matchups.rs`, the exact equity between every
+//! pair of canonical hands
+//!
+//! The equity of one canonical hand against another is the mean of the
+//! all-in equities over every pair of their combos that share no card, each
+//! computed over all 1,712,304 boards. Suit relabelling makes every combo of
+//! the first hand equivalent, so one combo of each is enumerated against
+//! every opposing combo.
+//!
+//! Run in release mode; a full run takes about half an hour on one core:
+//!
+//! ```text
+//! cargo run --release --example preflop_matchups > holdem_core/src/pushfold/matchups.rs
+//! ```
+
+mod exact;
+
+use exact::{canonical_hands, Matchups};
+use holdem_core::Evaluator;
+
+fn main() {
+    let matchups = Matchups::new(&Evaluator::instance());
+    let hands: Vec<String> = canonical_hands()
+        .iter()
+        .map(|hole| hole.notation())
+        .collect();
+    let n = hands.len();
+
+    let mut equity = vec![0.0; n * n];
+    for (i, hole) in canonical_hands().iter().enumerate() {
+        let mut points = vec![0.0; n];
+        let mut combos = vec![0usize; n];
+        for (villain, share) in matchups.against_every_combo(hole) {
+            let j = hands
+                .iter()
+                .position(|hand| *hand == villain.notation())
+                .unwrap();
+            points[j] += share;
+            combos[j] += 1;
+        }
+        for j in 0..n {
+            equity[i * n + j] = points[j] / combos[j] as f64;
+        }
+        eprintln!("{}: {} of {}", hands[i], i + 1, n);
+    }
+
+    // Both sides of a matchup were enumerated, so they must agree
+    for i in 0..n {
+        for j in 0..n {
+            let sum = equity[i * n + j] + equity[j * n + i];
+            assert!((sum - 1.0).abs() < 1e-9, "{} vs {}", hands[i], hands[j]);
+        }
+    }
+
+    println!("//! Exact all-in equities between the 169 canonical hands");
+    println!("//!");
+    println!("//! Generated by `examples/preflop_matchups.rs`; do not edit by hand.");
+    println!();
+    println!("/// The canonical hands, in the order the equity table is indexed");
+    println!("#[rustfmt::skip]");
+    println!("pub(super) const HANDS: [&str; 169] = [");
+    for row in hands.chunks(13) {
+        let cells: Vec<String> = row.iter().map(|hand| format!("\"{hand}\"")).collect();
+        println!("    {},", cells.join(", "));
+    }
+    println!("];");
+    println!();
+    println!("/// Equity of `HANDS[i]` against `HANDS[j]` for every `i < j`, row by");
+    println!("/// row, rounded to four decimals; the reverse matchup is one minus it");
+    println!("#[rustfmt::skip]");
+    println!("#[allow(clippy::approx_constant)]");
+    println!("pub(super) static EQUITY: [f64; 169 * 168 / 2] = [");
+    for i in 0..n {
+        let row: Vec<String> = (i + 1..n)
+            .map(|j| format!("{:.4}", equity[i * n + j]))
+            .collect();
+        for line in row.chunks(12) {
+            println!("    {},", line.join(", "));
+        }
+    }
+    println!("];");
+}
@@ -0,0 +1,101 @@
+//! Generates `preflop::SKLANSKY_CHUBUKOV` by exact enumeration
+//!
+//! For every canonical hand, the all-in equity against each of the 1,225
+//! possible opposing combos is computed over all 1,712,304 boards, then the
+//! Sklansky-Chubukov number is solved from those equities.
+//!
+//! Run in release mode; a full run takes about half an hour on one core:
+//!
+//! ```text
+//! cargo run --release --example sklansky_chubukov
+//! ```
+
+mod exact;
+
+use exact::{canonical_hands, Matchups};
+use holdem_core::Evaluator;
+
+/// Largest stack, in big blinds, at which shoving beats folding
+///
+/// The small blind has posted 0.5 and the big blind 1. Facing the shove,
+/// the big blind calls a combo exactly when calling loses less than the
+/// posted blind, that is when the pusher's equity `e` satisfies
+/// `(1 - e) * 2S - S >= -1`. Compared with folding, the shove gains 1.5
+/// when the big blind folds and `e * 2S - S + 0.5` when called.
+fn sklansky_chubukov(equities: &[f64]) -> f64 {
+    let gain = |stack: f64| -> f64 {
+        equities
+            .iter()
+            .map(|&e| {
+                if (1.0 - e) * 2.0 * stack - stack >= -1.0 {
+                    e * 2.0 * stack - stack + 0.5
+                } else {
+                    1.5
+                }
+            })
+            .sum()
+    };
+
+    // A combo the pusher is ahead of is called only up to 1 / (2e - 1)
+    // big blinds, where its gain reaches the 1.5 of a fold. The total is
+    // therefore continuous and linear between those breakpoints.
+    let mut points: Vec<f64> = equities
+        .iter()
+        .filter(|&&e| e > 0.5)
+        .map(|&e| 1.0 / (2.0 * e - 1.0))
+        .collect();
+    points.push(0.0);
+    points.sort_by(f64::total_cmp);
+    points.dedup();
+
+    let top = points[points.len() - 1];
+    let tail = gain(top + 1.0) - gain(top);
+    if tail > 0.0 || (tail >= 0.0 && gain(top) >= 0.0) {
+        return f64::INFINITY;
+    }
+    if gain(top) >= 0.0 {
+        return top + gain(top) / -tail;
+    }
+    for pair in points.windows(2).rev() {
+        let (lo, hi) = (gain(pair[0]), gain(pair[1]));
+        if lo >= 0.0 {
+            return pair[0] + (pair[1] - pair[0]) * lo / (lo - hi);
+        }
+    }
+    0.0
+}
+
+fn main() {
+    let matchups = Matchups::new(&Evaluator::instance());
+
+    let mut numbers = Vec::new();
+    for hole in canonical_hands() {
+        let equities: Vec<f64> = matchups
+            .against_every_combo(&hole)
+            .into_iter()
+            .map(|(_, equity)| equity)
+            .collect();
+        let notation = hole.notation();
+        let number = sklansky_chubukov(&equities);
+        eprintln!("{notation}: {number:.2}");
+        numbers.push((notation, number));
+    }
+
+    numbers.sort_by(|a, b| b.1.total_cmp(&a.1));
+    println!("#[rustfmt::skip]");
+    println!("pub const SKLANSKY_CHUBUKOV: [(&str, f64); CANONICAL_HAND_COUNT] = [");
+    for row in numbers.chunks(5) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|(hand, number)| {
+                if number.is_infinite() {
+                    format!("(\"{hand}\", f64::INFINITY)")
+                } else {
+                    format!("(\"{hand}\", {number:.1})")
+                }
+            })
+            .collect();
+        println!("    {},", cells.join(", "));
+    }
+    println!("];");
+}
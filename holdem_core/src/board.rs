@@ -49,9 +49,13 @@
 //! ```
 
 use crate::card::Card;
+use crate::equity::{for_each_runout, live_cards};
 use crate::errors::PokerError;
+use crate::evaluator::{Evaluator, HandValue};
+use crate::hole_cards::HoleCards;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents the current betting street in Texas Hold'em
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -67,6 +71,53 @@ pub enum Street {
     River,
 }
 
+impl Street {
+    /// Every street in dealing order, from preflop to river
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Street;
+    ///
+    /// let streets: Vec<Street> = Street::all().collect();
+    /// assert_eq!(streets.first(), Some(&Street::Preflop));
+    /// assert_eq!(streets.len(), 4);
+    /// ```
+    pub fn all() -> impl Iterator<Item = Street> {
+        [Street::Preflop, Street::Flop, Street::Turn, Street::River].into_iter()
+    }
+
+    /// The street dealt after this one, or `None` after the river
+    pub fn next(&self) -> Option<Street> {
+        match self {
+            Street::Preflop => Some(Street::Flop),
+            Street::Flop => Some(Street::Turn),
+            Street::Turn => Some(Street::River),
+            Street::River => None,
+        }
+    }
+
+    /// The street dealt before this one, or `None` before the flop
+    pub fn prev(&self) -> Option<Street> {
+        match self {
+            Street::Preflop => None,
+            Street::Flop => Some(Street::Preflop),
+            Street::Turn => Some(Street::Flop),
+            Street::River => Some(Street::Turn),
+        }
+    }
+
+    /// Number of community cards on the board once this street is dealt
+    pub fn cards_dealt(&self) -> usize {
+        match self {
+            Street::Preflop => 0,
+            Street::Flop => 3,
+            Street::Turn => 4,
+            Street::River => 5,
+        }
+    }
+}
+
 impl fmt::Display for Street {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -410,12 +461,272 @@ impl Board {
     ///
     /// This method does not panic.
     pub fn cards_at_street(&self, street: Street) -> &[Card] {
-        match street {
-            Street::Preflop => &[],
-            Street::Flop => &self.cards[..3.min(self.cards.len())],
-            Street::Turn => &self.cards[..4.min(self.cards.len())],
-            Street::River => &self.cards,
+        &self.cards[..street.cards_dealt().min(self.cards.len())]
+    }
+
+    /// Returns the community cards in the order they were revealed
+    ///
+    /// Each card is labelled with the street it was dealt on, which lets
+    /// hand-history exporters reproduce "Turn: X, River: Y" exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{board::Board, Card, Street};
+    /// use std::str::FromStr;
+    ///
+    /// let board = Board::new()
+    ///     .with_flop([
+    ///         Card::from_str("As").unwrap(),
+    ///         Card::from_str("Kd").unwrap(),
+    ///         Card::from_str("Qh").unwrap(),
+    ///     ])
+    ///     .unwrap()
+    ///     .with_turn(Card::from_str("Js").unwrap())
+    ///     .unwrap();
+    ///
+    /// let order = board.dealt_community_in_order();
+    /// assert_eq!(order[3], (Street::Turn, Card::from_str("Js").unwrap()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn dealt_community_in_order(&self) -> Vec<(Street, Card)> {
+        self.cards
+            .iter()
+            .enumerate()
+            .map(|(i, &card)| {
+                let street = Street::all()
+                    .find(|street| i < street.cards_dealt())
+                    .unwrap_or(Street::River);
+                (street, card)
+            })
+            .collect()
+    }
+
+    /// Returns every unseen card that would complete a flush or straight
+    ///
+    /// A card completes a flush when the board already shows two or more of
+    /// its suit, so two suited hole cards make a flush once it lands. It
+    /// completes a straight when, together with the board, it puts three
+    /// ranks inside some five-rank straight window (the wheel included), so
+    /// two hole cards can fill the rest. Cards on the board or in `dead` are
+    /// skipped. Only the flop and turn have a next community card; other
+    /// streets return an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{board::Board, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let board = Board::new()
+    ///     .with_flop(["9h", "8h", "2c"].map(|c| Card::from_str(c).unwrap()))
+    ///     .unwrap();
+    /// let danger = board.cards_that_complete_draws(&[]);
+    /// assert!(danger.contains(&Card::from_str("Ah").unwrap()));
+    /// assert!(danger.contains(&Card::from_str("7c").unwrap()));
+    /// assert!(!danger.contains(&Card::from_str("Kc").unwrap()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn cards_that_complete_draws(&self, dead: &[Card]) -> Vec<Card> {
+        if !matches!(self.street(), Street::Flop | Street::Turn) {
+            return Vec::new();
+        }
+
+        let mut suit_counts = [0; 4];
+        let mut rank_mask = 0u16;
+        for card in &self.cards {
+            suit_counts[card.suit as usize] += 1;
+            rank_mask |= 1 << card.rank;
+        }
+
+        live_cards(&self.cards)
+            .into_iter()
+            .filter(|card| !dead.contains(card))
+            .filter(|card| {
+                suit_counts[card.suit as usize] >= 2 || completes_straight(rank_mask, card.rank)
+            })
+            .collect()
+    }
+
+    /// Describes how `card` would change the board if dealt next
+    ///
+    /// Flushes and straights follow [`cards_that_complete_draws`]: a card
+    /// completes a flush when the board already shows two or more of its
+    /// suit, and a straight when its rank is not yet showing and puts three
+    /// ranks inside a five-rank window. It pairs the board when its rank is
+    /// already showing, and is an overcard when it outranks every board
+    /// card. `card` is assumed to be unseen; before the flop every card
+    /// counts as an overcard.
+    ///
+    /// [`cards_that_complete_draws`]: Self::cards_that_complete_draws
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Board, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let board = Board::from_str("9h 8h 2c").unwrap();
+    /// let impact = board.new_card_impact(Card::from_str("Ah").unwrap());
+    /// assert!(impact.completes_flush && impact.overcard);
+    /// assert!(!impact.pairs_board && !impact.completes_straight);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn new_card_impact(&self, card: Card) -> CardImpact {
+        let mut rank_mask = 0u16;
+        let mut suited = 0;
+        for board_card in &self.cards {
+            rank_mask |= 1 << board_card.rank;
+            if board_card.suit == card.suit {
+                suited += 1;
+            }
+        }
+
+        CardImpact {
+            pairs_board: rank_mask & 1 << card.rank != 0,
+            completes_flush: suited >= 2,
+            completes_straight: completes_straight(rank_mask, card.rank),
+            overcard: self
+                .cards
+                .iter()
+                .all(|board_card| card.rank > board_card.rank),
+        }
+    }
+
+    /// Returns the strongest two-card holding on the board and its value
+    ///
+    /// Every combo of unseen cards is evaluated with the visible board;
+    /// among equally strong holdings the first found is returned. Returns
+    /// `None` before the flop, when no hand can be made yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Board, HandRank};
+    /// use std::str::FromStr;
+    ///
+    /// let board = Board::from_str("As Ks Qs 2d 3c").unwrap();
+    /// let (hole, value) = board.nuts().unwrap();
+    /// assert_eq!(value.rank, HandRank::RoyalFlush);
+    /// assert_eq!(hole.notation(), "JTs");
+    /// ```
+    pub fn nuts(&self) -> Option<(HoleCards, HandValue)> {
+        self.extreme_holding(|value, best| value > best)
+    }
+
+    /// Returns the weakest two-card holding on the board and its value
+    ///
+    /// The counterpart of [`nuts`](Self::nuts): the lowest value any holding
+    /// can make, which on paired or coordinated boards is still a made hand.
+    /// Returns `None` before the flop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Board, HandRank};
+    /// use std::str::FromStr;
+    ///
+    /// let board = Board::from_str("7c 7d Kh 2s 9c").unwrap();
+    /// let (_, value) = board.worst_holding().unwrap();
+    /// assert_eq!(value.rank, HandRank::Pair);
+    /// ```
+    pub fn worst_holding(&self) -> Option<(HoleCards, HandValue)> {
+        self.extreme_holding(|value, worst| value < worst)
+    }
+
+    /// Returns the suit-isomorphism class of the board
+    ///
+    /// Relabelling suits never changes how hands compare, so boards that
+    /// differ only by a suit permutation are strategically identical. The
+    /// flop is treated as unordered, while the turn and river keep their
+    /// positions. Every board in a class maps to the same canonical
+    /// representative, so the class can be used as a map key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Board;
+    /// use std::str::FromStr;
+    ///
+    /// let hearts = Board::from_str("Kh Qd Jc").unwrap();
+    /// let spades = Board::from_str("Qs Ks Jh").unwrap();
+    /// assert_ne!(hearts.isomorphic_class(), spades.isomorphic_class());
+    ///
+    /// let relabelled = Board::from_str("Jd Ks Qh").unwrap();
+    /// assert_eq!(hearts.isomorphic_class(), relabelled.isomorphic_class());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn isomorphic_class(&self) -> BoardClass {
+        let flop_len = self.cards.len().min(3);
+        let index = |card: &Card| card.rank * 4 + card.suit;
+
+        let mut best: Option<Vec<Card>> = None;
+        for permutation in suit_permutations() {
+            let mut cards: Vec<Card> = self
+                .cards
+                .iter()
+                .map(|card| Card {
+                    rank: card.rank,
+                    suit: permutation[card.suit as usize],
+                })
+                .collect();
+            cards[..flop_len].sort_by_key(|card| std::cmp::Reverse(index(card)));
+
+            let smaller = best
+                .as_ref()
+                .is_none_or(|best| cards.iter().map(index).lt(best.iter().map(index)));
+            if smaller {
+                best = Some(cards);
+            }
+        }
+
+        BoardClass {
+            representative: Board {
+                cards: best.expect("there is always a suit permutation"),
+                street: self.street,
+            },
+        }
+    }
+
+    /// Finds the holding whose value beats all others under `better`
+    fn extreme_holding(
+        &self,
+        better: impl Fn(HandValue, HandValue) -> bool,
+    ) -> Option<(HoleCards, HandValue)> {
+        if self.cards.len() < 3 {
+            return None;
         }
+
+        let evaluator = Evaluator::instance();
+        let mut cards = self.cards.clone();
+        cards.extend_from_slice(&self.cards[..2]);
+        let hole_start = self.cards.len();
+
+        let mut extreme: Option<([Card; 2], HandValue)> = None;
+        for_each_runout(&self.cards, 2, |hole| {
+            cards[hole_start..].copy_from_slice(hole);
+            let value = evaluator.evaluate_cards(&cards);
+            if extreme.is_none_or(|(_, current)| better(value, current)) {
+                extreme = Some(([hole[0], hole[1]], value));
+            }
+        });
+
+        extreme.map(|([first, second], value)| {
+            let hole = HoleCards::new(first, second).expect("runout cards are distinct");
+            (hole, value)
+        })
     }
 
     /// Checks for duplicate cards within the provided cards and existing board cards
@@ -451,6 +762,89 @@ impl Board {
     }
 }
 
+/// All 24 ways to relabel the four suits
+fn suit_permutations() -> impl Iterator<Item = [u8; 4]> {
+    (0..4u8).flat_map(|a| {
+        (0..4u8).filter(move |&b| b != a).flat_map(move |b| {
+            (0..4u8)
+                .filter(move |&c| c != a && c != b)
+                .map(move |c| [a, b, c, 6 - a - b - c])
+        })
+    })
+}
+
+/// A class of boards that are identical up to relabelling suits
+///
+/// Created by [`Board::isomorphic_class`]. Two boards share a class exactly
+/// when a suit permutation turns one into the other, treating the flop as
+/// unordered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BoardClass {
+    /// The canonical board standing in for the whole class
+    representative: Board,
+}
+
+impl BoardClass {
+    /// Returns the canonical board of the class
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn representative(&self) -> &Board {
+        &self.representative
+    }
+}
+
+/// How one more community card changes the board
+///
+/// Created by [`Board::new_card_impact`]. A card with none of these effects
+/// is a blank.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CardImpact {
+    /// The card's rank is already on the board
+    pub pairs_board: bool,
+    /// Two suited hole cards, or fewer, now make a flush
+    pub completes_flush: bool,
+    /// Two hole cards, or fewer, now make a straight using the card
+    pub completes_straight: bool,
+    /// The card outranks every card on the board
+    pub overcard: bool,
+}
+
+impl CardImpact {
+    /// True when the card changes nothing tracked here
+    pub fn is_blank(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// True if `rank`, added to a board showing `board_mask`, puts three ranks
+/// inside some straight window holding it
+///
+/// A rank already on the board only pairs it and completes nothing.
+fn completes_straight(board_mask: u16, rank: u8) -> bool {
+    if board_mask & 1 << rank != 0 {
+        return false;
+    }
+    let rank_mask = board_mask | 1 << rank;
+
+    // Treat the ace as both high (bit 12) and low (below the deuce)
+    let extended = (rank_mask as u32) << 1 | (rank_mask as u32 >> 12 & 1);
+    let positions: &[u32] = if rank == 12 {
+        &[0, 13]
+    } else {
+        &[rank as u32 + 1]
+    };
+
+    (0..=9u32).any(|low| {
+        let window = 0b11111 << low;
+        positions
+            .iter()
+            .any(|&position| window & 1 << position != 0)
+            && (extended & window).count_ones() >= 3
+    })
+}
+
 impl Default for Board {
     fn default() -> Self {
         Self::new()
@@ -474,6 +868,88 @@ impl fmt::Display for Board {
     }
 }
 
+impl FromStr for Board {
+    type Err = PokerError;
+
+    /// Parses either the `Display` form, e.g. "Board: [As Kd Qh] (Flop)", or
+    /// whitespace-separated cards in deal order
+    ///
+    /// The card count must be 0, 3, 4 or 5, and a street given in the
+    /// `Display` form must match it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (cards, street) = match s.strip_prefix("Board: [") {
+            Some(rest) => {
+                let (cards, street) =
+                    rest.split_once("] (")
+                        .ok_or_else(|| PokerError::InvalidCardString {
+                            string: s.to_string(),
+                        })?;
+                let cards = if cards == "empty" { "" } else { cards };
+                (cards, street.strip_suffix(')'))
+            }
+            None => (s, None),
+        };
+
+        let cards = cards
+            .split_whitespace()
+            .map(Card::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut board = Board::new();
+        match cards.len() {
+            0 => {}
+            3..=5 => {
+                board.deal_flop(cards[..3].to_vec())?;
+                if let Some(&turn) = cards.get(3) {
+                    board.deal_turn(turn)?;
+                }
+                if let Some(&river) = cards.get(4) {
+                    board.deal_river(river)?;
+                }
+            }
+            actual @ 1..=2 => return Err(PokerError::FlopMustBeThreeCards { actual }),
+            _ => {
+                return Err(PokerError::CannotDealFromStreet {
+                    current_street: Street::River.to_string(),
+                })
+            }
+        }
+
+        if street.is_some_and(|street| street != board.street.to_string()) {
+            return Err(PokerError::InvalidStreetTransition);
+        }
+        Ok(board)
+    }
+}
+
+/// Generates a board at a random street with distinct cards
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Board {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let count = *u.choose(&[0, 3, 4, 5])?;
+        let cards = crate::card::arbitrary_distinct(u, count)?;
+
+        let mut board = Board::new();
+        if count >= 3 {
+            board
+                .deal_flop(cards[..3].to_vec())
+                .expect("arbitrary cards are distinct");
+        }
+        if count >= 4 {
+            board
+                .deal_turn(cards[3])
+                .expect("arbitrary cards are distinct");
+        }
+        if count == 5 {
+            board
+                .deal_river(cards[4])
+                .expect("arbitrary cards are distinct");
+        }
+        Ok(board)
+    }
+}
+
 impl IntoIterator for Board {
     type Item = Card;
     type IntoIter = std::vec::IntoIter<Card>;
@@ -498,6 +974,43 @@ mod tests {
     use crate::card::Card;
     use crate::hand::Hand;
 
+    #[test]
+    fn test_street_navigation() {
+        assert_eq!(Street::River.next(), None);
+        assert_eq!(Street::Preflop.prev(), None);
+        for street in Street::all() {
+            if let Some(next) = street.next() {
+                assert_eq!(next.prev(), Some(street));
+                assert!(next > street);
+            }
+        }
+
+        let dealt: Vec<usize> = Street::all().map(|street| street.cards_dealt()).collect();
+        assert_eq!(dealt, [0, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_isomorphic_class() {
+        let class = |s: &str| Board::from_str(s).unwrap().isomorphic_class();
+
+        // Rainbow flops that differ by a suit permutation and card order
+        assert_eq!(class("Kh Qd Jc"), class("Js Kc Qh"));
+        assert_eq!(class("Kh Qd Jc").representative().len(), 3);
+
+        // Same ranks, but monotone, two-tone and rainbow textures differ
+        let monotone = class("Kh Qh Jh");
+        assert_eq!(monotone, class("Ks Qs Js"));
+        assert_ne!(monotone, class("Kh Qh Jc"));
+        assert_ne!(class("Kh Qh Jc"), class("Kh Qd Jc"));
+
+        // Which flop card the flush draw pairs with matters
+        assert_ne!(class("Kh Qh Jc"), class("Kh Qc Jh"));
+
+        // Turn and river keep their order
+        assert_eq!(class("Kh Qd Jc 2h"), class("Ks Qh Jd 2s"));
+        assert_ne!(class("Kh Qd Jc 2h 3s"), class("Kh Qd Jc 3s 2h"));
+    }
+
     #[test]
     fn test_board_creation() {
         let board = Board::new();
@@ -762,7 +1275,7 @@ mod tests {
 
         // Check that board cards are included
         for card in &board.cards {
-            assert!(hand.cards.iter().any(|&c| c == *card));
+            assert!(hand.cards.contains(card));
         }
     }
 
@@ -1380,4 +1893,181 @@ mod tests {
         modified.deal_river(Card::new(8, 0).unwrap()).unwrap();
         assert_ne!(original, modified);
     }
+
+    #[test]
+    fn test_dealt_community_in_order() {
+        use std::str::FromStr;
+
+        let flop = [
+            Card::from_str("7c").unwrap(),
+            Card::from_str("Ah").unwrap(),
+            Card::from_str("2d").unwrap(),
+        ];
+        let turn = Card::from_str("Ks").unwrap();
+        let river = Card::from_str("3h").unwrap();
+
+        let mut board = Board::new();
+        board.deal_flop(flop.to_vec()).unwrap();
+        board.deal_turn(turn).unwrap();
+        board.deal_river(river).unwrap();
+
+        assert_eq!(
+            board.dealt_community_in_order(),
+            vec![
+                (Street::Flop, flop[0]),
+                (Street::Flop, flop[1]),
+                (Street::Flop, flop[2]),
+                (Street::Turn, turn),
+                (Street::River, river),
+            ]
+        );
+        assert!(Board::new().dealt_community_in_order().is_empty());
+    }
+
+    #[test]
+    fn test_cards_that_complete_draws() {
+        use std::str::FromStr;
+
+        let card = |s: &str| Card::from_str(s).unwrap();
+        let board = Board::new()
+            .with_flop([card("9h"), card("8h"), card("2c")])
+            .unwrap();
+
+        let danger = board.cards_that_complete_draws(&[]);
+        let hearts = danger.iter().filter(|c| c.suit == card("Ah").suit).count();
+        assert_eq!(hearts, 11);
+
+        // Fives through queens fill a straight window with the nine and eight
+        let mut straight_ranks: Vec<char> = danger
+            .iter()
+            .filter(|c| c.suit != card("Ah").suit)
+            .map(|c| c.rank_char())
+            .collect();
+        straight_ranks.dedup();
+        assert_eq!(straight_ranks, ['5', '6', '7', 'T', 'J', 'Q']);
+        assert_eq!(danger.len(), 11 + 6 * 3);
+
+        let with_dead = board.cards_that_complete_draws(&[card("Ah"), card("7c")]);
+        assert_eq!(with_dead.len(), danger.len() - 2);
+
+        assert!(Board::new().cards_that_complete_draws(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_pairing_card_does_not_complete_straight() {
+        use std::str::FromStr;
+
+        let card = |s: &str| Card::from_str(s).unwrap();
+        let board = Board::from_str("9h 8c 7d").unwrap();
+        let danger = board.cards_that_complete_draws(&[]);
+
+        // Pairing the nine adds no straight; a six or a ten does
+        assert!(!danger.contains(&card("9s")));
+        assert!(danger.contains(&card("6s")));
+        assert!(danger.contains(&card("Ts")));
+    }
+
+    #[test]
+    fn test_new_card_impact() {
+        use std::str::FromStr;
+
+        let card = |s: &str| Card::from_str(s).unwrap();
+        let board = Board::from_str("Kh 9h 4c").unwrap();
+
+        let flush_turn = board.new_card_impact(card("2h"));
+        assert!(flush_turn.completes_flush);
+        assert!(!flush_turn.is_blank());
+
+        let blank = board.new_card_impact(card("2d"));
+        assert!(blank.is_blank(), "{:?}", blank);
+
+        let paired = board.new_card_impact(card("9d"));
+        assert_eq!(
+            paired,
+            CardImpact {
+                pairs_board: true,
+                ..CardImpact::default()
+            }
+        );
+
+        // The ace is an overcard, and the queen fills K-Q-9 toward a straight
+        assert!(board.new_card_impact(card("Ad")).overcard);
+        assert!(board.new_card_impact(card("Qd")).completes_straight);
+
+        // A card that pairs the board never completes a straight, even on
+        // a connected board
+        let connected = Board::from_str("9h 8c 7d").unwrap();
+        for rank in ["9s", "8s", "7s"] {
+            let impact = connected.new_card_impact(card(rank));
+            assert!(impact.pairs_board && !impact.completes_straight, "{}", rank);
+        }
+        assert!(connected.new_card_impact(card("Ts")).completes_straight);
+    }
+
+    #[test]
+    fn test_board_from_str() {
+        let board = Board::from_str("Board: [7c Ah 2d Ks] (Turn)").unwrap();
+        assert_eq!(board.street(), Street::Turn);
+        assert_eq!(board, Board::from_str("7c Ah 2d Ks").unwrap());
+        assert_eq!(
+            Board::from_str("Board: [empty] (Preflop)").unwrap(),
+            Board::new()
+        );
+
+        assert_eq!(
+            Board::from_str("7c Ah"),
+            Err(PokerError::FlopMustBeThreeCards { actual: 2 })
+        );
+        assert_eq!(
+            Board::from_str("Board: [7c Ah 2d] (River)"),
+            Err(PokerError::InvalidStreetTransition)
+        );
+        assert!(Board::from_str("7c Ah 2d 7c").is_err());
+    }
+
+    #[test]
+    fn test_nuts_and_worst_holding() {
+        use crate::evaluator::HandRank;
+
+        let board = Board::from_str("As Ks Qs 2d 3c").unwrap();
+        let (hole, nuts) = board.nuts().unwrap();
+        assert_eq!(nuts.rank, HandRank::RoyalFlush);
+        assert_eq!(hole.notation(), "JTs");
+
+        // On a paired board every holding has at least the board pair
+        let paired = Board::from_str("7c 7d Kh 2s 9c").unwrap();
+        let (hole, worst) = paired.worst_holding().unwrap();
+        assert_eq!(worst.rank, HandRank::Pair);
+        assert_eq!(worst.kickers(), vec![11, 7, 2]);
+        assert_eq!(
+            hole.cards.map(|card| card.rank_char()),
+            ['4', '3'],
+            "worst holding was {}",
+            hole
+        );
+
+        let (_, flop_worst) = Board::from_str("Ah Kh Qh")
+            .unwrap()
+            .worst_holding()
+            .unwrap();
+        assert_eq!(flop_worst.rank, HandRank::HighCard);
+
+        assert!(Board::new().nuts().is_none());
+        assert!(Board::new().worst_holding().is_none());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary_boards_are_valid() {
+        use arbitrary::{Arbitrary, Unstructured};
+        use rand::{RngCore, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1375);
+        let mut bytes = [0u8; 32];
+        for _ in 0..1_000 {
+            rng.fill_bytes(&mut bytes);
+            let board = Board::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            assert_eq!(Board::from_str(&board.to_string()), Ok(board));
+        }
+    }
 }
@@ -134,6 +134,37 @@ impl Card {
         Ok(Self { rank, suit })
     }
 
+    /// Rank value reserved for the joker
+    #[cfg(feature = "wild_cards")]
+    pub const JOKER_RANK: u8 = 13;
+
+    /// Creates a joker
+    ///
+    /// The joker sits outside the normal 0-12 rank range, so it is never
+    /// produced by `Card::new` or `Deck::new` and is only meaningful to the
+    /// wild card evaluator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Card;
+    ///
+    /// assert!(Card::joker().is_joker());
+    /// ```
+    #[cfg(feature = "wild_cards")]
+    pub fn joker() -> Self {
+        Self {
+            rank: Self::JOKER_RANK,
+            suit: 0,
+        }
+    }
+
+    /// Returns true if this card is the joker
+    #[cfg(feature = "wild_cards")]
+    pub fn is_joker(&self) -> bool {
+        self.rank == Self::JOKER_RANK
+    }
+
     /// Returns the card's rank value (0=Two to 12=Ace)
     ///
     /// # Examples
@@ -394,6 +425,39 @@ impl Ord for Card {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Card {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let index = u.int_in_range(0..=51u8)?;
+        Ok(Card {
+            rank: index / 4,
+            suit: index % 4,
+        })
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+/// Draws `count` distinct cards, as if dealt from a fresh deck
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_distinct(
+    u: &mut arbitrary::Unstructured<'_>,
+    count: usize,
+) -> arbitrary::Result<Vec<Card>> {
+    let mut deck: Vec<u8> = (0..52).collect();
+    let mut cards = Vec::with_capacity(count);
+    for _ in 0..count {
+        let index = deck.swap_remove(u.choose_index(deck.len())?);
+        cards.push(Card {
+            rank: index / 4,
+            suit: index % 4,
+        });
+    }
+    Ok(cards)
+}
+
 impl Card {
     /// Returns the ordering value for suits (higher value = higher suit)
     fn suit_order(suit: u8) -> u8 {
@@ -629,8 +693,7 @@ mod packed_card_tests {
         let cards: Vec<Card> = (0..52)
             .map(|i| Card::new((i % 13) as u8, (i % 4) as u8).unwrap())
             .collect();
-        let packed_cards: Vec<PackedCard> =
-            cards.iter().map(|c| PackedCard::from_card(c)).collect();
+        let packed_cards: Vec<PackedCard> = cards.iter().map(PackedCard::from_card).collect();
 
         // Packed cards should use roughly half the memory
         assert!(mem::size_of_val(&*packed_cards) * 2 >= mem::size_of_val(&*cards));
@@ -1028,6 +1091,8 @@ mod tests {
     }
 
     #[test]
+    // The antisymmetry checks are written as negated comparisons on purpose
+    #[allow(clippy::nonminimal_bool)]
     fn test_card_comparison_properties() {
         // Test transitivity
         let ace_spades = Card::new(12, 3).unwrap();
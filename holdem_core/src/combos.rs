@@ -0,0 +1,194 @@
+//! # Combos Module
+//!
+//! Counts the two-card holdings that make a given hand category on a board,
+//! after removing dead cards. Holding a card removes every combination that
+//! needs it, which is exactly the blocker effect solvers reason about.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::combos::count_combos;
+//! use holdem_core::evaluator::HandRank;
+//! use holdem_core::{Board, Card};
+//! use std::str::FromStr;
+//!
+//! let board = Board::new()
+//!     .with_flop([
+//!         Card::from_str("Kc").unwrap(),
+//!         Card::from_str("7d").unwrap(),
+//!         Card::from_str("2s").unwrap(),
+//!     ])
+//!     .unwrap();
+//!
+//! // Three combos each of KK, 77 and 22
+//! assert_eq!(count_combos(HandRank::ThreeOfAKind, &board, &[]), 9);
+//! ```
+
+use crate::card::Card;
+use crate::equity::for_each_runout;
+use crate::evaluator::{Evaluator, HandRank};
+use crate::{Board, HoleCards};
+
+/// Counts the two-card combinations that make `target` on the board
+///
+/// Combinations using a board card or any card in `dead` are excluded. The
+/// category is that of the best five cards from the holding plus the board.
+/// Boards without a flop have no five-card hand yet, so they count zero.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn count_combos(target: HandRank, board: &Board, dead: &[Card]) -> usize {
+    combos_making_exactly(target, board, dead).len()
+}
+
+/// Lists the two-card combinations whose best hand is exactly `rank`
+///
+/// Holdings that make something stronger are left out, so on a board with
+/// three or more cards of one suit "exactly a straight" excludes the
+/// straights that are also flushes.
+/// Combinations using a board card or any card in `dead` are excluded, and
+/// boards without a flop return no combos.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::combos::combos_making_exactly;
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::Board;
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Kc 7d 2s").unwrap();
+/// let sets = combos_making_exactly(HandRank::ThreeOfAKind, &board, &[]);
+/// assert!(sets.iter().all(|hole| hole.is_pair()));
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn combos_making_exactly(rank: HandRank, board: &Board, dead: &[Card]) -> Vec<HoleCards> {
+    let board_cards = board.visible_cards();
+    if board_cards.len() < 3 {
+        return Vec::new();
+    }
+
+    let mut unavailable = board_cards.to_vec();
+    unavailable.extend_from_slice(dead);
+
+    let evaluator = Evaluator::instance();
+    let mut cards = board_cards.to_vec();
+    cards.extend_from_slice(&[board_cards[0]; 2]);
+    let hole_start = board_cards.len();
+
+    let mut combos = Vec::new();
+    for_each_runout(&unavailable, 2, |hole| {
+        cards[hole_start..].copy_from_slice(hole);
+        if evaluator.evaluate_cards(&cards).rank == rank {
+            combos.push(HoleCards::new(hole[0], hole[1]).expect("runout cards are distinct"));
+        }
+    });
+    combos
+}
+
+/// Counts the villain value combos that share a card with the hero's holding
+///
+/// Combinations that use a board card cannot be held by villain and are
+/// ignored. The higher the score, the more of villain's value range the hero
+/// removes, which makes the holding a better bluffing candidate.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::combos::{blocker_score, combos_making_exactly};
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::{Board, HoleCards};
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Kc 7d 2s").unwrap();
+/// let sets = combos_making_exactly(HandRank::ThreeOfAKind, &board, &[]);
+/// let hero = HoleCards::from_notation("K7o").unwrap();
+/// assert!(blocker_score(&hero, &sets, &board) > 0);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn blocker_score(hero: &HoleCards, villain_value_range: &[HoleCards], board: &Board) -> usize {
+    let board_cards = board.visible_cards();
+    villain_value_range
+        .iter()
+        .filter(|villain| !villain.cards.iter().any(|card| board_cards.contains(card)))
+        .filter(|villain| villain.cards.iter().any(|card| hero.cards.contains(card)))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::card;
+
+    fn flop(cards: [&str; 3]) -> Board {
+        Board::new().with_flop(cards.map(card)).unwrap()
+    }
+
+    #[test]
+    fn test_count_sets_on_flop() {
+        let board = flop(["Kc", "7d", "2s"]);
+        assert_eq!(count_combos(HandRank::ThreeOfAKind, &board, &[]), 9);
+    }
+
+    #[test]
+    fn test_held_card_blocks_combos() {
+        let board = flop(["Kc", "7d", "2s"]);
+        // Holding a king leaves only one KK combo
+        assert_eq!(
+            count_combos(HandRank::ThreeOfAKind, &board, &[card("Kh")]),
+            7
+        );
+    }
+
+    #[test]
+    fn test_flush_combos_with_nut_blocker() {
+        let board = flop(["Kh", "7h", "2h"]);
+        // Ten hearts remain, giving C(10, 2) flush combos
+        assert_eq!(count_combos(HandRank::Flush, &board, &[]), 45);
+        assert_eq!(count_combos(HandRank::Flush, &board, &[card("Ah")]), 36);
+    }
+
+    #[test]
+    fn test_exact_straights_exclude_flushes() {
+        let board = flop(["9h", "8h", "7c"]).with_turn(card("Kh")).unwrap();
+        let straights = combos_making_exactly(HandRank::Straight, &board, &[]);
+
+        // J-T, T-6 and 6-5 each lose the one combo that is two more hearts
+        let hearts = card("Kh").suit;
+        assert_eq!(straights.len(), 45);
+        assert!(straights
+            .iter()
+            .all(|hole| !hole.cards.iter().all(|card| card.suit == hearts)));
+        assert_eq!(count_combos(HandRank::Straight, &board, &[]), 45);
+    }
+
+    #[test]
+    fn test_preflop_board_counts_nothing() {
+        assert_eq!(count_combos(HandRank::Pair, &Board::new(), &[]), 0);
+    }
+
+    #[test]
+    fn test_nut_flush_blocker() {
+        let board = flop(["Kh", "7h", "2h"]);
+        let ace = card("Ah");
+        let nut_flushes: Vec<HoleCards> = combos_making_exactly(HandRank::Flush, &board, &[])
+            .into_iter()
+            .filter(|hole| hole.cards.contains(&ace))
+            .collect();
+        assert_eq!(nut_flushes.len(), 9);
+
+        let ace_blocker = HoleCards::new(ace, card("Qc")).unwrap();
+        assert_eq!(blocker_score(&ace_blocker, &nut_flushes, &board), 9);
+
+        // A lower heart blocks only the one combo it pairs with the ace
+        let queen_blocker = HoleCards::new(card("Qh"), card("Qc")).unwrap();
+        assert_eq!(blocker_score(&queen_blocker, &nut_flushes, &board), 1);
+    }
+}
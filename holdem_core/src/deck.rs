@@ -41,14 +41,24 @@
 //! - **Efficient Dealing**: Pop from end for O(1) deal operations
 //! - **Serialization Ready**: Full serde support for persistence
 //! - **Flexible Shuffling**: Uses rand crate for high-quality randomization
+//! - **Optional Burn Cards**: Street-dealing helpers can burn a card before
+//!   the flop, turn and river, as in a live game
+//! - **Scripted Deals**: Named cards can be pulled from anywhere in the deck
+//!   to set up reproducible scenarios without shuffling
 
+use crate::board::Board;
 use crate::card::Card;
+use crate::errors::PokerError;
+use crate::hole_cards::HoleCards;
 use serde::{Deserialize, Serialize};
 
 /// Represents a deck of cards not yet dealt
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deck {
     cards: Vec<Card>,
+    /// Whether the street-dealing helpers burn a card before each street
+    #[serde(default)]
+    burn_cards: bool,
 }
 
 impl Deck {
@@ -73,7 +83,39 @@ impl Deck {
                 cards.push(Card::new(rank, suit).unwrap());
             }
         }
-        Self { cards }
+        Self {
+            cards,
+            burn_cards: false,
+        }
+    }
+
+    /// Enables or disables burning a card before each street
+    ///
+    /// Only the street-dealing helpers (`deal_flop`, `deal_turn`,
+    /// `deal_river` and `deal_full_board`) burn cards; `deal` and `deal_one`
+    /// are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Deck;
+    ///
+    /// let mut deck = Deck::new().with_burn_cards(true);
+    /// deck.deal_flop().unwrap();
+    /// assert_eq!(deck.remaining(), 52 - 4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn with_burn_cards(mut self, burn_cards: bool) -> Self {
+        self.burn_cards = burn_cards;
+        self
+    }
+
+    /// Returns true if the street-dealing helpers burn cards
+    pub fn burns_cards(&self) -> bool {
+        self.burn_cards
     }
 
     /// Shuffles the deck using the provided random number generator
@@ -97,6 +139,34 @@ impl Deck {
         self.cards.shuffle(rng);
     }
 
+    /// Cuts the deck, moving the top `position` cards to the bottom
+    ///
+    /// This is the physical cut made before dealing. The deck is rotated, so
+    /// no cards are lost, and cutting again at `remaining() - position`
+    /// restores the original order. Positions past the end wrap around.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Deck;
+    ///
+    /// let mut deck = Deck::new();
+    /// let top = deck.cards()[51];
+    /// deck.cut(1);
+    /// assert_eq!(deck.cards()[0], top);
+    /// assert_eq!(deck.remaining(), 52);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn cut(&mut self, position: usize) {
+        if !self.cards.is_empty() {
+            let position = position % self.cards.len();
+            self.cards.rotate_right(position);
+        }
+    }
+
     /// Deals a single card from the top of the deck
     ///
     /// Returns `None` if the deck is empty.
@@ -210,6 +280,246 @@ impl Deck {
     pub fn cards(&self) -> &[Card] {
         &self.cards
     }
+
+    /// Counts the undealt cards of a rank (0 = Two, 12 = Ace)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Deck;
+    ///
+    /// let deck = Deck::new();
+    /// assert_eq!(deck.count_remaining_rank(12), 4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn count_remaining_rank(&self, rank: u8) -> usize {
+        self.cards.iter().filter(|card| card.rank == rank).count()
+    }
+
+    /// Counts the undealt cards of a suit (0 = hearts, 1 = diamonds,
+    /// 2 = clubs, 3 = spades)
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn count_remaining_suit(&self, suit: u8) -> usize {
+        self.cards.iter().filter(|card| card.suit == suit).count()
+    }
+
+    /// Probability that the next card dealt satisfies `predicate`
+    ///
+    /// Every undealt card is equally likely; an empty deck gives `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Deck;
+    ///
+    /// let deck = Deck::new();
+    /// let heart = deck.draw_probability(|card| card.suit == 0);
+    /// assert!((heart - 0.25).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn draw_probability(&self, predicate: impl Fn(&Card) -> bool) -> f64 {
+        if self.cards.is_empty() {
+            return 0.0;
+        }
+        let hits = self.cards.iter().filter(|card| predicate(card)).count();
+        hits as f64 / self.cards.len() as f64
+    }
+
+    /// Deals the three flop cards, burning one first if enabled
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Deck;
+    ///
+    /// let mut deck = Deck::new();
+    /// let flop = deck.deal_flop().unwrap();
+    /// assert_eq!(flop.len(), 3);
+    /// assert_eq!(deck.remaining(), 49);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::NotEnoughCards` if the deck cannot supply the
+    /// flop (and burn card). No cards are removed in that case.
+    pub fn deal_flop(&mut self) -> Result<[Card; 3], PokerError> {
+        let cards = self.deal_street(3)?;
+        Ok([cards[0], cards[1], cards[2]])
+    }
+
+    /// Deals the turn card, burning one first if enabled
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::NotEnoughCards` if the deck cannot supply the
+    /// turn (and burn card). No cards are removed in that case.
+    pub fn deal_turn(&mut self) -> Result<Card, PokerError> {
+        Ok(self.deal_street(1)?[0])
+    }
+
+    /// Deals the river card, burning one first if enabled
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::NotEnoughCards` if the deck cannot supply the
+    /// river (and burn card). No cards are removed in that case.
+    pub fn deal_river(&mut self) -> Result<Card, PokerError> {
+        Ok(self.deal_street(1)?[0])
+    }
+
+    /// Deals a complete five-card board
+    ///
+    /// Consumes five cards, plus three burn cards when burning is enabled,
+    /// and returns a board on the river.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Deck, Street};
+    ///
+    /// let mut deck = Deck::new().with_burn_cards(true);
+    /// let board = deck.deal_full_board().unwrap();
+    /// assert_eq!(board.street(), Street::River);
+    /// assert_eq!(deck.remaining(), 52 - 8);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::NotEnoughCards` if the deck cannot supply the
+    /// whole board. No cards are removed in that case.
+    pub fn deal_full_board(&mut self) -> Result<Board, PokerError> {
+        let needed = if self.burn_cards { 8 } else { 5 };
+        self.ensure_remaining(needed)?;
+
+        Board::new()
+            .with_flop(self.deal_flop()?)?
+            .with_turn(self.deal_turn()?)?
+            .with_river(self.deal_river()?)
+    }
+
+    /// Removes a named card from anywhere in the deck
+    ///
+    /// The order of the remaining cards is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Card, Deck};
+    /// use std::str::FromStr;
+    ///
+    /// let mut deck = Deck::new();
+    /// let ace = Card::from_str("As").unwrap();
+    /// assert_eq!(deck.deal_specific(ace).unwrap(), ace);
+    /// assert!(deck.deal_specific(ace).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::CardNotInDeck` if the card has already been dealt.
+    pub fn deal_specific(&mut self, card: Card) -> Result<Card, PokerError> {
+        let position = self.position(card)?;
+        Ok(self.cards.remove(position))
+    }
+
+    /// Deals exact hole cards
+    ///
+    /// Both cards are removed from the deck and returned as hole cards. The
+    /// deck knows nothing about seats, so the caller decides which player
+    /// receives them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Card, Deck};
+    /// use std::str::FromStr;
+    ///
+    /// let mut deck = Deck::new();
+    /// let cards = ["Ah", "Kh"].map(|c| Card::from_str(c).unwrap());
+    /// let hole = deck.deal_hole_cards(cards).unwrap();
+    /// assert_eq!(hole.cards, cards);
+    /// assert_eq!(deck.remaining(), 50);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::DuplicateCard` if both cards are the same, or
+    /// `PokerError::CardNotInDeck` if either card has been dealt. No cards
+    /// are removed on error.
+    pub fn deal_hole_cards(&mut self, cards: [Card; 2]) -> Result<HoleCards, PokerError> {
+        let hole = HoleCards::new(cards[0], cards[1])?;
+        for card in cards {
+            self.position(card)?;
+        }
+
+        for card in cards {
+            self.deal_specific(card)?;
+        }
+        Ok(hole)
+    }
+
+    /// Deals four hole cards to each of `players` players for Omaha
+    ///
+    /// Cards go out one at a time around the table, as at a live game, so
+    /// player `i` receives the `i`-th card of each round.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Deck;
+    ///
+    /// let mut deck = Deck::new();
+    /// let hands = deck.deal_omaha_hole_cards(3).unwrap();
+    /// assert_eq!(hands.len(), 3);
+    /// assert_eq!(deck.remaining(), 52 - 12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::NotEnoughCards` if the deck holds fewer than four
+    /// cards per player. No cards are removed in that case.
+    pub fn deal_omaha_hole_cards(&mut self, players: usize) -> Result<Vec<[Card; 4]>, PokerError> {
+        self.ensure_remaining(4 * players)?;
+        let dealt = self.deal(4 * players);
+        Ok((0..players)
+            .map(|player| std::array::from_fn(|round| dealt[round * players + player]))
+            .collect())
+    }
+
+    /// Finds a card still in the deck
+    fn position(&self, card: Card) -> Result<usize, PokerError> {
+        self.cards
+            .iter()
+            .position(|&c| c == card)
+            .ok_or(PokerError::CardNotInDeck(card))
+    }
+
+    /// Burns a card if enabled, then deals `count` cards
+    fn deal_street(&mut self, count: usize) -> Result<Vec<Card>, PokerError> {
+        let burn = usize::from(self.burn_cards);
+        self.ensure_remaining(burn + count)?;
+        self.deal(burn);
+        Ok(self.deal(count))
+    }
+
+    /// Checks that at least `requested` cards remain
+    fn ensure_remaining(&self, requested: usize) -> Result<(), PokerError> {
+        if self.cards.len() < requested {
+            return Err(PokerError::NotEnoughCards {
+                requested,
+                remaining: self.cards.len(),
+            });
+        }
+        Ok(())
+    }
 }
 
 impl Default for Deck {
@@ -492,4 +802,191 @@ mod tests {
             dealing_time
         );
     }
+
+    #[test]
+    fn test_deck_deal_full_board() {
+        let mut deck = Deck::new();
+        let board = deck.deal_full_board().unwrap();
+
+        assert_eq!(board.street(), crate::Street::River);
+        assert_eq!(board.len(), 5);
+        assert_eq!(deck.remaining(), 52 - 5);
+        for card in board.visible_cards() {
+            assert!(!deck.cards().contains(card));
+        }
+    }
+
+    #[test]
+    fn test_deck_deal_full_board_with_burns() {
+        let mut deck = Deck::new().with_burn_cards(true);
+        assert!(deck.burns_cards());
+
+        let board = deck.deal_full_board().unwrap();
+        assert_eq!(board.street(), crate::Street::River);
+        assert_eq!(deck.remaining(), 52 - 5 - 3);
+    }
+
+    #[test]
+    fn test_deck_deal_streets() {
+        let mut deck = Deck::new().with_burn_cards(true);
+
+        let flop = deck.deal_flop().unwrap();
+        assert_eq!(deck.remaining(), 48);
+        let turn = deck.deal_turn().unwrap();
+        assert_eq!(deck.remaining(), 46);
+        let river = deck.deal_river().unwrap();
+        assert_eq!(deck.remaining(), 44);
+
+        assert!(!flop.contains(&turn));
+        assert!(!flop.contains(&river));
+        assert_ne!(turn, river);
+    }
+
+    #[test]
+    fn test_deck_deal_full_board_not_enough_cards() {
+        let mut deck = Deck::new().with_burn_cards(true);
+        deck.deal(45);
+
+        let result = deck.deal_full_board();
+        assert_eq!(
+            result,
+            Err(PokerError::NotEnoughCards {
+                requested: 8,
+                remaining: 7
+            })
+        );
+        assert_eq!(deck.remaining(), 7);
+    }
+
+    #[test]
+    fn test_deck_deal_specific_board() {
+        use std::str::FromStr;
+
+        let mut deck = Deck::new();
+        let cards = ["Ah", "Kd", "7c", "7s", "2h"].map(|c| Card::from_str(c).unwrap());
+        for card in cards {
+            assert_eq!(deck.deal_specific(card), Ok(card));
+        }
+
+        assert_eq!(deck.remaining(), 47);
+        for card in cards {
+            assert!(!deck.cards().contains(&card));
+            assert_eq!(
+                deck.deal_specific(card),
+                Err(PokerError::CardNotInDeck(card))
+            );
+        }
+
+        let board = Board::new()
+            .with_flop([cards[0], cards[1], cards[2]])
+            .unwrap()
+            .with_turn(cards[3])
+            .unwrap()
+            .with_river(cards[4])
+            .unwrap();
+        assert_eq!(board.visible_cards(), &cards);
+    }
+
+    #[test]
+    fn test_deck_deal_hole_cards() {
+        use std::str::FromStr;
+
+        let card = |s: &str| Card::from_str(s).unwrap();
+        let mut deck = Deck::new();
+
+        let hole = deck.deal_hole_cards([card("As"), card("Ad")]).unwrap();
+        assert_eq!(hole, HoleCards::new(card("As"), card("Ad")).unwrap());
+        assert_eq!(deck.remaining(), 50);
+
+        assert_eq!(
+            deck.deal_hole_cards([card("Kh"), card("Kh")]),
+            Err(PokerError::DuplicateCard(card("Kh")))
+        );
+        // The second card is still available, but the first is not
+        assert_eq!(
+            deck.deal_hole_cards([card("As"), card("Kh")]),
+            Err(PokerError::CardNotInDeck(card("As")))
+        );
+        assert!(deck.cards().contains(&card("Kh")));
+        assert_eq!(deck.remaining(), 50);
+    }
+
+    #[test]
+    fn test_deck_cut_and_restore() {
+        use rand::SeedableRng;
+
+        let mut deck = Deck::new();
+        let mut rng = rand::rngs::StdRng::from_seed([3; 32]);
+        deck.shuffle(&mut rng);
+        let original = deck.cards().to_vec();
+
+        deck.cut(20);
+        assert_ne!(deck.cards(), &original[..]);
+        assert_eq!(deck.remaining(), 52);
+        // The 20 cards that were on top are now dealt last
+        assert_eq!(deck.cards()[..20], original[32..]);
+        let mut sorted = deck.cards().to_vec();
+        sorted.sort();
+        let mut expected = original.clone();
+        expected.sort();
+        assert_eq!(sorted, expected);
+
+        deck.cut(52 - 20);
+        assert_eq!(deck.cards(), &original[..]);
+
+        deck.cut(52);
+        assert_eq!(deck.cards(), &original[..]);
+    }
+
+    #[test]
+    fn test_deck_remaining_counts_and_probabilities() {
+        let mut deck = Deck::new();
+        for rank in 0..13 {
+            deck.deal_specific(Card::new(rank, 0).unwrap()).unwrap();
+        }
+
+        assert_eq!(deck.count_remaining_suit(0), 0);
+        assert_eq!(deck.count_remaining_suit(3), 13);
+        assert_eq!(deck.count_remaining_rank(12), 3);
+        assert_eq!(deck.draw_probability(|card| card.suit == 0), 0.0);
+
+        let by_suit: f64 = (0..4)
+            .map(|suit| deck.draw_probability(|card| card.suit == suit))
+            .sum();
+        assert!((by_suit - 1.0).abs() < 1e-12);
+        let by_rank: f64 = (0..13)
+            .map(|rank| deck.draw_probability(|card| card.rank == rank))
+            .sum();
+        assert!((by_rank - 1.0).abs() < 1e-12);
+
+        deck.deal(39);
+        assert_eq!(deck.draw_probability(|_| true), 0.0);
+    }
+
+    #[test]
+    fn test_deal_omaha_hole_cards() {
+        use crate::omaha::OmahaHoleCards;
+        use std::collections::HashSet;
+
+        let mut deck = Deck::new();
+        let hands = deck.deal_omaha_hole_cards(3).unwrap();
+        assert_eq!(hands.len(), 3);
+        assert_eq!(deck.remaining(), 40);
+
+        let dealt: HashSet<Card> = hands.iter().flatten().copied().collect();
+        assert_eq!(dealt.len(), 12);
+        for cards in hands {
+            let hole = OmahaHoleCards::new(cards).unwrap();
+            assert_eq!(hole.all_two_card_subsets().len(), 6);
+        }
+
+        assert_eq!(
+            deck.deal_omaha_hole_cards(11),
+            Err(PokerError::NotEnoughCards {
+                requested: 44,
+                remaining: 40,
+            })
+        );
+        assert_eq!(deck.remaining(), 40);
+    }
 }
@@ -0,0 +1,171 @@
+//! # Describe Module
+//!
+//! Plain-English descriptions of a player's holding on a board, for CLI tools
+//! and logs. The wording is from the player's point of view: a kicker is only
+//! mentioned when it comes from the hole cards, the nut flush is called out,
+//! and a holding that cannot beat the board itself is said to play the board.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::describe::describe_holding;
+//! use holdem_core::{Board, Card, HoleCards};
+//! use std::str::FromStr;
+//!
+//! let hole = HoleCards::from_notation("AKo").unwrap();
+//! let board = Board::new()
+//!     .with_flop(["Ac", "7d", "2s"].map(|c| Card::from_str(c).unwrap()))
+//!     .unwrap();
+//!
+//! assert_eq!(describe_holding(&hole, &board), "pair of Aces with King kicker");
+//! ```
+
+use crate::card::Card;
+use crate::evaluator::evaluator::{rank_name, rank_plural};
+use crate::evaluator::{Evaluator, HandRank};
+use crate::{Board, HoleCards};
+
+/// Describes the best hand a player makes with their hole cards on a board
+///
+/// Before the flop the hole cards themselves are described, e.g.
+/// "pocket Kings" or "Ace-King".
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn describe_holding(hole: &HoleCards, board: &Board) -> String {
+    let [first, second] = hole.cards;
+    let (high, low) = if first.rank >= second.rank {
+        (first, second)
+    } else {
+        (second, first)
+    };
+
+    let board_cards = board.visible_cards();
+    if board_cards.len() < 3 {
+        return if high.rank == low.rank {
+            format!("pocket {}", rank_plural(high.rank as u32))
+        } else {
+            format!(
+                "{}-{}",
+                rank_name(high.rank as u32),
+                rank_name(low.rank as u32)
+            )
+        };
+    }
+
+    let evaluator = Evaluator::instance();
+    let mut cards = hole.cards.to_vec();
+    cards.extend_from_slice(board_cards);
+    let value = evaluator.evaluate_cards(&cards);
+
+    if board_cards.len() == 5 && evaluator.evaluate_cards(board_cards) == value {
+        return format!("{}, playing the board", value.describe());
+    }
+
+    match value.rank {
+        HandRank::Flush if is_nut_flush(&hole.cards, board_cards) => "nut flush".to_string(),
+        HandRank::Pair => {
+            let pair = value.value / 2197;
+            let kickers = [
+                value.value / 169 % 13,
+                value.value / 13 % 13,
+                value.value % 13,
+            ];
+            let kicker = if high.rank as u32 == pair && low.rank as u32 != pair {
+                Some(low.rank as u32)
+            } else if low.rank as u32 == pair && high.rank as u32 != pair {
+                Some(high.rank as u32)
+            } else {
+                None
+            };
+
+            match kicker {
+                Some(kicker) if kickers.contains(&kicker) => format!(
+                    "pair of {} with {} kicker",
+                    rank_plural(pair),
+                    rank_name(kicker)
+                ),
+                Some(_) => format!("pair of {}", rank_plural(pair)),
+                None => value.describe(),
+            }
+        }
+        _ => value.describe(),
+    }
+}
+
+/// True if the hole cards hold the highest card of the board's flush suit
+fn is_nut_flush(hole: &[Card; 2], board: &[Card]) -> bool {
+    (0..4u8).any(|suit| {
+        if board.iter().filter(|card| card.suit == suit).count() < 3 {
+            return false;
+        }
+        let nut_rank = (0..13u8)
+            .rev()
+            .find(|&rank| !board.contains(&Card { rank, suit }))
+            .unwrap_or(0);
+        hole.contains(&Card {
+            rank: nut_rank,
+            suit,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{card, hole};
+
+    fn board(cards: &[&str]) -> Board {
+        let mut board = Board::new()
+            .with_flop([card(cards[0]), card(cards[1]), card(cards[2])])
+            .unwrap();
+        if let Some(turn) = cards.get(3) {
+            board = board.with_turn(card(turn)).unwrap();
+        }
+        if let Some(river) = cards.get(4) {
+            board = board.with_river(card(river)).unwrap();
+        }
+        board
+    }
+
+    #[test]
+    fn test_top_pair_top_kicker() {
+        let description = describe_holding(&hole("Ah", "Kd"), &board(&["Ac", "7d", "2s"]));
+        assert_eq!(description, "pair of Aces with King kicker");
+
+        // A weak kicker that does not play is not mentioned
+        let description =
+            describe_holding(&hole("Ah", "3d"), &board(&["Ac", "Kd", "Qs", "Jh", "8c"]));
+        assert_eq!(description, "pair of Aces");
+    }
+
+    #[test]
+    fn test_flush() {
+        let flop = board(&["Kh", "9h", "2h"]);
+        assert_eq!(describe_holding(&hole("Ah", "5h"), &flop), "nut flush");
+        assert_eq!(
+            describe_holding(&hole("Qh", "5h"), &flop),
+            "King-high flush"
+        );
+    }
+
+    #[test]
+    fn test_playing_the_board() {
+        let description =
+            describe_holding(&hole("2c", "3d"), &board(&["As", "Kd", "Qh", "Jc", "Ts"]));
+        assert_eq!(description, "Ace-high straight, playing the board");
+    }
+
+    #[test]
+    fn test_preflop_describes_hole_cards() {
+        assert_eq!(
+            describe_holding(&hole("Kh", "Kd"), &Board::new()),
+            "pocket Kings"
+        );
+        assert_eq!(
+            describe_holding(&hole("Kh", "Ad"), &Board::new()),
+            "Ace-King"
+        );
+    }
+}
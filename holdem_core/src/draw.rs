@@ -0,0 +1,123 @@
+//! # Draw Module
+//!
+//! Discard decisions for five-card draw. Every keep/discard pattern of a
+//! five-card hand is scored by simulating replacement cards from the deck and
+//! averaging the percentile of the final hand among all 5-card hand classes.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::draw::best_discard;
+//! use holdem_core::{Card, Deck};
+//! use rand::SeedableRng;
+//! use std::str::FromStr;
+//!
+//! let hand = ["Ah", "Ad", "Ac", "7s", "2d"].map(|c| Card::from_str(c).unwrap());
+//! let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+//! let (discard, _) = best_discard(&hand, &Deck::new(), 500, &mut rng);
+//! assert_eq!(discard, [false, false, false, true, true]);
+//! ```
+
+use crate::card::Card;
+use crate::evaluator::Evaluator;
+use crate::Deck;
+use rand::seq::SliceRandom;
+
+/// Finds the discard pattern with the best expected final hand
+///
+/// Each of the 32 patterns marks the cards to throw away with `true`. The
+/// replacements are drawn from the cards left in `deck`, skipping any of the
+/// hand's own cards, and each pattern is scored by the mean percentile of the
+/// final hand over `iterations` draws (at least one). Standing pat is scored
+/// exactly, and ties go to the pattern discarding fewer cards. Patterns that
+/// need more cards than the deck holds are not considered.
+///
+/// Returns the best pattern with its expected percentile in `[0, 1]`.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn best_discard(
+    hand: &[Card; 5],
+    deck: &Deck,
+    iterations: usize,
+    rng: &mut impl rand::Rng,
+) -> ([bool; 5], f64) {
+    let evaluator = Evaluator::instance();
+    let mut stub: Vec<Card> = deck
+        .cards()
+        .iter()
+        .filter(|card| !hand.contains(card))
+        .copied()
+        .collect();
+    let iterations = iterations.max(1);
+
+    let mut best = (
+        [false; 5],
+        evaluator.percentile(evaluator.evaluate_5_card(hand)),
+    );
+    let mut patterns: Vec<u8> = (1..32).collect();
+    patterns.sort_by_key(|mask| mask.count_ones());
+
+    for mask in patterns {
+        let discard: [bool; 5] = std::array::from_fn(|i| mask & 1 << i != 0);
+        let drawn = mask.count_ones() as usize;
+        if drawn > stub.len() {
+            continue;
+        }
+
+        let mut total = 0.0;
+        for _ in 0..iterations {
+            let (replacements, _) = stub.partial_shuffle(rng, drawn);
+            let mut replacements = replacements.iter();
+            let final_hand: [Card; 5] = std::array::from_fn(|i| {
+                if discard[i] {
+                    *replacements.next().expect("one replacement per discard")
+                } else {
+                    hand[i]
+                }
+            });
+            total += evaluator.percentile(evaluator.evaluate_5_card(&final_hand));
+        }
+
+        let expected = total / iterations as f64;
+        if expected > best.1 {
+            best = (discard, expected);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use std::str::FromStr;
+
+    fn hand(notation: [&str; 5]) -> [Card; 5] {
+        notation.map(|c| Card::from_str(c).unwrap())
+    }
+
+    #[test]
+    fn test_made_flush_stands_pat() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1377);
+        let flush = hand(["Ah", "Jh", "8h", "5h", "2h"]);
+        let (discard, expected) = best_discard(&flush, &Deck::new(), 200, &mut rng);
+        assert_eq!(discard, [false; 5]);
+
+        let evaluator = Evaluator::instance();
+        assert_eq!(
+            expected,
+            evaluator.percentile(evaluator.evaluate_5_card(&flush))
+        );
+    }
+
+    #[test]
+    fn test_trips_draw_two() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1377);
+        let trips = hand(["9h", "9d", "9c", "Ks", "3d"]);
+        let (discard, expected) = best_discard(&trips, &Deck::new(), 300, &mut rng);
+        assert_eq!(discard, [false, false, false, true, true]);
+        assert!((0.0..=1.0).contains(&expected));
+    }
+}
@@ -0,0 +1,1362 @@
+//! # Equity Module
+//!
+//! Exact heads-up equity calculation by enumerating every possible runout of
+//! the remaining community cards.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::equity::equity_exact;
+//! use holdem_core::{Board, Card, HoleCards};
+//! use std::str::FromStr;
+//!
+//! let hero = HoleCards::from_notation("AA").unwrap();
+//! let villain = HoleCards::new(
+//!     Card::from_str("Kh").unwrap(),
+//!     Card::from_str("Qh").unwrap(),
+//! )
+//! .unwrap();
+//! let board = Board::new()
+//!     .with_flop([
+//!         Card::from_str("Ad").unwrap(),
+//!         Card::from_str("7h").unwrap(),
+//!         Card::from_str("2d").unwrap(),
+//!     ])
+//!     .unwrap();
+//!
+//! let result = equity_exact(&hero, &villain, &board).unwrap();
+//! assert_eq!(result.total(), 990);
+//! assert!(result.equity() > 0.9);
+//! ```
+
+use crate::card::Card;
+use crate::errors::PokerError;
+use crate::evaluator::{Evaluator, HandValue};
+use crate::range::WeightedRange;
+use crate::{Board, Deck, HoleCards};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Showdown tallies for one hand against another
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EquityResult {
+    /// Runouts the hand wins outright
+    pub wins: u64,
+    /// Runouts that split the pot
+    pub ties: u64,
+    /// Runouts the hand loses
+    pub losses: u64,
+}
+
+impl EquityResult {
+    /// Total number of runouts counted
+    pub fn total(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    /// Share of the pot won on average, counting ties as half
+    ///
+    /// Returns `0.0` when no runouts were counted.
+    pub fn equity(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 + self.ties as f64 / 2.0) / total as f64
+    }
+
+    /// Fraction of runouts won outright
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    /// Fraction of runouts that split the pot
+    pub fn tie_rate(&self) -> f64 {
+        self.rate(self.ties)
+    }
+
+    /// Records one showdown between the hand and its opponent
+    pub fn record(&mut self, hero: HandValue, villain: HandValue) {
+        match hero.cmp(&villain) {
+            Ordering::Greater => self.wins += 1,
+            Ordering::Equal => self.ties += 1,
+            Ordering::Less => self.losses += 1,
+        }
+    }
+
+    /// Adds another set of tallies to this one
+    pub fn merge(&mut self, other: &EquityResult) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+    }
+
+    fn rate(&self, count: u64) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            0.0
+        } else {
+            count as f64 / total as f64
+        }
+    }
+}
+
+/// One dealt board and both hands' values at showdown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShowdownResult {
+    /// The complete five-card board
+    pub board: Board,
+    /// Value of hero's best five cards
+    pub hero: HandValue,
+    /// Value of villain's best five cards
+    pub villain: HandValue,
+}
+
+impl ShowdownResult {
+    /// How hero's hand compares with villain's on this board
+    pub fn outcome(&self) -> Ordering {
+        self.hero.cmp(&self.villain)
+    }
+}
+
+/// Simulated results of hero against several villain ranges at once
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MultiwayEquity {
+    /// Hero against each villain alone, in the order of the ranges
+    pub per_villain: Vec<EquityResult>,
+    /// Hero against the best villain hand: a win is a scoop and a tie is a
+    /// chop of any size
+    pub aggregate: EquityResult,
+    /// Hero's average share of the pot, a chop with `k` villains counting
+    /// as `1 / (k + 1)`
+    pub equity: f64,
+}
+
+/// Iterates over every turn and river pair of the cards not yet seen
+///
+/// The live cards are kept in a fixed array and the iterator advances two
+/// indices in place, so enumerating the 990 runouts of a heads-up flop needs
+/// no allocation at all. Pairs are yielded once each, in deck index order.
+/// Flop equity in [`equity_exact`] and every other two-card enumeration in
+/// the crate runs on it.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::RunoutIterator;
+/// use holdem_core::Board;
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Kc 7d 2s").unwrap();
+/// let runouts = RunoutIterator::new(board.visible_cards());
+/// assert_eq!(runouts.len(), 1176);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RunoutIterator {
+    /// The cards not in the dead set, in index order; only `..len` is used
+    live: [Card; 52],
+    /// Number of live cards
+    len: usize,
+    /// Index of the turn card of the next pair
+    first: usize,
+    /// Index of the river card of the next pair
+    second: usize,
+}
+
+impl RunoutIterator {
+    /// Creates an iterator over the two-card runouts avoiding `dead`
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn new(dead: &[Card]) -> Self {
+        let dead = card_mask(dead);
+        let mut live = [Card { rank: 0, suit: 0 }; 52];
+        let mut len = 0;
+        for index in 0..52u8 {
+            if dead & 1 << index == 0 {
+                live[len] = Card {
+                    rank: index / 4,
+                    suit: index % 4,
+                };
+                len += 1;
+            }
+        }
+
+        Self {
+            live,
+            len,
+            first: 0,
+            second: 1,
+        }
+    }
+}
+
+impl Iterator for RunoutIterator {
+    type Item = [Card; 2];
+
+    fn next(&mut self) -> Option<[Card; 2]> {
+        if self.second >= self.len {
+            self.first += 1;
+            self.second = self.first + 1;
+            if self.second >= self.len {
+                return None;
+            }
+        }
+
+        let runout = [self.live[self.first], self.live[self.second]];
+        self.second += 1;
+        Some(runout)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The rest of the current turn card's pairs, then every later pair
+        let current = self.len.saturating_sub(self.second);
+        let later = self.len.saturating_sub(self.first + 1);
+        let remaining = current + later * later.saturating_sub(1) / 2;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for RunoutIterator {}
+
+impl std::iter::FusedIterator for RunoutIterator {}
+
+/// Computes hero's exact equity against a known villain hand
+///
+/// Every completion of the board from the unseen cards is enumerated, so a
+/// flop spot visits 990 runouts and a turn spot 44. Preflop enumeration visits
+/// over 1.7 million boards and is best run in release builds.
+///
+/// # Errors
+///
+/// Returns `PokerError::DuplicateCard` if any card appears more than once
+/// across the two hands and the board.
+pub fn equity_exact(
+    hero: &HoleCards,
+    villain: &HoleCards,
+    board: &Board,
+) -> Result<EquityResult, PokerError> {
+    let board_cards = board.visible_cards();
+    let dead = dead_cards(hero, villain, board_cards)?;
+
+    let evaluator = Evaluator::instance();
+    let mut result = EquityResult::default();
+    for_each_runout(&dead, 5 - board_cards.len(), |runout| {
+        let (hero_value, villain_value) = showdown(&evaluator, hero, villain, board_cards, runout);
+        result.record(hero_value, villain_value);
+    });
+    Ok(result)
+}
+
+/// Computes the same tallies as [`equity_exact`] across all available cores
+///
+/// Runouts are split by their first card and each share is enumerated on a
+/// rayon worker; the integer tallies are then summed, so the result is
+/// identical to the serial enumeration.
+///
+/// # Errors
+///
+/// Returns `PokerError::DuplicateCard` if any card appears more than once
+/// across the two hands and the board.
+#[cfg(feature = "parallel")]
+pub fn equity_exact_parallel(
+    hero: &HoleCards,
+    villain: &HoleCards,
+    board: &Board,
+) -> Result<EquityResult, PokerError> {
+    let board_cards = board.visible_cards();
+    let dead = dead_cards(hero, villain, board_cards)?;
+    let count = 5 - board_cards.len();
+    if count == 0 {
+        return equity_exact(hero, villain, board);
+    }
+
+    let evaluator = Evaluator::instance();
+    let live = live_cards(&dead);
+    let result = (0..=live.len() - count)
+        .into_par_iter()
+        .map(|first| {
+            let mut result = EquityResult::default();
+            let mut runout = Vec::with_capacity(count);
+            runout.push(live[first]);
+            visit_combinations(&live, first + 1, count, &mut runout, &mut |runout| {
+                let (hero_value, villain_value) =
+                    showdown(&evaluator, hero, villain, board_cards, runout);
+                result.record(hero_value, villain_value);
+            });
+            result
+        })
+        .reduce(EquityResult::default, |mut total, share| {
+            total.merge(&share);
+            total
+        });
+    Ok(result)
+}
+
+/// Deals `count` independent boards for one matchup and records each showdown
+///
+/// Every board comes from a freshly shuffled deck without the four hole
+/// cards, so the results are independent samples suited to variance and
+/// histogram studies. Tally them with [`EquityResult::record`] for an
+/// equity estimate.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::{simulate_matchups, EquityResult};
+/// use holdem_core::{Card, HoleCards};
+/// use rand::SeedableRng;
+/// use std::str::FromStr;
+///
+/// let card = |s| Card::from_str(s).unwrap();
+/// let aces = HoleCards::new(card("As"), card("Ah")).unwrap();
+/// let kings = HoleCards::new(card("Kd"), card("Kc")).unwrap();
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+///
+/// let mut tally = EquityResult::default();
+/// for result in simulate_matchups(&aces, &kings, 1000, &mut rng).unwrap() {
+///     tally.record(result.hero, result.villain);
+/// }
+/// assert_eq!(tally.total(), 1000);
+/// ```
+///
+/// # Errors
+///
+/// Returns `PokerError::DuplicateCard` if the two hands share a card.
+pub fn simulate_matchups(
+    hero: &HoleCards,
+    villain: &HoleCards,
+    count: usize,
+    rng: &mut impl rand::Rng,
+) -> Result<Vec<ShowdownResult>, PokerError> {
+    let dead = dead_cards(hero, villain, &[])?;
+    let mut stub = Deck::new();
+    for card in dead {
+        stub.deal_specific(card)?;
+    }
+
+    let evaluator = Evaluator::instance();
+    let mut results = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mut deck = stub.clone();
+        deck.shuffle(rng);
+        let board = deck.deal_full_board()?;
+        let (hero_value, villain_value) =
+            showdown(&evaluator, hero, villain, board.visible_cards(), &[]);
+        results.push(ShowdownResult {
+            board,
+            hero: hero_value,
+            villain: villain_value,
+        });
+    }
+    Ok(results)
+}
+
+/// Counts the runouts on which hero finishes with the nuts
+///
+/// Every completion of the board from cards unseen by hero is enumerated,
+/// and hero has the nuts when no two-card holding from the remaining cards
+/// beats hero's value on the final board. Hero's own cards are blockers, so
+/// an ace-high flush holding the ace counts even where [`Board::nuts`],
+/// which ignores hero's cards, would name a higher kicker. Returns `(nut
+/// runouts, total runouts)`; on the river the single runout is the board
+/// itself. Boards before the flop, or hero cards that collide with the board,
+/// give `(0, 0)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::runouts_to_nuts;
+/// use holdem_core::{Board, Card, HoleCards};
+/// use std::str::FromStr;
+///
+/// let hero = HoleCards::new(Card::from_str("Ah").unwrap(), Card::from_str("2h").unwrap()).unwrap();
+/// let board = Board::from_str("Kh 7h 3c 9d").unwrap();
+/// assert_eq!(runouts_to_nuts(&hero, &board), (7, 46));
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn runouts_to_nuts(hero: &HoleCards, board: &Board) -> (usize, usize) {
+    let board_cards = board.visible_cards();
+    if board_cards.len() < 3 || hero.cards.iter().any(|card| board_cards.contains(card)) {
+        return (0, 0);
+    }
+
+    let evaluator = Evaluator::instance();
+    let mut dead = hero.cards.to_vec();
+    dead.extend_from_slice(board_cards);
+
+    let mut nut_runouts = 0;
+    let mut total = 0;
+    for_each_runout(&dead, 5 - board_cards.len(), |runout| {
+        let mut final_dead = dead.clone();
+        final_dead.extend_from_slice(runout);
+        let mut cards = final_dead.clone();
+        let hero_value = evaluator.evaluate_cards(&cards);
+
+        // Hero's cards lead `cards`, so each villain holding replaces them
+        let mut beaten = false;
+        for_each_runout(&final_dead, 2, |villain| {
+            cards[..2].copy_from_slice(villain);
+            beaten |= evaluator.evaluate_cards(&cards) > hero_value;
+        });
+
+        if !beaten {
+            nut_runouts += 1;
+        }
+        total += 1;
+    });
+    (nut_runouts, total)
+}
+
+/// Computes hero's equity after the flop, after the turn and at showdown
+///
+/// The flop and turn entries enumerate the remaining runouts exactly; the
+/// river entry is the single showdown on the complete board.
+///
+/// # Errors
+///
+/// Returns `PokerError::DuplicateCard` if any card appears more than once.
+pub fn equity_by_street(
+    hero: &HoleCards,
+    villain: &HoleCards,
+    flop: [Card; 3],
+    turn: Card,
+    river: Card,
+) -> Result<[EquityResult; 3], PokerError> {
+    let flop_board = Board::new().with_flop(flop)?;
+    let turn_board = flop_board.clone().with_turn(turn)?;
+    let river_board = turn_board.clone().with_river(river)?;
+
+    Ok([
+        equity_exact(hero, villain, &flop_board)?,
+        equity_exact(hero, villain, &turn_board)?,
+        equity_exact(hero, villain, &river_board)?,
+    ])
+}
+
+/// Hero's expected share of `pot` at showdown against a weighted range
+///
+/// Each villain combo's exact equity is weighted by its range weight; combos
+/// that share a card with the hero or the board are impossible and skipped.
+/// Returns `0.0` when no villain combo remains, or when the hero's cards
+/// collide with the board. Enumeration cost is that of [`equity_exact`] per
+/// combo, so flop spots with wide ranges are best run in release builds.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn showdown_ev(
+    hero: &HoleCards,
+    villain_range: &WeightedRange,
+    board: &Board,
+    pot: f64,
+) -> f64 {
+    let mut weighted_equity = 0.0;
+    let mut total_weight = 0.0;
+    for (villain, weight) in villain_range.combos() {
+        if let Ok(result) = equity_exact(hero, villain, board) {
+            weighted_equity += weight * result.equity();
+            total_weight += weight;
+        }
+    }
+
+    if total_weight > 0.0 {
+        pot * weighted_equity / total_weight
+    } else {
+        0.0
+    }
+}
+
+/// Equity realization factors by hand type, as `(in_position, out_of_position)`
+///
+/// [`RealizationFactors::STANDARD`], also the [`Default`], holds rule-of-thumb
+/// factors for heads-up, single-raised pots at 100 big blinds. Other stack
+/// depths, bet sizes and opponents call for other factors, passed straight
+/// to [`equity_realization`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RealizationFactors {
+    /// Suited hands with at most one gap, such as JTs or 86s
+    pub suited_connected: (f64, f64),
+    /// Other suited hands
+    pub suited: (f64, f64),
+    /// Pocket pairs
+    pub pair: (f64, f64),
+    /// Offsuit hands with at most one gap
+    pub offsuit_connected: (f64, f64),
+    /// Other offsuit hands
+    pub offsuit: (f64, f64),
+}
+
+impl RealizationFactors {
+    /// Rule-of-thumb factors for heads-up, single-raised pots
+    ///
+    /// | Hand type                   | In position | Out of position |
+    /// |-----------------------------|-------------|-----------------|
+    /// | Suited, at most one gap     | 1.12        | 0.85            |
+    /// | Other suited                | 1.05        | 0.80            |
+    /// | Pocket pair                 | 1.05        | 0.85            |
+    /// | Offsuit, at most one gap    | 1.00        | 0.75            |
+    /// | Other offsuit               | 0.95        | 0.70            |
+    ///
+    /// These are not copied from a single published table. They sit inside
+    /// the ranges commonly quoted for solver output: roughly 95-115% of raw
+    /// equity in position and 70-85% out of position. Playable suited and
+    /// connected hands are at the top of each range and disconnected offsuit
+    /// hands at the bottom. Michael Acevedo's *Modern Poker Theory* (D&B
+    /// Poker, 2019) discusses realization by position and hand type.
+    pub const STANDARD: Self = Self {
+        suited_connected: (1.12, 0.85),
+        suited: (1.05, 0.80),
+        pair: (1.05, 0.85),
+        offsuit_connected: (1.00, 0.75),
+        offsuit: (0.95, 0.70),
+    };
+
+    /// The same `(in_position, out_of_position)` factors for every hand type
+    pub fn uniform(in_position: f64, out_of_position: f64) -> Self {
+        let factors = (in_position, out_of_position);
+        Self {
+            suited_connected: factors,
+            suited: factors,
+            pair: factors,
+            offsuit_connected: factors,
+            offsuit: factors,
+        }
+    }
+
+    /// The factor for `hero` in or out of position
+    pub fn factor(&self, hero: &HoleCards, in_position: bool) -> f64 {
+        let connected = hero.connectivity() <= 1;
+        let (in_position_factor, out_of_position_factor) = if hero.is_pair() {
+            self.pair
+        } else {
+            match (hero.is_suited(), connected) {
+                (true, true) => self.suited_connected,
+                (true, false) => self.suited,
+                (false, true) => self.offsuit_connected,
+                (false, false) => self.offsuit,
+            }
+        };
+        if in_position {
+            in_position_factor
+        } else {
+            out_of_position_factor
+        }
+    }
+}
+
+impl Default for RealizationFactors {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// Hero's raw equity against a range, scaled by how much of it is realized
+///
+/// Equity from [`showdown_ev`] assumes the hand always reaches showdown, but
+/// the player out of position is bet off some of it while the player in
+/// position realizes more. The factor for hero's hand type and position is
+/// looked up in `factors`, usually [`RealizationFactors::STANDARD`].
+///
+/// The result is capped at `1.0`, and is `0.0` whenever [`showdown_ev`] has
+/// no villain combo to compare against.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::{equity_realization, RealizationFactors};
+/// use holdem_core::range::WeightedRange;
+/// use holdem_core::{Board, HoleCards};
+/// use std::str::FromStr;
+///
+/// let hero = HoleCards::from_notation("JTs").unwrap();
+/// let mut villain = WeightedRange::new();
+/// villain.add_hand("AA", 1.0).unwrap();
+/// let board = Board::from_str("Kc 7d 2s").unwrap();
+/// let factors = RealizationFactors::STANDARD;
+///
+/// let in_position = equity_realization(&hero, &villain, &board, true, &factors);
+/// let out_of_position = equity_realization(&hero, &villain, &board, false, &factors);
+/// assert!(in_position > out_of_position);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn equity_realization(
+    hero: &HoleCards,
+    villain_range: &WeightedRange,
+    board: &Board,
+    in_position: bool,
+    factors: &RealizationFactors,
+) -> f64 {
+    let factor = factors.factor(hero, in_position);
+    (showdown_ev(hero, villain_range, board, 1.0) * factor).min(1.0)
+}
+
+/// Hero's expected gain from calling a river bet rather than folding
+///
+/// Villain has bet `bet` into `pot`, the pot before the bet. Calling wins
+/// the pot plus both bets with hero's [`showdown_ev`] equity against
+/// `villain_betting_range` and otherwise loses the call, so the result is
+/// `equity * (pot + 2 * bet) - bet`. Folding is worth zero, so a positive
+/// value means the call is profitable. The range should hold every combo
+/// villain bets, value hands and bluffs alike. Before the river the same
+/// formula assumes the hand checks down after the call. When no villain
+/// combo is possible the equity is zero and the result is `-bet`.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::river_call_ev;
+/// use holdem_core::range::WeightedRange;
+/// use holdem_core::{Board, HoleCards};
+/// use std::str::FromStr;
+///
+/// let hero = HoleCards::from_notation("KQs").unwrap();
+/// let board = Board::from_str("Ac Kd 7c 4h 2s").unwrap();
+/// let mut nuts = WeightedRange::new();
+/// nuts.add_hand("77", 1.0).unwrap();
+///
+/// assert_eq!(river_call_ev(&hero, &nuts, &board, 50.0, 100.0), -50.0);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn river_call_ev(
+    hero: &HoleCards,
+    villain_betting_range: &WeightedRange,
+    board: &Board,
+    bet: f64,
+    pot: f64,
+) -> f64 {
+    let equity = showdown_ev(hero, villain_betting_range, board, 1.0);
+    equity * (pot + 2.0 * bet) - bet
+}
+
+/// Expected number of next cards that put hero ahead, over a villain range
+///
+/// For each villain combo that is currently ahead of or tied with hero, the
+/// outs are the unseen cards that leave hero strictly ahead once dealt as
+/// the next community card. The counts are averaged by range weight; combos
+/// hero already beats, and combos that share a card with hero or the board,
+/// are left out. Against a single combo this is the usual outs count.
+/// Returns `0.0` before the flop, on the river, or when no villain combo is
+/// ahead.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::weighted_outs;
+/// use holdem_core::range::WeightedRange;
+/// use holdem_core::{Board, Card, HoleCards};
+/// use std::str::FromStr;
+///
+/// let card = |s| Card::from_str(s).unwrap();
+/// let hero = HoleCards::new(card("Jh"), card("Th")).unwrap();
+/// let board = Board::from_str("9h 8c 2h").unwrap();
+/// let mut aces = WeightedRange::new();
+/// aces.add(HoleCards::new(card("As"), card("Ad")).unwrap(), 1.0);
+///
+/// // Nine hearts plus the six other queens and sevens
+/// assert_eq!(weighted_outs(&hero, &board, &aces), 15.0);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn weighted_outs(hero: &HoleCards, board: &Board, villain_range: &WeightedRange) -> f64 {
+    let board_cards = board.visible_cards();
+    if !(3..5).contains(&board_cards.len())
+        || hero.cards.iter().any(|card| board_cards.contains(card))
+    {
+        return 0.0;
+    }
+
+    let evaluator = Evaluator::instance();
+    let value = |hole: &HoleCards, next: &[Card]| {
+        let mut cards = hole.cards.to_vec();
+        cards.extend_from_slice(board_cards);
+        cards.extend_from_slice(next);
+        evaluator.evaluate_cards(&cards)
+    };
+
+    let mut weighted = 0.0;
+    let mut total_weight = 0.0;
+    for (villain, weight) in villain_range.combos() {
+        let Ok(dead) = dead_cards(hero, villain, board_cards) else {
+            continue;
+        };
+        if value(hero, &[]) > value(villain, &[]) {
+            continue;
+        }
+
+        let mut outs = 0;
+        for_each_runout(&dead, 1, |next| {
+            if value(hero, next) > value(villain, next) {
+                outs += 1;
+            }
+        });
+        weighted += weight * outs as f64;
+        total_weight += weight;
+    }
+
+    if total_weight > 0.0 {
+        weighted / total_weight
+    } else {
+        0.0
+    }
+}
+
+/// Simulates hero against several villains at once, each with its own range
+///
+/// Every iteration deals each villain a combo from their range by weight,
+/// avoiding hero's cards, the board and the combos already dealt, then
+/// completes the board from the remaining deck. The result holds one tally
+/// per villain for hero against that villain alone, the aggregate tally
+/// against the best villain hand, and hero's share of the pot. Hero wins the
+/// aggregate only by beating every villain, so its win rate is how often
+/// hero scoops. The pot share splits every chop evenly, so a three-way chop
+/// is worth a third of the pot.
+///
+/// Iterations where no conflict-free deal is found are skipped. Returns
+/// empty tallies when hero collides with the board or a villain has no
+/// possible combo.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::equity_vs_ranges;
+/// use holdem_core::range::top_percent_range;
+/// use holdem_core::{Board, HoleCards};
+/// use rand::SeedableRng;
+///
+/// let hero = HoleCards::from_notation("JTs").unwrap();
+/// let villains = [top_percent_range(10.0), top_percent_range(20.0)];
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+///
+/// let results = equity_vs_ranges(&hero, &villains, &Board::new(), 1000, &mut rng);
+/// assert_eq!(results.per_villain.len(), 2);
+/// assert!(results.equity < results.per_villain[0].equity());
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn equity_vs_ranges(
+    hero: &HoleCards,
+    villains: &[WeightedRange],
+    board: &Board,
+    iterations: usize,
+    rng: &mut impl rand::Rng,
+) -> MultiwayEquity {
+    let mut results = MultiwayEquity {
+        per_villain: vec![EquityResult::default(); villains.len()],
+        ..MultiwayEquity::default()
+    };
+    let board_cards = board.visible_cards();
+    if hero.cards.iter().any(|card| board_cards.contains(card)) {
+        return results;
+    }
+
+    let known = card_mask(&hero.cards) | card_mask(board_cards);
+
+    // Each villain's combos that avoid hero and the board
+    let mut samplers = Vec::with_capacity(villains.len());
+    for range in villains {
+        let sampler = range.sampler(|villain| card_mask(&villain.cards) & known == 0);
+        if sampler.is_empty() {
+            return results;
+        }
+        samplers.push(sampler);
+    }
+
+    let evaluator = Evaluator::instance();
+    let mut dealt = Vec::with_capacity(villains.len());
+    let full_deck = live_cards(&[]);
+    let mut deck = Vec::with_capacity(52);
+    let mut pot_share = 0.0;
+    'deal: for _ in 0..iterations {
+        dealt.clear();
+        let mut used = known;
+        for sampler in &samplers {
+            // Redraw a bounded number of times when earlier villains block
+            let villain = (0..100).find_map(|_| {
+                let villain = sampler.sample(rng)?;
+                (card_mask(&villain.cards) & used == 0).then_some(villain)
+            });
+            let Some(villain) = villain else {
+                continue 'deal;
+            };
+            used |= card_mask(&villain.cards);
+            dealt.push(villain);
+        }
+
+        deck.clear();
+        deck.extend(
+            full_deck
+                .iter()
+                .filter(|card| card_mask(std::slice::from_ref(card)) & used == 0),
+        );
+        let (runout, _) = deck.partial_shuffle(rng, 5 - board_cards.len());
+
+        let mut values = Vec::with_capacity(dealt.len());
+        let mut hero_value = None;
+        for (villain, result) in dealt.iter().zip(results.per_villain.iter_mut()) {
+            let (hero, villain) = showdown(&evaluator, hero, villain, board_cards, runout);
+            result.record(hero, villain);
+            hero_value = Some(hero);
+            values.push(villain);
+        }
+        if let (Some(hero), Some(&best)) = (hero_value, values.iter().max()) {
+            results.aggregate.record(hero, best);
+            if hero >= best {
+                let chopping = values.iter().filter(|&&value| value == hero).count();
+                pot_share += 1.0 / (chopping + 1) as f64;
+            }
+        }
+    }
+
+    let total = results.aggregate.total();
+    if total > 0 {
+        results.equity = pot_share / total as f64;
+    }
+    results
+}
+
+/// Probability of hero finishing in each hand category by the river
+///
+/// The board is completed `iterations` times from the cards hero cannot
+/// see, and the category of hero's final hand is tallied, indexed by
+/// [`HandRank::as_u8`](crate::evaluator::HandRank::as_u8) from high card to
+/// royal flush. Opponents play no part, so this shows what hero tends to
+/// make rather than how often it wins. Returns all zeros when `iterations`
+/// is zero or hero's cards collide with the board.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::equity::final_category_distribution;
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::{Board, HoleCards};
+/// use rand::SeedableRng;
+/// use std::str::FromStr;
+///
+/// let hero = HoleCards::from_notation("AA").unwrap();
+/// let board = Board::from_str("Ah Ad 7h 7d 2c").unwrap();
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+///
+/// let distribution = final_category_distribution(&hero, &board, 10, &mut rng);
+/// assert_eq!(distribution[HandRank::FourOfAKind.as_u8() as usize], 1.0);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn final_category_distribution(
+    hero: &HoleCards,
+    board: &Board,
+    iterations: usize,
+    rng: &mut impl rand::Rng,
+) -> [f64; 10] {
+    let mut distribution = [0.0; 10];
+    let board_cards = board.visible_cards();
+    if iterations == 0 || hero.cards.iter().any(|card| board_cards.contains(card)) {
+        return distribution;
+    }
+
+    let mut dead = hero.cards.to_vec();
+    dead.extend_from_slice(board_cards);
+    let mut deck = live_cards(&dead);
+    let evaluator = Evaluator::instance();
+    let mut cards = [hero.cards[0]; 7];
+    cards[..dead.len()].copy_from_slice(&dead);
+    for _ in 0..iterations {
+        let (runout, _) = deck.partial_shuffle(rng, 5 - board_cards.len());
+        cards[dead.len()..].copy_from_slice(runout);
+        let rank = evaluator.evaluate_7_card(&cards).rank;
+        distribution[rank.as_u8() as usize] += 1.0;
+    }
+
+    for probability in &mut distribution {
+        *probability /= iterations as f64;
+    }
+    distribution
+}
+
+/// Collects the known cards, rejecting duplicates
+pub(crate) fn dead_cards(
+    hero: &HoleCards,
+    villain: &HoleCards,
+    board: &[Card],
+) -> Result<Vec<Card>, PokerError> {
+    let mut dead = Vec::with_capacity(4 + board.len());
+    for card in hero.cards.iter().chain(&villain.cards).chain(board) {
+        if dead.contains(card) {
+            return Err(PokerError::DuplicateCard(*card));
+        }
+        dead.push(*card);
+    }
+    Ok(dead)
+}
+
+/// Evaluates both hands on the board completed by `runout`
+pub(crate) fn showdown(
+    evaluator: &Evaluator,
+    hero: &HoleCards,
+    villain: &HoleCards,
+    board: &[Card],
+    runout: &[Card],
+) -> (HandValue, HandValue) {
+    let mut hero_cards = [hero.cards[0]; 7];
+    let mut villain_cards = [villain.cards[0]; 7];
+    hero_cards[..2].copy_from_slice(&hero.cards);
+    villain_cards[..2].copy_from_slice(&villain.cards);
+    hero_cards[2..2 + board.len()].copy_from_slice(board);
+    villain_cards[2..2 + board.len()].copy_from_slice(board);
+    hero_cards[2 + board.len()..].copy_from_slice(runout);
+    villain_cards[2 + board.len()..].copy_from_slice(runout);
+
+    (
+        evaluator.evaluate_7_card(&hero_cards),
+        evaluator.evaluate_7_card(&villain_cards),
+    )
+}
+
+/// Calls `f` with every `count`-card combination of the cards not in `dead`
+///
+/// Two-card runouts, the turn and river of a flop or a villain's hole cards,
+/// are the hot path and go through the allocation-free [`RunoutIterator`].
+pub(crate) fn for_each_runout<F: FnMut(&[Card])>(dead: &[Card], count: usize, mut f: F) {
+    if count == 2 {
+        RunoutIterator::new(dead).for_each(|runout| f(&runout));
+        return;
+    }
+
+    let live = live_cards(dead);
+    let mut runout = Vec::with_capacity(count);
+    visit_combinations(&live, 0, count, &mut runout, &mut f);
+}
+
+/// The cards of a full deck that are not in `dead`, in index order
+pub(crate) fn live_cards(dead: &[Card]) -> Vec<Card> {
+    (0..52u8)
+        .map(|index| Card {
+            rank: index / 4,
+            suit: index % 4,
+        })
+        .filter(|card| !dead.contains(card))
+        .collect()
+}
+
+/// Bitmask of `cards`, one bit per deck index `rank * 4 + suit`
+pub(crate) fn card_mask(cards: &[Card]) -> u64 {
+    cards
+        .iter()
+        .fold(0, |mask, card| mask | 1 << (card.rank * 4 + card.suit))
+}
+
+fn visit_combinations<F: FnMut(&[Card])>(
+    live: &[Card],
+    start: usize,
+    count: usize,
+    runout: &mut Vec<Card>,
+    f: &mut F,
+) {
+    if runout.len() == count {
+        f(runout);
+        return;
+    }
+    let needed = count - runout.len();
+    for i in start..=live.len() - needed {
+        runout.push(live[i]);
+        visit_combinations(live, i + 1, count, runout, f);
+        runout.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{card, hole};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_equity_result_rates() {
+        let result = EquityResult {
+            wins: 6,
+            ties: 2,
+            losses: 2,
+        };
+        assert_eq!(result.total(), 10);
+        assert!((result.equity() - 0.7).abs() < 1e-12);
+        assert!((result.win_rate() - 0.6).abs() < 1e-12);
+        assert!((result.tie_rate() - 0.2).abs() < 1e-12);
+        assert_eq!(EquityResult::default().equity(), 0.0);
+    }
+
+    #[test]
+    fn test_equity_exact_flop_counts_all_runouts() {
+        let board = Board::new()
+            .with_flop([card("Ac"), card("7h"), card("2d")])
+            .unwrap();
+        let result = equity_exact(&hole("As", "Ad"), &hole("Kh", "Qh"), &board).unwrap();
+        assert_eq!(result.total(), 990);
+        assert!(result.equity() > 0.9);
+    }
+
+    #[test]
+    fn test_equity_exact_rejects_duplicates() {
+        let board = Board::new()
+            .with_flop([card("As"), card("7h"), card("2d")])
+            .unwrap();
+        assert_eq!(
+            equity_exact(&hole("As", "Ad"), &hole("Kh", "Qh"), &board),
+            Err(PokerError::DuplicateCard(card("As")))
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_equity_matches_serial() {
+        let flop = Board::new()
+            .with_flop([card("Ac"), card("7h"), card("2d")])
+            .unwrap();
+        let river = flop
+            .clone()
+            .with_turn(card("5h"))
+            .unwrap()
+            .with_river(card("9h"))
+            .unwrap();
+
+        for board in [&flop, &river] {
+            let hero = hole("As", "Ad");
+            let villain = hole("Kh", "Qh");
+            let serial = equity_exact(&hero, &villain, board).unwrap();
+            let parallel = equity_exact_parallel(&hero, &villain, board).unwrap();
+            assert_eq!(parallel, serial);
+            assert_eq!(parallel.equity().to_bits(), serial.equity().to_bits());
+        }
+    }
+
+    #[test]
+    fn test_simulate_matchups_aces_against_kings() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1389);
+        let results =
+            simulate_matchups(&hole("As", "Ah"), &hole("Kd", "Kc"), 20_000, &mut rng).unwrap();
+        assert_eq!(results.len(), 20_000);
+        assert!(results.iter().all(|result| result.board.len() == 5));
+
+        let mut tally = EquityResult::default();
+        for result in &results {
+            tally.record(result.hero, result.villain);
+        }
+        // Aces are about an 82% favourite over kings
+        assert!((tally.equity() - 0.82).abs() < 0.02, "{}", tally.equity());
+        assert!(tally.win_rate() > tally.tie_rate());
+
+        assert!(simulate_matchups(&hole("As", "Ah"), &hole("As", "Kc"), 1, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_runouts_to_nuts_with_nut_flush_draw() {
+        let board = Board::from_str("Kh 7h 3c 9d").unwrap();
+        // Nine hearts remain; the 3h and 9h pair the board and allow a full house
+        assert_eq!(runouts_to_nuts(&hole("Ah", "2h"), &board), (7, 46));
+
+        let river = Board::from_str("Kh 7h 3c 9d 5h").unwrap();
+        assert_eq!(runouts_to_nuts(&hole("Ah", "2h"), &river), (1, 1));
+        assert_eq!(runouts_to_nuts(&hole("Ah", "2h"), &Board::new()), (0, 0));
+    }
+
+    #[test]
+    fn test_equity_by_street_outdrawn_on_river() {
+        // Top set against a backdoor flush draw that gets there on the river
+        let [flop, turn, river] = equity_by_street(
+            &hole("As", "Ad"),
+            &hole("Kh", "Qh"),
+            [card("Ac"), card("7h"), card("2d")],
+            card("5h"),
+            card("9h"),
+        )
+        .unwrap();
+
+        assert_eq!(turn.total(), 44);
+        assert_eq!(river.total(), 1);
+        assert!(flop.equity() > turn.equity());
+        assert!(turn.equity() > 0.0);
+        assert_eq!(river.equity(), 0.0);
+    }
+
+    #[test]
+    fn test_showdown_ev_against_ranges() {
+        let board = Board::new()
+            .with_flop([card("As"), card("Ks"), card("Qs")])
+            .unwrap()
+            .with_turn(card("2d"))
+            .unwrap()
+            .with_river(card("3c"))
+            .unwrap();
+
+        let mut sets = WeightedRange::new();
+        sets.add_hand("AA", 1.0).unwrap();
+        sets.add_hand("KK", 0.5).unwrap();
+
+        // The royal flush beats every set, a pair of fours loses to all of them
+        assert_eq!(showdown_ev(&hole("Js", "Ts"), &sets, &board, 120.0), 120.0);
+        assert_eq!(showdown_ev(&hole("4h", "4c"), &sets, &board, 120.0), 0.0);
+
+        // Chopping with one combo and beating the other wins three quarters
+        let mut mixed = WeightedRange::new();
+        mixed.add(hole("Jd", "Td"), 1.0);
+        mixed.add(hole("4d", "4s"), 1.0);
+        let ev = showdown_ev(&hole("Jh", "Th"), &mixed, &board, 100.0);
+        assert!((ev - 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_equity_realization_depends_on_position() {
+        let board = Board::from_str("Kc 7d 2s").unwrap();
+        let mut villain = WeightedRange::new();
+        villain.add_hand("QQ", 1.0).unwrap();
+
+        let hero = hole("Jh", "Th");
+        let factors = RealizationFactors::uniform(1.1, 0.8);
+        let raw = showdown_ev(&hero, &villain, &board, 1.0);
+        let in_position = equity_realization(&hero, &villain, &board, true, &factors);
+        let out_of_position = equity_realization(&hero, &villain, &board, false, &factors);
+
+        assert!((in_position - raw * 1.1).abs() < 1e-12);
+        assert!((out_of_position - raw * 0.8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_standard_realization_factors() {
+        let standard = RealizationFactors::default();
+        assert_eq!(standard, RealizationFactors::STANDARD);
+
+        let classes = [
+            standard.suited_connected,
+            standard.suited,
+            standard.pair,
+            standard.offsuit_connected,
+            standard.offsuit,
+        ];
+        for (in_position, out_of_position) in classes {
+            assert!(in_position > out_of_position);
+            assert!((0.95..=1.15).contains(&in_position));
+            assert!((0.70..=0.85).contains(&out_of_position));
+        }
+        assert!(standard.suited_connected.0 > standard.offsuit.0);
+        assert!(standard.suited_connected.1 > standard.offsuit.1);
+
+        // Suited connectors realize more than their raw equity in position
+        // and less out of position
+        let board = Board::from_str("Kc 7d 2s").unwrap();
+        let mut villain = WeightedRange::new();
+        villain.add_hand("QQ", 1.0).unwrap();
+        let hero = hole("Jh", "Th");
+        let raw = showdown_ev(&hero, &villain, &board, 1.0);
+        let in_position = equity_realization(&hero, &villain, &board, true, &standard);
+        let out_of_position = equity_realization(&hero, &villain, &board, false, &standard);
+        assert!((in_position - raw * 1.12).abs() < 1e-12);
+        assert!((out_of_position - raw * 0.85).abs() < 1e-12);
+
+        let offsuit = hole("Jh", "4c");
+        assert_eq!(standard.factor(&offsuit, true), 0.95);
+        assert_eq!(standard.factor(&hole("9s", "9d"), false), 0.85);
+    }
+
+    #[test]
+    fn test_equity_realization_uses_hand_type() {
+        let board = Board::from_str("Kc 7d 2s").unwrap();
+        let mut villain = WeightedRange::new();
+        villain.add_hand("QQ", 1.0).unwrap();
+
+        let hero = hole("Jh", "Th");
+        let factors = RealizationFactors {
+            suited_connected: (1.0, 1.0),
+            ..RealizationFactors::uniform(0.5, 0.5)
+        };
+        let raw = showdown_ev(&hero, &villain, &board, 1.0);
+        assert_eq!(
+            equity_realization(&hero, &villain, &board, false, &factors),
+            raw
+        );
+
+        let offsuit = hole("Jh", "Tc");
+        let raw = showdown_ev(&offsuit, &villain, &board, 1.0);
+        assert_eq!(
+            equity_realization(&offsuit, &villain, &board, false, &factors),
+            raw * 0.5
+        );
+    }
+
+    #[test]
+    fn test_equity_realization_is_capped() {
+        let board = Board::from_str("Kc 7d 2s").unwrap();
+        let mut villain = WeightedRange::new();
+        villain.add_hand("QQ", 1.0).unwrap();
+
+        let hero = hole("Kh", "Kd");
+        let factors = RealizationFactors::uniform(2.0, 2.0);
+        assert_eq!(
+            equity_realization(&hero, &villain, &board, true, &factors),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_runout_iterator_on_flop() {
+        let hero = hole("Ah", "Kh");
+        let villain = hole("Qs", "Qd");
+        let board = Board::from_str("Kc 7d 2s").unwrap();
+        let dead = dead_cards(&hero, &villain, board.visible_cards()).unwrap();
+
+        let runouts = RunoutIterator::new(&dead);
+        assert_eq!(runouts.len(), 990);
+
+        let runouts: Vec<[Card; 2]> = runouts.collect();
+        assert_eq!(runouts.len(), 990);
+        assert!(
+            runouts
+                .iter()
+                .all(|runout| runout[0] != runout[1]
+                    && !runout.iter().any(|card| dead.contains(card)))
+        );
+
+        let distinct: std::collections::HashSet<[Card; 2]> = runouts.iter().copied().collect();
+        assert_eq!(distinct.len(), 990);
+
+        let mut expected = Vec::new();
+        visit_combinations(&live_cards(&dead), 0, 2, &mut Vec::new(), &mut |runout| {
+            expected.push([runout[0], runout[1]])
+        });
+        assert_eq!(runouts, expected);
+    }
+
+    #[test]
+    fn test_weighted_outs_against_polarized_range() {
+        let hero = hole("Jh", "Th");
+        let board = Board::from_str("9h 8c 2h").unwrap();
+
+        let mut aces = WeightedRange::new();
+        aces.add(hole("As", "Ad"), 1.0);
+        assert_eq!(weighted_outs(&hero, &board, &aces), 15.0);
+
+        // The set of eights takes away the eight of hearts, and hero is
+        // already ahead of the missed draw, which drops out
+        let mut polarized = aces.clone();
+        polarized.add(hole("8d", "8s"), 1.0);
+        polarized.add(hole("4c", "3c"), 1.0);
+        assert_eq!(weighted_outs(&hero, &board, &polarized), 14.5);
+
+        let river = Board::from_str("9h 8c 2h 3d 4s").unwrap();
+        assert_eq!(weighted_outs(&hero, &river, &aces), 0.0);
+    }
+
+    #[test]
+    fn test_equity_vs_ranges_multiway() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1421);
+        let hero = HoleCards::from_notation("99").unwrap();
+        let mut premiums = WeightedRange::new();
+        let mut broadways = WeightedRange::new();
+        for hand in ["AA", "KK", "QQ", "AKs", "AKo"] {
+            premiums.add_hand(hand, 1.0).unwrap();
+        }
+        for hand in ["JJ", "TT", "AQs", "AQo", "KQs"] {
+            broadways.add_hand(hand, 1.0).unwrap();
+        }
+
+        let board = Board::new();
+        let both = [premiums.clone(), broadways.clone()];
+        let results = equity_vs_ranges(&hero, &both, &board, 3000, &mut rng);
+        assert_eq!(results.per_villain.len(), 2);
+        assert_eq!(results.aggregate.total(), results.per_villain[0].total());
+
+        let alone = |range: &WeightedRange, rng: &mut rand::rngs::StdRng| {
+            equity_vs_ranges(&hero, std::slice::from_ref(range), &board, 3000, rng).equity
+        };
+        let aggregate = results.equity;
+        assert!(
+            aggregate < alone(&premiums, &mut rng),
+            "aggregate {}",
+            aggregate
+        );
+        assert!(
+            aggregate < alone(&broadways, &mut rng),
+            "aggregate {}",
+            aggregate
+        );
+        assert!(results.per_villain[0].equity() < results.per_villain[1].equity());
+    }
+
+    #[test]
+    fn test_equity_vs_ranges_three_way_chop() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1421);
+        let hero = hole("2h", "3d");
+        let board = Board::from_str("As Kd Qh Jc Tc").unwrap();
+        let mut first = WeightedRange::new();
+        first.add(hole("4s", "5s"), 1.0);
+        let mut second = WeightedRange::new();
+        second.add(hole("6h", "7d"), 1.0);
+
+        // Everyone plays the board's broadway straight
+        let results = equity_vs_ranges(&hero, &[first, second], &board, 100, &mut rng);
+        assert_eq!(results.aggregate.ties, 100);
+        assert!((results.equity - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(results.aggregate.equity(), 0.5);
+    }
+
+    #[test]
+    fn test_final_category_distribution_four_flush() {
+        use crate::evaluator::HandRank;
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1423);
+        let hero = HoleCards::new(card("Ah"), card("5h")).unwrap();
+        let board = Board::from_str("Kh 9h 2c").unwrap();
+        let distribution = final_category_distribution(&hero, &board, 5000, &mut rng);
+
+        assert!((distribution.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        // Nine hearts among 47 cards make the flush about 35% of the time
+        let flush = distribution[HandRank::Flush.as_u8() as usize];
+        assert!((flush - 0.35).abs() < 0.03, "flush probability {}", flush);
+        assert_eq!(distribution[HandRank::FourOfAKind.as_u8() as usize], 0.0);
+    }
+
+    #[test]
+    fn test_river_call_ev_depends_on_bluffs() {
+        let hero = hole("Kh", "Qh");
+        let board = Board::from_str("As Kd 7c 4h 2s").unwrap();
+        let mut value = WeightedRange::new();
+        value.add_hand("AQo", 1.0).unwrap();
+        value.add_hand("77", 1.0).unwrap();
+
+        // Every value hand beats second pair, so calling just loses the bet
+        assert_eq!(river_call_ev(&hero, &value, &board, 100.0, 100.0), -100.0);
+
+        // A pot-sized bet needs a third of the range to be bluffs
+        let mut balanced = value.clone();
+        balanced.add_hand("JTs", 1.0).unwrap();
+        balanced.add_hand("T9s", 1.0).unwrap();
+        balanced.add_hand("98s", 1.0).unwrap();
+        let ev = river_call_ev(&hero, &balanced, &board, 100.0, 100.0);
+        assert!(ev > 0.0, "calling EV {}", ev);
+    }
+}
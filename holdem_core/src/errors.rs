@@ -47,6 +47,12 @@ pub enum PokerError {
     DuplicateCardsInDeal,
     /// New card duplicates existing board card
     DuplicateWithExistingBoardCard(Card),
+    /// Not enough cards left in the deck for the requested deal
+    NotEnoughCards { requested: usize, remaining: usize },
+    /// Card is not part of the hand
+    CardNotInHand(Card),
+    /// Card has already been dealt from the deck
+    CardNotInDeck(Card),
 }
 
 impl fmt::Display for PokerError {
@@ -148,6 +154,22 @@ impl fmt::Display for PokerError {
             PokerError::DuplicateWithExistingBoardCard(card) => {
                 write!(f, "New card duplicates existing board card: {}", card)
             }
+            PokerError::NotEnoughCards {
+                requested,
+                remaining,
+            } => {
+                write!(
+                    f,
+                    "Not enough cards in deck: requested {}, {} remaining",
+                    requested, remaining
+                )
+            }
+            PokerError::CardNotInHand(card) => {
+                write!(f, "Card not in hand: {}", card)
+            }
+            PokerError::CardNotInDeck(card) => {
+                write!(f, "Card already dealt from deck: {}", card)
+            }
         }
     }
 }
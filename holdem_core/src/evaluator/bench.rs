@@ -0,0 +1,110 @@
+//! Callable evaluation benchmark
+//!
+//! Measures table construction and 7-card evaluation throughput on the
+//! current machine, so downstream users can compare hardware and table
+//! storage without copying test code. Hands are drawn from a fixed seed and
+//! generated before timing starts, so runs measure evaluation alone and are
+//! comparable across machines.
+
+use super::errors::EvaluatorError;
+use super::evaluator::{build_five_card_table, Evaluator};
+use crate::equity::live_cards;
+use crate::Card;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Seed for the benchmark hands, fixed so that every run evaluates the same hands
+const BENCHMARK_SEED: u64 = 0x42_45_4e_43_48;
+
+/// Timings from [`Evaluator::benchmark_evaluation`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkReport {
+    /// 7-card hands evaluated per second
+    pub hands_per_sec: f64,
+    /// Mean time to evaluate one 7-card hand, in nanoseconds
+    pub ns_per_hand: f64,
+    /// Time to build the 5-card lookup table from scratch, in milliseconds
+    pub table_load_ms: f64,
+}
+
+impl Evaluator {
+    /// Benchmark table construction and `iterations` 7-card evaluations
+    ///
+    /// The 5-card lookup table is rebuilt from scratch and timed, bypassing
+    /// the copy that evaluators share, then `iterations` random 7-card hands
+    /// are evaluated. With zero iterations both throughput fields are zero.
+    /// Build in release mode for meaningful numbers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`Evaluator::new`] if the lookup tables could
+    /// not be built.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use holdem_core::evaluator::Evaluator;
+    ///
+    /// let report = Evaluator::benchmark_evaluation(1_000_000).unwrap();
+    /// println!("{:.0} hands/s, {:.1} ns/hand", report.hands_per_sec, report.ns_per_hand);
+    /// ```
+    pub fn benchmark_evaluation(iterations: usize) -> Result<BenchmarkReport, EvaluatorError> {
+        let start = Instant::now();
+        black_box(build_five_card_table());
+        let table_load_ms = start.elapsed().as_secs_f64() * 1000.0;
+        let evaluator = Evaluator::new()?;
+
+        let mut rng = StdRng::seed_from_u64(BENCHMARK_SEED);
+        let mut deck = live_cards(&[]);
+        let hands: Vec<[Card; 7]> = (0..iterations)
+            .map(|_| {
+                let (drawn, _) = deck.partial_shuffle(&mut rng, 7);
+                [
+                    drawn[0], drawn[1], drawn[2], drawn[3], drawn[4], drawn[5], drawn[6],
+                ]
+            })
+            .collect();
+
+        let start = Instant::now();
+        for hand in &hands {
+            black_box(evaluator.evaluate_7_card(black_box(hand)));
+        }
+        let elapsed = start.elapsed().as_secs_f64();
+
+        let (hands_per_sec, ns_per_hand) = if iterations == 0 {
+            (0.0, 0.0)
+        } else {
+            let elapsed = elapsed.max(f64::MIN_POSITIVE);
+            (
+                iterations as f64 / elapsed,
+                elapsed * 1e9 / iterations as f64,
+            )
+        };
+
+        Ok(BenchmarkReport {
+            hands_per_sec,
+            ns_per_hand,
+            table_load_ms,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_report_is_populated() {
+        let report = Evaluator::benchmark_evaluation(10_000).unwrap();
+        assert!(report.ns_per_hand > 0.0);
+        assert!(report.hands_per_sec > 0.0);
+        assert!(report.table_load_ms > 0.0);
+
+        let empty = Evaluator::benchmark_evaluation(0).unwrap();
+        assert_eq!(empty.hands_per_sec, 0.0);
+        assert_eq!(empty.ns_per_hand, 0.0);
+    }
+}
@@ -0,0 +1,209 @@
+//! Hand comparison with explanations
+//!
+//! Narrates why one hand beats another, for hand replayers and teaching
+//! tools: either the categories differ, or the first tie-breaking rank in
+//! which the two hands differ decides it.
+
+use super::evaluator::{rank_name, Evaluator, HandRank, HandValue};
+use crate::Hand;
+use std::cmp::Ordering;
+
+/// Outcome of comparing two hands, with a human-readable explanation
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandComparison {
+    /// How hand A compares to hand B
+    pub ordering: Ordering,
+    /// Value of hand A
+    pub a: HandValue,
+    /// Value of hand B
+    pub b: HandValue,
+    /// Why the comparison came out this way
+    pub explanation: String,
+}
+
+impl Evaluator {
+    /// Compare two hands and explain the deciding factor
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::Evaluator;
+    /// use holdem_core::Hand;
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// let a = Hand::from_notation("Ah Jh 9h 6h 2h").unwrap();
+    /// let b = Hand::from_notation("Kd Qd 9d 6d 2d").unwrap();
+    /// let result = evaluator.compare_verbose(&a, &b);
+    /// assert_eq!(
+    ///     result.explanation,
+    ///     "both have a flush; hand A wins on the high card: Ace vs King"
+    /// );
+    /// ```
+    pub fn compare_verbose(&self, a: &Hand, b: &Hand) -> HandComparison {
+        let a = self.evaluate_hand(a);
+        let b = self.evaluate_hand(b);
+        let ordering = a.cmp(&b);
+
+        let explanation = if a.rank != b.rank {
+            let (winner, best, other) = match ordering {
+                Ordering::Greater => ("A", a, b),
+                _ => ("B", b, a),
+            };
+            format!(
+                "hand {} wins with {} ({}) over {} ({})",
+                winner,
+                category(best.rank),
+                best.describe(),
+                category(other.rank),
+                other.describe()
+            )
+        } else {
+            let deciding = components(&a)
+                .into_iter()
+                .zip(components(&b))
+                .zip(labels(a.rank))
+                .find(|((a, b), _)| a != b);
+
+            match deciding {
+                Some(((rank_a, rank_b), label)) => {
+                    let (winner, best, other) = match ordering {
+                        Ordering::Greater => ("A", rank_a, rank_b),
+                        _ => ("B", rank_b, rank_a),
+                    };
+                    format!(
+                        "both have {}; hand {} wins on the {}: {} vs {}",
+                        category(a.rank),
+                        winner,
+                        label,
+                        rank_name(best),
+                        rank_name(other)
+                    )
+                }
+                None => format!("both have {}; the hands tie", a.describe()),
+            }
+        };
+
+        HandComparison {
+            ordering,
+            a,
+            b,
+            explanation,
+        }
+    }
+}
+
+/// Category name with its article, e.g. "a flush" or "two pair"
+fn category(rank: HandRank) -> &'static str {
+    match rank {
+        HandRank::HighCard => "high card",
+        HandRank::Pair => "one pair",
+        HandRank::TwoPair => "two pair",
+        HandRank::ThreeOfAKind => "three of a kind",
+        HandRank::Straight => "a straight",
+        HandRank::Flush => "a flush",
+        HandRank::FullHouse => "a full house",
+        HandRank::FourOfAKind => "four of a kind",
+        HandRank::StraightFlush => "a straight flush",
+        HandRank::RoyalFlush => "a royal flush",
+        #[cfg(feature = "wild_cards")]
+        HandRank::FiveOfAKind => "five of a kind",
+    }
+}
+
+/// Names of the tie-breaking ranks returned by [`components`]
+fn labels(rank: HandRank) -> &'static [&'static str] {
+    match rank {
+        HandRank::HighCard | HandRank::Flush => &[
+            "high card",
+            "second card",
+            "third card",
+            "fourth card",
+            "fifth card",
+        ],
+        HandRank::Pair => &["pair", "top kicker", "second kicker", "third kicker"],
+        HandRank::TwoPair => &["top pair", "bottom pair", "kicker"],
+        HandRank::ThreeOfAKind => &["trips", "top kicker", "second kicker"],
+        HandRank::Straight | HandRank::StraightFlush => &["high card"],
+        HandRank::FullHouse => &["trips", "pair"],
+        HandRank::FourOfAKind => &["quads", "kicker"],
+        HandRank::RoyalFlush => &[],
+        #[cfg(feature = "wild_cards")]
+        HandRank::FiveOfAKind => &["rank"],
+    }
+}
+
+/// Tie-breaking ranks of a value, most significant first
+fn components(value: &HandValue) -> Vec<u32> {
+    let v = value.value;
+    match value.rank {
+        HandRank::HighCard | HandRank::Flush => {
+            (0..13).rev().filter(|&rank| v & (1 << rank) != 0).collect()
+        }
+        HandRank::Pair => vec![v / 2197, v / 169 % 13, v / 13 % 13, v % 13],
+        HandRank::TwoPair => vec![v / 169, v / 13 % 13, v % 13],
+        HandRank::ThreeOfAKind => vec![v / 169, v / 13 % 13, v % 13],
+        HandRank::Straight | HandRank::StraightFlush => vec![v],
+        HandRank::FullHouse | HandRank::FourOfAKind => vec![v / 13, v % 13],
+        HandRank::RoyalFlush => Vec::new(),
+        #[cfg(feature = "wild_cards")]
+        HandRank::FiveOfAKind => vec![v],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compare(a: &str, b: &str) -> HandComparison {
+        Evaluator::instance().compare_verbose(
+            &Hand::from_notation(a).unwrap(),
+            &Hand::from_notation(b).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_decided_by_kicker() {
+        let result = compare("Ah Ad Kc 7s 2h", "As Ac Qd 7h 2d");
+        assert_eq!(result.ordering, Ordering::Greater);
+        assert_eq!(
+            result.explanation,
+            "both have one pair; hand A wins on the top kicker: King vs Queen"
+        );
+
+        let result = compare("9h 9d 5c 5s 2h", "9s 9c 5d 5h 3d");
+        assert_eq!(result.ordering, Ordering::Less);
+        assert_eq!(
+            result.explanation,
+            "both have two pair; hand B wins on the kicker: Three vs Two"
+        );
+    }
+
+    #[test]
+    fn test_decided_by_primary_rank() {
+        let result = compare("Ah Jh 9h 6h 2h", "Kd Qd 9d 6d 2d");
+        assert_eq!(
+            result.explanation,
+            "both have a flush; hand A wins on the high card: Ace vs King"
+        );
+
+        let result = compare("6h 6d 6c Ks Kh", "7h 7d 7c 2s 2h");
+        assert_eq!(result.ordering, Ordering::Less);
+        assert_eq!(
+            result.explanation,
+            "both have a full house; hand B wins on the trips: Seven vs Six"
+        );
+
+        let result = compare("Th 9d 8c 7s 6h", "Ah Ad Ac 7d 2c");
+        assert_eq!(
+            result.explanation,
+            "hand A wins with a straight (Ten-high straight) over three of a kind (three of a kind, Aces)"
+        );
+    }
+
+    #[test]
+    fn test_genuine_tie() {
+        let result = compare("Ah Kd Qc Js 9h", "As Kc Qd Jh 9c");
+        assert_eq!(result.ordering, Ordering::Equal);
+        assert_eq!(result.explanation, "both have Ace high; the hands tie");
+    }
+}
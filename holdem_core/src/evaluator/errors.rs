@@ -1,6 +1,10 @@
 //! Error types for the poker evaluator system
 
+use crate::errors::PokerError;
+use std::error::Error;
 use std::fmt;
+use std::io;
+use std::sync::Arc;
 
 /// Errors that can occur during hand evaluation
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +21,43 @@ pub enum EvaluatorError {
     MemoryAllocationError(String),
     /// Evaluation algorithm error
     EvaluationError(String),
+    /// Lookup table index outside the table bounds
+    HashOutOfBounds { index: usize, size: usize },
+    /// Underlying I/O failure, available through `source()`
+    Io(IoError),
+    /// Invalid poker data, available through `source()`
+    Poker(PokerError),
+}
+
+/// An I/O error that can be cloned and compared along with `EvaluatorError`
+///
+/// Clones share the original `std::io::Error`. Two values are equal when
+/// their kind and message match.
+#[derive(Debug, Clone)]
+pub struct IoError(Arc<io::Error>);
+
+impl IoError {
+    /// The original I/O error
+    pub fn inner(&self) -> &io::Error {
+        &self.0
+    }
+
+    /// The kind of the original I/O error
+    pub fn kind(&self) -> io::ErrorKind {
+        self.0.kind()
+    }
+}
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind() == other.kind() && self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 impl fmt::Display for EvaluatorError {
@@ -32,11 +73,28 @@ impl fmt::Display for EvaluatorError {
                 write!(f, "Memory allocation error: {}", msg)
             }
             EvaluatorError::EvaluationError(msg) => write!(f, "Evaluation error: {}", msg),
+            EvaluatorError::HashOutOfBounds { index, size } => {
+                write!(
+                    f,
+                    "Hash index {} out of bounds for table of size {}",
+                    index, size
+                )
+            }
+            EvaluatorError::Io(err) => write!(f, "File I/O error: {}", err),
+            EvaluatorError::Poker(err) => write!(f, "Poker error: {}", err),
         }
     }
 }
 
-impl std::error::Error for EvaluatorError {}
+impl Error for EvaluatorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EvaluatorError::Io(err) => Some(err.inner()),
+            EvaluatorError::Poker(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl EvaluatorError {
     /// Create a new invalid hand error
@@ -70,8 +128,43 @@ impl EvaluatorError {
     }
 }
 
-impl From<std::io::Error> for EvaluatorError {
-    fn from(err: std::io::Error) -> Self {
-        EvaluatorError::FileIoError(err.to_string())
+impl From<io::Error> for EvaluatorError {
+    fn from(err: io::Error) -> Self {
+        EvaluatorError::Io(IoError(Arc::new(err)))
+    }
+}
+
+impl From<PokerError> for EvaluatorError {
+    fn from(err: PokerError) -> Self {
+        EvaluatorError::Poker(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Card;
+
+    #[test]
+    fn test_poker_error_is_chained() {
+        let err = EvaluatorError::from(PokerError::DuplicateCard(Card::new(12, 3).unwrap()));
+        assert_eq!(err.to_string(), "Poker error: Duplicate card found: As");
+
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<PokerError>(),
+            Some(&PokerError::DuplicateCard(Card::new(12, 3).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_io_error_clones_compare_equal() {
+        let err = EvaluatorError::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert_eq!(err.clone(), err);
+        assert_ne!(
+            err,
+            EvaluatorError::from(io::Error::new(io::ErrorKind::NotFound, "other"))
+        );
+        assert_eq!(err.to_string(), "File I/O error: missing");
     }
 }
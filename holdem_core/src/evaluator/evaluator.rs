@@ -2,8 +2,35 @@
 
 use super::errors::EvaluatorError;
 use super::tables::JumpTable;
-use crate::{Card, Hand};
-use std::sync::Arc;
+use crate::equity::live_cards;
+use crate::{Board, Card, Hand, HoleCards};
+use std::sync::{Arc, OnceLock};
+
+/// Number of distinct 7-card hands, C(52, 7)
+const SEVEN_CARD_HANDS: u64 = 133_784_560;
+
+/// Number of distinct 5-card hands, and the size of the 5-card lookup table
+pub const FIVE_CARD_TABLE_SIZE: usize = 2_598_960;
+
+/// Binomial coefficients `C(n, k)` for `n < 52` and `k <= 5`
+const BINOMIAL: [[usize; 6]; 52] = {
+    let mut table = [[0usize; 6]; 52];
+    let mut n = 0;
+    while n < 52 {
+        table[n][0] = 1;
+        let mut k = 1;
+        while k < 6 {
+            table[n][k] = if n == 0 {
+                0
+            } else {
+                table[n - 1][k - 1] + table[n - 1][k]
+            };
+            k += 1;
+        }
+        n += 1;
+    }
+    table
+};
 
 /// Hand ranking enumeration
 #[derive(
@@ -30,6 +57,9 @@ pub enum HandRank {
     StraightFlush = 8,
     /// Royal flush
     RoyalFlush = 9,
+    /// Five of a kind, only reachable with wild cards
+    #[cfg(feature = "wild_cards")]
+    FiveOfAKind = 10,
 }
 
 impl HandRank {
@@ -46,6 +76,8 @@ impl HandRank {
             7 => Some(HandRank::FourOfAKind),
             8 => Some(HandRank::StraightFlush),
             9 => Some(HandRank::RoyalFlush),
+            #[cfg(feature = "wild_cards")]
+            10 => Some(HandRank::FiveOfAKind),
             _ => None,
         }
     }
@@ -63,6 +95,8 @@ impl HandRank {
             HandRank::FourOfAKind => 7,
             HandRank::StraightFlush => 8,
             HandRank::RoyalFlush => 9,
+            #[cfg(feature = "wild_cards")]
+            HandRank::FiveOfAKind => 10,
         }
     }
 }
@@ -93,102 +127,1134 @@ impl HandValue {
         Self::new(rank, strength)
     }
 
-    /// Convert to a combined u32 value
+    /// Pack rank and value into a single monotonically ordered `u32`
+    ///
+    /// Bit layout, most significant first:
+    ///
+    /// | Bits    | Contents                            |
+    /// |---------|-------------------------------------|
+    /// | 31..=16 | `HandRank::as_u8()` (0 = high card) |
+    /// | 15..=0  | within-category value               |
+    ///
+    /// Every value produced by the evaluator fits in 16 bits, so comparing
+    /// the packed integers orders hands exactly like `HandValue`'s `Ord`.
     pub fn as_u32(&self) -> u32 {
         ((self.rank.as_u8() as u32) << 16) | self.value
     }
+
+    /// Signed distance from `other` to this hand in the packed rank
+    ///
+    /// The difference of the two [`as_u32`](Self::as_u32) values:
+    /// positive when this hand is stronger, zero when they tie, and large
+    /// whenever the categories differ, since each category spans 2^16 ranks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::evaluator::evaluate_5_card_fast;
+    /// use holdem_core::Card;
+    /// use std::str::FromStr;
+    ///
+    /// let hand = |cards: [&str; 5]| evaluate_5_card_fast(&cards.map(|c| Card::from_str(c).unwrap()));
+    /// let nines = hand(["9h", "9d", "Ac", "Ks", "Qh"]);
+    /// let tens = hand(["Th", "Td", "Ac", "Ks", "Qh"]);
+    /// assert!(tens.rank_gap(&nines) > 0);
+    /// assert_eq!(nines.rank_gap(&tens), -tens.rank_gap(&nines));
+    /// ```
+    pub fn rank_gap(&self, other: &HandValue) -> i64 {
+        self.as_u32() as i64 - other.as_u32() as i64
+    }
+
+    /// Kicker ranks (0 = Two, 12 = Ace), highest first
+    ///
+    /// Kickers are the cards outside the made combination that still break
+    /// ties: three for a pair, one for two pair, two for trips, one for
+    /// quads, and the four cards below the top card of a high-card hand.
+    /// Straights, flushes and full houses have no kickers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::evaluator::evaluate_5_card_fast;
+    /// use holdem_core::Card;
+    /// use std::str::FromStr;
+    ///
+    /// let cards = ["9h", "9d", "Ac", "Ks", "Qh"].map(|c| Card::from_str(c).unwrap());
+    /// assert_eq!(evaluate_5_card_fast(&cards).kickers(), vec![12, 11, 10]);
+    /// ```
+    pub fn kickers(&self) -> Vec<u8> {
+        let value = self.value;
+        let ranks = match self.rank {
+            HandRank::HighCard => {
+                let top = highest_rank(value);
+                return (0..top as u8)
+                    .rev()
+                    .filter(|&rank| value & (1 << rank) != 0)
+                    .collect();
+            }
+            HandRank::Pair => vec![value / 169 % 13, value / 13 % 13, value % 13],
+            HandRank::TwoPair | HandRank::FourOfAKind => vec![value % 13],
+            HandRank::ThreeOfAKind => vec![value / 13 % 13, value % 13],
+            _ => Vec::new(),
+        };
+        ranks.into_iter().map(|rank| rank as u8).collect()
+    }
+
+    /// Describe the hand in plain English, e.g. "pair of Aces with King kicker"
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::evaluator::evaluate_5_card_fast;
+    /// use holdem_core::Card;
+    /// use std::str::FromStr;
+    ///
+    /// let cards = ["Ah", "Ad", "Kc", "7s", "2h"].map(|c| Card::from_str(c).unwrap());
+    /// let value = evaluate_5_card_fast(&cards);
+    /// assert_eq!(value.describe(), "pair of Aces with King kicker");
+    /// ```
+    pub fn describe(&self) -> String {
+        let value = self.value;
+        match self.rank {
+            HandRank::HighCard => format!("{} high", rank_name(highest_rank(value))),
+            HandRank::Pair => format!(
+                "pair of {} with {} kicker",
+                rank_plural(value / 2197),
+                rank_name(value / 169 % 13)
+            ),
+            HandRank::TwoPair => format!(
+                "two pair, {} and {}",
+                rank_plural(value / 169),
+                rank_plural(value / 13 % 13)
+            ),
+            HandRank::ThreeOfAKind => format!("three of a kind, {}", rank_plural(value / 169)),
+            HandRank::Straight => format!("{}-high straight", rank_name(value)),
+            HandRank::Flush => format!("{}-high flush", rank_name(highest_rank(value))),
+            HandRank::FullHouse => format!(
+                "{} full of {}",
+                rank_plural(value / 13),
+                rank_plural(value % 13)
+            ),
+            HandRank::FourOfAKind => format!("four of a kind, {}", rank_plural(value / 13)),
+            HandRank::StraightFlush => format!("{}-high straight flush", rank_name(value)),
+            HandRank::RoyalFlush => "royal flush".to_string(),
+            #[cfg(feature = "wild_cards")]
+            HandRank::FiveOfAKind => format!("five of a kind, {}", rank_plural(value)),
+        }
+    }
+}
+
+/// English name of a rank (0 = Two, 12 = Ace)
+pub(crate) fn rank_name(rank: u32) -> &'static str {
+    const NAMES: [&str; 13] = [
+        "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen",
+        "King", "Ace",
+    ];
+    NAMES.get(rank as usize).copied().unwrap_or("?")
+}
+
+/// Plural English name of a rank (0 = Twos, 12 = Aces)
+pub(crate) fn rank_plural(rank: u32) -> &'static str {
+    const NAMES: [&str; 13] = [
+        "Twos", "Threes", "Fours", "Fives", "Sixes", "Sevens", "Eights", "Nines", "Tens", "Jacks",
+        "Queens", "Kings", "Aces",
+    ];
+    NAMES.get(rank as usize).copied().unwrap_or("?")
+}
+
+/// Highest rank set in a 13-bit rank mask
+fn highest_rank(rank_mask: u32) -> u32 {
+    31 - rank_mask.max(1).leading_zeros()
+}
+
+/// Perfect hash of a 5-card hand into `0..FIVE_CARD_TABLE_SIZE`
+///
+/// Each card is mapped to an index `rank * 4 + suit`; the sorted indices are
+/// then ranked in the combinatorial number system, which gives every distinct
+/// set of five cards its own slot regardless of input order.
+///
+/// # Examples
+///
+/// ```
+/// use holdem_core::evaluator::evaluator::{perfect_hash_5_cards, FIVE_CARD_TABLE_SIZE};
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let cards = ["As", "Ks", "Qs", "Js", "Ts"].map(|c| Card::from_str(c).unwrap());
+/// let mut reversed = cards;
+/// reversed.reverse();
+///
+/// assert_eq!(perfect_hash_5_cards(&cards), perfect_hash_5_cards(&reversed));
+/// assert!(perfect_hash_5_cards(&cards) < FIVE_CARD_TABLE_SIZE);
+/// ```
+pub fn perfect_hash_5_cards(cards: &[Card; 5]) -> usize {
+    let mut indices = cards.map(|card| card_index(&card));
+    indices.sort_unstable();
+    indices
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| BINOMIAL[index][i + 1])
+        .sum()
+}
+
+/// Index of a card in `0..52`, ordered by rank and then suit
+fn card_index(card: &Card) -> usize {
+    (card.rank as usize) * 4 + card.suit as usize
+}
+
+/// Evaluate a 5-card hand directly from its cards
+///
+/// This is the reference evaluator that [`evaluate_5_card_fast`], and so the
+/// 5-card lookup table, is checked against. Values within each rank are
+/// packed so that a larger `value` always means a stronger hand and so that
+/// every value fits in the 16 bits reserved for it by [`HandValue::as_u32`].
+///
+/// # Examples
+///
+/// ```
+/// use holdem_core::evaluator::evaluator::evaluate_5_card_hand;
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let cards = ["5h", "4d", "3c", "2s", "Ah"].map(|c| Card::from_str(c).unwrap());
+/// assert_eq!(evaluate_5_card_hand(&cards).rank, HandRank::Straight);
+/// ```
+pub fn evaluate_5_card_hand(cards: &[Card; 5]) -> HandValue {
+    let mut counts = [0u8; 13];
+    let mut rank_mask = 0u32;
+    for card in cards {
+        counts[card.rank as usize] += 1;
+        rank_mask |= 1 << card.rank;
+    }
+
+    let is_flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let straight_high = straight_high_card(rank_mask as u16).map(u32::from);
+
+    if let Some(high) = straight_high {
+        if is_flush {
+            return if high == 12 {
+                HandValue::new(HandRank::RoyalFlush, 12)
+            } else {
+                HandValue::new(HandRank::StraightFlush, high)
+            };
+        }
+    }
+
+    // Rank groups ordered by size, then by rank, e.g. [(3, K), (2, 7)]
+    let mut groups: Vec<(u8, u32)> = (0..13u32)
+        .rev()
+        .filter(|&rank| counts[rank as usize] > 0)
+        .map(|rank| (counts[rank as usize], rank))
+        .collect();
+    groups.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+    match (groups[0].0, groups.get(1).map(|g| g.0)) {
+        (4, _) => HandValue::new(HandRank::FourOfAKind, groups[0].1 * 13 + groups[1].1),
+        (3, Some(2)) => HandValue::new(HandRank::FullHouse, groups[0].1 * 13 + groups[1].1),
+        _ if is_flush => HandValue::new(HandRank::Flush, rank_mask),
+        _ if straight_high.is_some() => {
+            HandValue::new(HandRank::Straight, straight_high.unwrap_or_default())
+        }
+        (3, _) => HandValue::new(
+            HandRank::ThreeOfAKind,
+            groups[0].1 * 169 + groups[1].1 * 13 + groups[2].1,
+        ),
+        (2, Some(2)) => HandValue::new(
+            HandRank::TwoPair,
+            groups[0].1 * 169 + groups[1].1 * 13 + groups[2].1,
+        ),
+        (2, _) => HandValue::new(
+            HandRank::Pair,
+            groups[0].1 * 2197 + groups[1].1 * 169 + groups[2].1 * 13 + groups[3].1,
+        ),
+        _ => HandValue::new(HandRank::HighCard, rank_mask),
+    }
+}
+
+/// Evaluate a 5-card hand without sorting or heap allocation
+///
+/// Produces exactly the same [`HandValue`] as [`evaluate_5_card_hand`], but
+/// detects flushes from per-suit rank masks and straights from the combined
+/// 13-bit rank mask, then reads rank groups off a fixed-size count array from
+/// the highest rank down.
+///
+/// # Examples
+///
+/// ```
+/// use holdem_core::evaluator::evaluator::{evaluate_5_card_fast, evaluate_5_card_hand};
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let cards = ["Kh", "Kd", "7c", "7s", "2h"].map(|c| Card::from_str(c).unwrap());
+/// assert_eq!(evaluate_5_card_fast(&cards), evaluate_5_card_hand(&cards));
+/// ```
+pub fn evaluate_5_card_fast(cards: &[Card; 5]) -> HandValue {
+    let mut rank_mask = 0u32;
+    let mut suit_masks = [0u32; 4];
+    let mut counts = [0u8; 13];
+    for card in cards {
+        let bit = 1 << card.rank;
+        rank_mask |= bit;
+        suit_masks[card.suit as usize] |= bit;
+        counts[card.rank as usize] += 1;
+    }
+
+    // Five distinct ranks: only high card, straight, flush or straight flush
+    if rank_mask.count_ones() == 5 {
+        let is_flush = suit_masks.iter().any(|mask| mask.count_ones() == 5);
+        return match (
+            is_flush,
+            straight_high_card(rank_mask as u16).map(u32::from),
+        ) {
+            (true, Some(12)) => HandValue::new(HandRank::RoyalFlush, 12),
+            (true, Some(high)) => HandValue::new(HandRank::StraightFlush, high),
+            (true, None) => HandValue::new(HandRank::Flush, rank_mask),
+            (false, Some(high)) => HandValue::new(HandRank::Straight, high),
+            (false, None) => HandValue::new(HandRank::HighCard, rank_mask),
+        };
+    }
+
+    let mut quads = None;
+    let mut trips = None;
+    let mut pairs = [0u32; 2];
+    let mut pair_count = 0;
+    let mut kickers = [0u32; 3];
+    let mut kicker_count = 0;
+    for rank in (0..13u32).rev() {
+        match counts[rank as usize] {
+            4 => quads = Some(rank),
+            3 => trips = Some(rank),
+            2 => {
+                pairs[pair_count] = rank;
+                pair_count += 1;
+            }
+            1 => {
+                kickers[kicker_count] = rank;
+                kicker_count += 1;
+            }
+            _ => {}
+        }
+    }
+
+    match (quads, trips, pair_count) {
+        (Some(quad), _, _) => HandValue::new(HandRank::FourOfAKind, quad * 13 + kickers[0]),
+        (None, Some(trip), 1) => HandValue::new(HandRank::FullHouse, trip * 13 + pairs[0]),
+        (None, Some(trip), _) => HandValue::new(
+            HandRank::ThreeOfAKind,
+            trip * 169 + kickers[0] * 13 + kickers[1],
+        ),
+        (None, None, 2) => HandValue::new(
+            HandRank::TwoPair,
+            pairs[0] * 169 + pairs[1] * 13 + kickers[0],
+        ),
+        _ => HandValue::new(
+            HandRank::Pair,
+            pairs[0] * 2197 + kickers[0] * 169 + kickers[1] * 13 + kickers[2],
+        ),
+    }
+}
+
+/// High card of the best straight in a 13-bit rank mask, if there is one
+///
+/// Bit `r` of `rank_mask` marks rank `r` (0 = Two, 12 = Ace); bits above
+/// the ace are ignored. The ace also plays below the deuce, so the wheel
+/// (A-2-3-4-5) is a straight with high card 3, the five. Masks may hold any
+/// number of ranks, and the highest straight among them is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::evaluator::evaluator::straight_high_card;
+///
+/// assert_eq!(straight_high_card(0b1_0000_0000_1111), Some(3)); // wheel
+/// assert_eq!(straight_high_card(0b1_1111_0000_0000), Some(12)); // broadway
+/// assert_eq!(straight_high_card(0b0_0000_0101_1011), None);
+/// ```
+pub fn straight_high_card(rank_mask: u16) -> Option<u8> {
+    // Bit 0 is the ace playing low, bit r + 1 is rank r
+    let mask = (rank_mask & 0x1FFF) as u32;
+    let extended = mask << 1 | mask >> 12;
+    (0..=9u8)
+        .rev()
+        .find(|&low| extended >> low & 0b11111 == 0b11111)
+        .map(|low| low + 3)
+}
+
+/// Build the 5-card lookup table, indexed by [`perfect_hash_5_cards`]
+pub(crate) fn build_five_card_table() -> Vec<HandValue> {
+    let deck = live_cards(&[]);
+
+    let mut table = vec![HandValue::new(HandRank::HighCard, 0); FIVE_CARD_TABLE_SIZE];
+    for a in 0..48 {
+        for b in a + 1..49 {
+            for c in b + 1..50 {
+                for d in c + 1..51 {
+                    for e in d + 1..52 {
+                        let cards = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                        table[perfect_hash_5_cards(&cards)] = evaluate_5_card_fast(&cards);
+                    }
+                }
+            }
+        }
+    }
+    table
+}
+
+/// Run `init` the first time `cell` is used and return the cached outcome
+fn init_once<T: Clone>(
+    cell: &OnceLock<Result<T, EvaluatorError>>,
+    init: impl FnOnce() -> Result<T, EvaluatorError>,
+) -> Result<T, EvaluatorError> {
+    cell.get_or_init(init).clone()
 }
 
 /// Main poker hand evaluator
 #[derive(Debug, Clone)]
 pub struct Evaluator {
-    /// Jump table for hand evaluation
-    tables: Arc<JumpTable>,
+    /// 5-card lookup table, shared by every evaluator in the process
+    five_card_table: Arc<Vec<HandValue>>,
 }
 
 impl Evaluator {
     /// Create a new evaluator instance
+    ///
+    /// The 5-card lookup table is built on first use and shared by every
+    /// evaluator in the process, so later calls are cheap.
+    ///
+    /// # Errors
+    ///
+    /// None at present: the table is computed in memory and never read
+    /// from disk, so this always returns `Ok`.
     pub fn new() -> Result<Self, EvaluatorError> {
-        let mut table = JumpTable::with_target_memory();
-        table.build().map_err(|e| {
-            EvaluatorError::table_init_failed(&format!("Failed to initialize lookup tables: {}", e))
-        })?;
+        static FIVE_CARD_TABLE: OnceLock<Arc<Vec<HandValue>>> = OnceLock::new();
+        let five_card_table = FIVE_CARD_TABLE
+            .get_or_init(|| Arc::new(build_five_card_table()))
+            .clone();
 
-        Ok(Self {
-            tables: Arc::new(table),
-        })
+        Ok(Self { five_card_table })
     }
 
     /// Get the global evaluator instance (singleton pattern)
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_instance`](Self::try_instance) returns an error, which
+    /// the in-memory table build never does.
     pub fn instance() -> Arc<Evaluator> {
-        use std::sync::OnceLock;
-        static INSTANCE: OnceLock<Evaluator> = OnceLock::new();
-        let evaluator =
-            INSTANCE.get_or_init(|| Evaluator::new().expect("Failed to create evaluator instance"));
-        Arc::new(evaluator.clone())
+        Self::try_instance().expect("Failed to create evaluator instance")
+    }
+
+    /// Get the global evaluator instance, returning initialization errors
+    ///
+    /// Initialization runs at most once per process and its outcome is
+    /// cached, so later calls return the same result without retrying.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`Evaluator::new`]. The table is built in
+    /// memory and needs no table directory, so initialization cannot fail
+    /// today and this always returns `Ok`.
+    pub fn try_instance() -> Result<Arc<Evaluator>, EvaluatorError> {
+        static INSTANCE: OnceLock<Result<Evaluator, EvaluatorError>> = OnceLock::new();
+        init_once(&INSTANCE, Evaluator::new).map(Arc::new)
     }
 
     /// Evaluate a 5-card hand
+    ///
+    /// The cards must be valid and distinct; use
+    /// [`try_evaluate_5_card`](Self::try_evaluate_5_card) for unchecked input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a card has an out-of-range rank or suit.
     pub fn evaluate_5_card(&self, cards: &[Card; 5]) -> HandValue {
-        // For now, return a placeholder implementation
-        // In a full implementation, this would use the lookup tables
-        HandValue::new(HandRank::HighCard, 0)
+        self.five_card_table[perfect_hash_5_cards(cards)]
+    }
+
+    /// Evaluate a 5-card hand, rejecting malformed input
+    ///
+    /// # Errors
+    ///
+    /// Returns `EvaluatorError::InvalidCardValue` for an out-of-range rank or
+    /// suit, `EvaluatorError::InvalidHand` for duplicate cards, and
+    /// `EvaluatorError::HashOutOfBounds` if the hash falls outside the table.
+    pub fn try_evaluate_5_card(&self, cards: &[Card; 5]) -> Result<HandValue, EvaluatorError> {
+        for (i, card) in cards.iter().enumerate() {
+            if card.rank > 12 || card.suit > 3 {
+                return Err(EvaluatorError::invalid_card_value(&format!(
+                    "rank {} suit {}",
+                    card.rank, card.suit
+                )));
+            }
+            if cards[..i].contains(card) {
+                return Err(EvaluatorError::invalid_hand(&format!(
+                    "Duplicate card: {}",
+                    card
+                )));
+            }
+        }
+        self.lookup_5_card(perfect_hash_5_cards(cards))
+    }
+
+    /// Look up a 5-card hash in the table, checking bounds
+    fn lookup_5_card(&self, index: usize) -> Result<HandValue, EvaluatorError> {
+        self.five_card_table
+            .get(index)
+            .copied()
+            .ok_or(EvaluatorError::HashOutOfBounds {
+                index,
+                size: self.five_card_table.len(),
+            })
     }
 
     /// Evaluate a 6-card hand
+    ///
+    /// Returns the best value among the six 5-card subsets.
     pub fn evaluate_6_card(&self, cards: &[Card; 6]) -> HandValue {
-        // For now, return a placeholder implementation
-        // In a full implementation, this would use the lookup tables
-        HandValue::new(HandRank::HighCard, 0)
+        let mut best = HandValue::new(HandRank::HighCard, 0);
+        for skip in 0..6 {
+            let mut subset = [cards[0]; 5];
+            let mut slot = 0;
+            for (i, card) in cards.iter().enumerate() {
+                if i != skip {
+                    subset[slot] = *card;
+                    slot += 1;
+                }
+            }
+            best = best.max(self.evaluate_5_card(&subset));
+        }
+        best
     }
 
     /// Evaluate a 7-card hand
+    ///
+    /// Returns the best value among the twenty-one 5-card subsets.
     pub fn evaluate_7_card(&self, cards: &[Card; 7]) -> HandValue {
-        // For now, return a placeholder implementation
-        // In a full implementation, this would use the lookup tables
-        HandValue::new(HandRank::HighCard, 0)
+        let mut best = HandValue::new(HandRank::HighCard, 0);
+        for skip_a in 0..7 {
+            for skip_b in skip_a + 1..7 {
+                let mut subset = [cards[0]; 5];
+                let mut slot = 0;
+                for (i, card) in cards.iter().enumerate() {
+                    if i != skip_a && i != skip_b {
+                        subset[slot] = *card;
+                        slot += 1;
+                    }
+                }
+                best = best.max(self.evaluate_5_card(&subset));
+            }
+        }
+        best
+    }
+
+    /// Evaluate between five and seven cards
+    ///
+    /// Any other card count yields the lowest possible high-card value.
+    pub fn evaluate_cards(&self, cards: &[Card]) -> HandValue {
+        match cards.len() {
+            5 => self.evaluate_5_card(&[cards[0], cards[1], cards[2], cards[3], cards[4]]),
+            6 => {
+                self.evaluate_6_card(&[cards[0], cards[1], cards[2], cards[3], cards[4], cards[5]])
+            }
+            7 => self.evaluate_7_card(&[
+                cards[0], cards[1], cards[2], cards[3], cards[4], cards[5], cards[6],
+            ]),
+            _ => HandValue::new(HandRank::HighCard, 0),
+        }
     }
 
     /// Evaluate a hand from hole cards and board
     pub fn evaluate_hand(&self, hand: &Hand) -> HandValue {
-        let cards = hand.cards();
-        match cards.len() {
-            5 => {
-                let card_array: [Card; 5] = cards
-                    .try_into()
-                    .unwrap_or_else(|_| panic!("Expected 5 cards, got {}", cards.len()));
-                self.evaluate_5_card(&card_array)
+        self.evaluate_cards(hand.cards())
+    }
+
+    /// Evaluate hole cards together with whatever the board shows
+    ///
+    /// Five cards are evaluated on the flop, six on the turn and seven on the
+    /// river, so the same holding can be tracked street by street without
+    /// building a [`Hand`]. Before the flop there is no five-card hand, and
+    /// the lowest high-card value is returned. The hole cards must not appear
+    /// on the board; debug builds assert this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::{Evaluator, HandRank};
+    /// use holdem_core::{Board, HoleCards};
+    /// use std::str::FromStr;
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// let hole = HoleCards::from_notation("AKs").unwrap();
+    /// let board = Board::from_str("Ad 7c 2h").unwrap();
+    /// assert_eq!(evaluator.evaluate_holding(&hole, &board).rank, HandRank::Pair);
+    /// ```
+    pub fn evaluate_holding(&self, hole: &HoleCards, board: &Board) -> HandValue {
+        self.value_with(&hole.cards, board.visible_cards(), &[])
+    }
+
+    /// All 7,462 distinct 5-card hand values, weakest first
+    ///
+    /// Every 5-card hand has exactly one of these values, so the list spans
+    /// the whole strength scale from 7-5-4-3-2 offsuit up to a royal flush.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::{Evaluator, HandRank};
+    ///
+    /// let ranks = Evaluator::instance().enumerate_distinct_ranks();
+    /// assert_eq!(ranks.len(), 7462);
+    /// assert_eq!(ranks.last().unwrap().rank, HandRank::RoyalFlush);
+    /// ```
+    pub fn enumerate_distinct_ranks(&self) -> Vec<HandValue> {
+        self.distinct_classes().to_vec()
+    }
+
+    /// The distinct 5-card hand values in ascending order, built once
+    fn distinct_classes(&self) -> &'static [HandValue] {
+        static CLASSES: OnceLock<Vec<HandValue>> = OnceLock::new();
+        CLASSES.get_or_init(|| {
+            let mut classes = self.five_card_table.to_vec();
+            classes.sort_unstable();
+            classes.dedup();
+            classes
+        })
+    }
+
+    /// Percentile of a hand value among the 7,462 distinct 5-card hand classes
+    ///
+    /// Returns `0.0` for the weakest class (7-5-4-3-2 offsuit) and `1.0` for
+    /// a royal flush.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::{Evaluator, HandRank, HandValue};
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// assert_eq!(evaluator.percentile(HandValue::new(HandRank::RoyalFlush, 12)), 1.0);
+    /// ```
+    pub fn percentile(&self, value: HandValue) -> f64 {
+        let classes = self.distinct_classes();
+        let position = classes.partition_point(|class| *class < value);
+        position.min(classes.len() - 1) as f64 / (classes.len() - 1) as f64
+    }
+
+    /// Fraction of all 133,784,560 seven-card hands weaker than `value`
+    ///
+    /// Unlike [`percentile`](Self::percentile), which weighs every 5-card
+    /// class equally, this weighs each value by how often a random 7-card
+    /// hand makes it. A royal flush returns just under `1.0`, and any value
+    /// no 7-card hand can fall below (such as 7-5-4-3-2) returns `0.0`.
+    ///
+    /// The distribution is built on first use by counting suit assignments
+    /// per rank multiset, which takes a fraction of a second.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::{Evaluator, HandRank, HandValue};
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// assert!(evaluator.rank_percentile_7card(HandValue::new(HandRank::RoyalFlush, 12)) > 0.9999);
+    /// ```
+    pub fn rank_percentile_7card(&self, value: HandValue) -> f64 {
+        static DISTRIBUTION: OnceLock<Vec<(HandValue, u64)>> = OnceLock::new();
+        let distribution = DISTRIBUTION.get_or_init(|| self.seven_card_distribution());
+
+        let position = distribution.partition_point(|(class, _)| *class < value);
+        let weaker = distribution
+            .get(position)
+            .map_or(SEVEN_CARD_HANDS, |(_, weaker)| *weaker);
+        weaker as f64 / SEVEN_CARD_HANDS as f64
+    }
+
+    /// Every 7-card hand value paired with the number of hands weaker than it
+    ///
+    /// Hands with five or more cards of one suit can hold nothing better than
+    /// their flush, so they are counted per suited rank set. Every other hand
+    /// is valued by its rank multiset alone, counting the suit assignments
+    /// that avoid a flush.
+    fn seven_card_distribution(&self) -> Vec<(HandValue, u64)> {
+        fn choose(n: u64, k: u64) -> u64 {
+            (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+        }
+
+        fn visit(rank: u8, left: u8, counts: &mut [u8; 13], f: &mut impl FnMut(&[u8; 13])) {
+            if left == 0 {
+                f(counts);
+                return;
             }
-            6 => {
-                let card_array: [Card; 6] = cards
-                    .try_into()
-                    .unwrap_or_else(|_| panic!("Expected 6 cards, got {}", cards.len()));
-                self.evaluate_6_card(&card_array)
+            if rank == 13 {
+                return;
             }
-            7 => {
-                let card_array: [Card; 7] = cards
-                    .try_into()
-                    .unwrap_or_else(|_| panic!("Expected 7 cards, got {}", cards.len()));
-                self.evaluate_7_card(&card_array)
+            for count in 0..=left.min(4) {
+                counts[rank as usize] = count;
+                visit(rank + 1, left - count, counts, f);
             }
-            _ => HandValue::new(HandRank::HighCard, 0),
+            counts[rank as usize] = 0;
+        }
+
+        let mut values: Vec<(HandValue, u64)> = Vec::new();
+
+        for suited in 0..1u16 << 13 {
+            let size = suited.count_ones() as u64;
+            if !(5..=7).contains(&size) {
+                continue;
+            }
+            let cards: Vec<Card> = (0..13u8)
+                .filter(|rank| suited & 1 << rank != 0)
+                .map(|rank| Card { rank, suit: 0 })
+                .collect();
+            values.push((self.evaluate_cards(&cards), 4 * choose(39, 7 - size)));
         }
+
+        visit(0, 7, &mut [0; 13], &mut |counts| {
+            let ranks: Vec<u8> = (0..13u8).filter(|&r| counts[r as usize] > 0).collect();
+            let all_suits: u64 = counts.iter().map(|&c| choose(4, c as u64)).product();
+
+            let mut flush_suits = 0;
+            if ranks.len() >= 5 {
+                for subset in 0..1u32 << ranks.len() {
+                    if subset.count_ones() < 5 {
+                        continue;
+                    }
+                    flush_suits += ranks
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &rank)| {
+                            let in_suit = (subset >> i & 1) as u8;
+                            let rest = counts[rank as usize] - in_suit;
+                            if rest > 3 {
+                                0
+                            } else {
+                                choose(3, rest as u64)
+                            }
+                        })
+                        .product::<u64>();
+                }
+            }
+
+            let cards: Vec<Card> = ranks
+                .iter()
+                .flat_map(|&rank| (0..counts[rank as usize]).map(move |_| rank))
+                .enumerate()
+                .map(|(i, rank)| Card {
+                    rank,
+                    suit: (i % 4) as u8,
+                })
+                .collect();
+            values.push((self.evaluate_cards(&cards), all_suits - 4 * flush_suits));
+        });
+
+        values.sort_unstable();
+        let mut distribution: Vec<(HandValue, u64)> = Vec::new();
+        let mut weaker = 0;
+        for (value, count) in values {
+            match distribution.last() {
+                Some((last, _)) if *last == value => {}
+                _ => distribution.push((value, weaker)),
+            }
+            weaker += count;
+        }
+        distribution
+    }
+
+    /// Get the 5-card lookup table, indexed by [`perfect_hash_5_cards`]
+    pub(crate) fn five_card_table(&self) -> &[HandValue] {
+        &self.five_card_table
     }
 
     /// Get the jump table
+    ///
+    /// Evaluation does not use the jump table, so it is only built the first
+    /// time it is requested and then shared by every evaluator in the
+    /// process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table cannot be built.
     pub fn tables(&self) -> &JumpTable {
-        &self.tables
+        static TABLES: OnceLock<JumpTable> = OnceLock::new();
+        TABLES.get_or_init(|| {
+            let mut table = JumpTable::with_target_memory();
+            table
+                .build()
+                .unwrap_or_else(|e| panic!("Failed to initialize lookup tables: {}", e));
+            table
+        })
     }
 
     /// Validate the evaluator state
     pub fn validate(&self) -> Result<(), EvaluatorError> {
-        // Basic validation - check if tables exist and have content
-        if self.tables.size > 0 {
+        // Every distinct 5-card hand must have a slot in the lookup table
+        if self.five_card_table.len() == FIVE_CARD_TABLE_SIZE {
             Ok(())
         } else {
             Err(EvaluatorError::table_init_failed(
-                "Jump table not initialized",
+                "5-card lookup table not initialized",
             ))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn cards(notation: [&str; 5]) -> [Card; 5] {
+        notation.map(|c| Card::from_str(c).unwrap())
+    }
+
+    #[test]
+    fn test_rank_percentile_7card() {
+        let evaluator = Evaluator::instance();
+        let royal = HandValue::new(HandRank::RoyalFlush, 12);
+        // 4,324 seven-card hands contain a royal flush
+        assert_eq!(
+            evaluator.rank_percentile_7card(royal),
+            1.0 - 4324.0 / SEVEN_CARD_HANDS as f64
+        );
+
+        let seven_high = evaluator.evaluate_5_card(&cards(["7h", "5d", "4c", "3s", "2h"]));
+        assert_eq!(evaluator.rank_percentile_7card(seven_high), 0.0);
+
+        // 23,294,460 seven-card hands are no better than high card
+        let pair = HandValue::new(HandRank::Pair, 0);
+        let high_card_share = evaluator.rank_percentile_7card(pair);
+        assert!((high_card_share - 23_294_460.0 / SEVEN_CARD_HANDS as f64).abs() < 1e-12);
+        assert!(evaluator.rank_percentile_7card(HandValue::new(HandRank::Flush, 0)) > 0.9);
+    }
+
+    #[test]
+    fn test_hand_rank_u8_round_trip() {
+        for value in 0..10u8 {
+            let rank = HandRank::from_u8(value).unwrap();
+            assert_eq!(rank.as_u8(), value);
+        }
+        #[cfg(not(feature = "wild_cards"))]
+        assert_eq!(HandRank::from_u8(10), None);
+        #[cfg(feature = "wild_cards")]
+        assert_eq!(HandRank::from_u8(10), Some(HandRank::FiveOfAKind));
+        assert_eq!(HandRank::from_u8(u8::MAX), None);
+    }
+
+    #[test]
+    fn test_as_u32_matches_hand_value_order() {
+        let evaluator = Evaluator::instance();
+        let mut values = evaluator.five_card_table().to_vec();
+        values.sort();
+        values.dedup();
+
+        for pair in values.windows(2) {
+            assert!(pair[0].as_u32() < pair[1].as_u32());
+        }
+        assert_eq!(values[0].rank, HandRank::HighCard);
+        assert_eq!(values[values.len() - 1].rank, HandRank::RoyalFlush);
+    }
+
+    #[test]
+    fn test_straight_high_card() {
+        let mask = |ranks: &[u8]| ranks.iter().fold(0u16, |mask, rank| mask | 1 << rank);
+
+        assert_eq!(straight_high_card(mask(&[12, 0, 1, 2, 3])), Some(3));
+        assert_eq!(straight_high_card(mask(&[8, 9, 10, 11, 12])), Some(12));
+        assert_eq!(straight_high_card(mask(&[12, 0, 1, 2, 4])), None);
+        assert_eq!(straight_high_card(mask(&[11, 12, 0, 1, 2])), None);
+
+        // The six-high straight outranks the wheel it contains
+        assert_eq!(straight_high_card(mask(&[12, 0, 1, 2, 3, 4])), Some(4));
+        assert_eq!(straight_high_card(mask(&[5, 6, 7, 8, 9, 0, 12])), Some(9));
+        assert_eq!(straight_high_card(0), None);
+    }
+
+    #[test]
+    fn test_rank_gap() {
+        let hand =
+            |cards: [&str; 5]| evaluate_5_card_fast(&cards.map(|c| Card::from_str(c).unwrap()));
+        let royal = hand(["As", "Ks", "Qs", "Js", "Ts"]);
+        let high_card = hand(["7h", "5d", "4c", "3s", "2h"]);
+
+        // Nine categories apart, so the gap spans at least eight full categories
+        assert!(royal.rank_gap(&high_card) > 8 << 16);
+        assert_eq!(high_card.rank_gap(&royal), -royal.rank_gap(&high_card));
+        assert_eq!(royal.rank_gap(&royal), 0);
+        assert_eq!(high_card.rank_gap(&hand(["7d", "5c", "4s", "3h", "2d"])), 0);
+    }
+
+    #[test]
+    fn test_hand_value_describe() {
+        let cases = [
+            (["As", "Ks", "Qs", "Js", "Ts"], "royal flush"),
+            (["5h", "4h", "3h", "2h", "Ah"], "Five-high straight flush"),
+            (["9h", "9d", "9c", "9s", "5h"], "four of a kind, Nines"),
+            (["6h", "6d", "6c", "Ks", "Kh"], "Sixes full of Kings"),
+            (["Kh", "9h", "7h", "4h", "2h"], "King-high flush"),
+            (["Th", "9d", "8c", "7s", "6h"], "Ten-high straight"),
+            (["9h", "9d", "9c", "5s", "4h"], "three of a kind, Nines"),
+            (["9h", "9d", "5c", "5s", "4h"], "two pair, Nines and Fives"),
+            (
+                ["Ah", "Ad", "Kc", "7s", "2h"],
+                "pair of Aces with King kicker",
+            ),
+            (["Kh", "9d", "6c", "5s", "4h"], "King high"),
+        ];
+        for (notation, description) in cases {
+            assert_eq!(
+                evaluate_5_card_fast(&cards(notation)).describe(),
+                description
+            );
+        }
+    }
+
+    #[test]
+    fn test_hand_value_kickers() {
+        let pair = evaluate_5_card_fast(&cards(["9h", "9d", "Ac", "4s", "Qh"]));
+        assert_eq!(pair.kickers(), vec![12, 10, 2]);
+
+        let two_pair = evaluate_5_card_fast(&cards(["9h", "9d", "5c", "5s", "Kh"]));
+        assert_eq!(two_pair.kickers(), vec![11]);
+
+        let trips = evaluate_5_card_fast(&cards(["9h", "9d", "9c", "5s", "Kh"]));
+        assert_eq!(trips.kickers(), vec![11, 3]);
+
+        let high_card = evaluate_5_card_fast(&cards(["Kh", "9d", "6c", "5s", "4h"]));
+        assert_eq!(high_card.kickers(), vec![7, 4, 3, 2]);
+
+        let straight = evaluate_5_card_fast(&cards(["Th", "9d", "8c", "7s", "6h"]));
+        assert!(straight.kickers().is_empty());
+    }
+
+    #[test]
+    fn test_reference_evaluator_ranks() {
+        let cases = [
+            (["As", "Ks", "Qs", "Js", "Ts"], HandRank::RoyalFlush),
+            (["9h", "8h", "7h", "6h", "5h"], HandRank::StraightFlush),
+            (["9h", "9d", "9c", "9s", "5h"], HandRank::FourOfAKind),
+            (["9h", "9d", "9c", "5s", "5h"], HandRank::FullHouse),
+            (["Kh", "9h", "7h", "4h", "2h"], HandRank::Flush),
+            (["5h", "4d", "3c", "2s", "Ah"], HandRank::Straight),
+            (["9h", "9d", "9c", "5s", "4h"], HandRank::ThreeOfAKind),
+            (["9h", "9d", "5c", "5s", "4h"], HandRank::TwoPair),
+            (["9h", "9d", "6c", "5s", "4h"], HandRank::Pair),
+            (["Kh", "9d", "6c", "5s", "4h"], HandRank::HighCard),
+        ];
+        for (notation, rank) in cases {
+            assert_eq!(evaluate_5_card_hand(&cards(notation)).rank, rank);
+        }
+
+        let wheel = evaluate_5_card_hand(&cards(["5h", "4d", "3c", "2s", "Ah"]));
+        let six_high = evaluate_5_card_hand(&cards(["6h", "5d", "4c", "3s", "2h"]));
+        assert!(six_high > wheel);
+
+        let kings_up = evaluate_5_card_hand(&cards(["Kh", "Kd", "2c", "2s", "3h"]));
+        let queens_up = evaluate_5_card_hand(&cards(["Qh", "Qd", "Jc", "Js", "Ah"]));
+        assert!(kings_up > queens_up);
+    }
+
+    #[test]
+    fn test_lookup_table_matches_reference() {
+        let evaluator = Evaluator::instance();
+        let table = &evaluator.five_card_table;
+        assert_eq!(table.len(), FIVE_CARD_TABLE_SIZE);
+
+        let mut distinct: Vec<u32> = table.iter().map(|value| value.as_u32()).collect();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 7462);
+
+        let hand = cards(["Qh", "Qd", "Jc", "Js", "Ah"]);
+        assert_eq!(
+            evaluator.evaluate_5_card(&hand),
+            evaluate_5_card_hand(&hand)
+        );
+    }
+
+    #[test]
+    fn test_try_instance_shares_tables() {
+        let first = Evaluator::try_instance().unwrap();
+        let second = Evaluator::instance();
+        assert!(Arc::ptr_eq(&first.five_card_table, &second.five_card_table));
+        assert!(Evaluator::new().unwrap().validate().is_ok());
+    }
+
+    #[test]
+    fn test_jump_table_is_built_once() {
+        let first = Evaluator::new().unwrap();
+        let second = Evaluator::new().unwrap();
+        assert!(first.tables().size > 0);
+        assert!(std::ptr::eq(first.tables(), second.tables()));
+    }
+
+    #[test]
+    fn test_init_once_caches_the_first_outcome() {
+        // Evaluator::new cannot fail, so the caching of an error is checked
+        // on init_once directly with an error of the test's own making
+        let cell = OnceLock::new();
+        let mut attempts = 0;
+        let result: Result<u32, _> = init_once(&cell, || {
+            attempts += 1;
+            Err(EvaluatorError::table_init_failed("first attempt"))
+        });
+        assert!(matches!(result, Err(EvaluatorError::TableInitFailed(_))));
+
+        let retry = init_once(&cell, || {
+            attempts += 1;
+            Ok(1)
+        });
+        assert_eq!(retry, result);
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_try_evaluate_5_card() {
+        let evaluator = Evaluator::instance();
+        let hand = cards(["As", "Ks", "Qs", "Js", "Ts"]);
+        assert_eq!(
+            evaluator.try_evaluate_5_card(&hand),
+            Ok(evaluator.evaluate_5_card(&hand))
+        );
+
+        let duplicate = cards(["As", "As", "Qs", "Js", "Ts"]);
+        assert!(matches!(
+            evaluator.try_evaluate_5_card(&duplicate),
+            Err(EvaluatorError::InvalidHand(_))
+        ));
+
+        let mut malformed = hand;
+        malformed[0].rank = 13;
+        assert!(matches!(
+            evaluator.try_evaluate_5_card(&malformed),
+            Err(EvaluatorError::InvalidCardValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_corrupted_hash_is_out_of_bounds() {
+        let evaluator = Evaluator::instance();
+        let corrupted =
+            perfect_hash_5_cards(&cards(["As", "Ks", "Qs", "Js", "Ts"])) + FIVE_CARD_TABLE_SIZE;
+
+        assert_eq!(
+            evaluator.lookup_5_card(corrupted),
+            Err(EvaluatorError::HashOutOfBounds {
+                index: corrupted,
+                size: FIVE_CARD_TABLE_SIZE
+            })
+        );
+    }
+
+    #[test]
+    fn test_fast_evaluator_matches_reference_on_all_hands() {
+        let evaluator = Evaluator::instance();
+        let deck = live_cards(&[]);
+
+        let mut checked = 0;
+        for a in 0..48 {
+            for b in a + 1..49 {
+                for c in b + 1..50 {
+                    for d in c + 1..51 {
+                        for e in d + 1..52 {
+                            let hand = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                            let fast = evaluate_5_card_fast(&hand);
+                            assert_eq!(
+                                fast,
+                                evaluate_5_card_hand(&hand),
+                                "mismatch for {:?}",
+                                hand
+                            );
+                            assert_eq!(fast, evaluator.evaluate_5_card(&hand));
+                            checked += 1;
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(checked, FIVE_CARD_TABLE_SIZE);
+    }
+
+    #[test]
+    fn test_percentile_bounds() {
+        let evaluator = Evaluator::instance();
+        let worst = evaluate_5_card_hand(&cards(["7h", "5d", "4c", "3s", "2h"]));
+        let royal = evaluate_5_card_hand(&cards(["As", "Ks", "Qs", "Js", "Ts"]));
+        let pair = evaluate_5_card_hand(&cards(["9h", "9d", "6c", "5s", "4h"]));
+
+        assert_eq!(evaluator.percentile(worst), 0.0);
+        assert_eq!(evaluator.percentile(royal), 1.0);
+        assert!(evaluator.percentile(pair) > 0.0 && evaluator.percentile(pair) < 1.0);
+    }
+
+    #[test]
+    fn test_enumerate_distinct_ranks() {
+        let ranks = Evaluator::instance().enumerate_distinct_ranks();
+        assert_eq!(ranks.len(), 7462);
+        assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            ranks[0],
+            evaluate_5_card_hand(&cards(["7h", "5d", "4c", "3s", "2h"]))
+        );
+    }
+
+    #[test]
+    fn test_seven_card_evaluation_picks_best_five() {
+        let evaluator = Evaluator::instance();
+        let seven = ["As", "Ks", "Qs", "Js", "Ts", "2h", "2d"].map(|c| Card::from_str(c).unwrap());
+        assert_eq!(evaluator.evaluate_7_card(&seven).rank, HandRank::RoyalFlush);
+
+        let six = ["9h", "9d", "9c", "5s", "5h", "2d"].map(|c| Card::from_str(c).unwrap());
+        assert_eq!(evaluator.evaluate_6_card(&six).rank, HandRank::FullHouse);
+    }
+
+    #[test]
+    fn test_evaluate_holding_by_street() {
+        let evaluator = Evaluator::instance();
+        let hole =
+            HoleCards::new(Card::from_str("Ah").unwrap(), Card::from_str("Kh").unwrap()).unwrap();
+
+        let flop = Board::from_str("Ad 7h 2h").unwrap();
+        let turn = flop
+            .clone()
+            .with_turn(Card::from_str("Kd").unwrap())
+            .unwrap();
+        let river = turn
+            .clone()
+            .with_river(Card::from_str("9h").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            evaluator.evaluate_holding(&hole, &flop).rank,
+            HandRank::Pair
+        );
+        assert_eq!(
+            evaluator.evaluate_holding(&hole, &turn).rank,
+            HandRank::TwoPair
+        );
+        assert_eq!(
+            evaluator.evaluate_holding(&hole, &river).rank,
+            HandRank::Flush
+        );
+
+        let seven = ["Ah", "Kh", "Ad", "7h", "2h", "Kd", "9h"].map(|c| Card::from_str(c).unwrap());
+        assert_eq!(
+            evaluator.evaluate_holding(&hole, &river),
+            evaluator.evaluate_7_card(&seven)
+        );
+        assert_eq!(
+            evaluator.evaluate_holding(&hole, &Board::new()),
+            HandValue::new(HandRank::HighCard, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overlap")]
+    fn test_evaluate_holding_rejects_shared_cards() {
+        let hole =
+            HoleCards::new(Card::from_str("Ah").unwrap(), Card::from_str("Kh").unwrap()).unwrap();
+        let flop = Board::from_str("Ah 7h 2h").unwrap();
+        Evaluator::instance().evaluate_holding(&hole, &flop);
+    }
+}
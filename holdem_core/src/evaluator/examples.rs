@@ -13,7 +13,7 @@
 
 use super::errors::EvaluatorError;
 use super::evaluator::{HandRank, HandValue};
-use super::integration::{benchmark_evaluation, utils, EvaluatorComparison, MathEvaluator};
+use super::integration::{EvaluatorComparison, MathEvaluator};
 use super::tables::{CanonicalMapping, JumpTable};
 use crate::card::PackedCard;
 use crate::{Card, Hand};
@@ -154,11 +154,9 @@ pub fn integration_example() -> Result<(), EvaluatorError> {
     println!("=== Integration Example ===");
 
     // Create hands using holdem_core
-    let hole_cards = crate::HoleCards::from_notation("AKs")
-        .map_err(|_| EvaluatorError::table_init_failed("Invalid hole cards"))?;
+    let hole_cards = crate::HoleCards::from_notation("AKs")?;
     let board = crate::Board::new();
-    let hand = Hand::from_hole_cards_and_board(&hole_cards, &board)
-        .map_err(|_| EvaluatorError::table_init_failed("Invalid hand"))?;
+    let hand = Hand::from_hole_cards_and_board(&hole_cards, &board)?;
 
     println!("Evaluating complete hand:");
     println!("  Hole cards: {}", hole_cards);
@@ -178,7 +176,7 @@ pub fn integration_example() -> Result<(), EvaluatorError> {
 
     // Test type conversion utilities
     let cards = hand.cards();
-    let packed_cards = super::integration::convert_cards(&cards);
+    let packed_cards = super::integration::convert_cards(cards);
     let converted_back = super::integration::convert_cards_back(&packed_cards)?;
 
     println!("\nType Conversion Test:");
@@ -250,7 +248,7 @@ pub fn canonicalization_example() -> Result<(), EvaluatorError> {
             mapping
                 .canonical_cards
                 .iter()
-                .map(|&c| (c & 0x03))
+                .map(|&c| c & 0x03)
                 .collect::<Vec<_>>()
         );
         println!("  Suit mapping: {:?}", mapping.suit_map);
@@ -444,6 +442,7 @@ fn extract_suits(cards: &[PackedCard]) -> Vec<u8> {
 }
 
 /// Helper function to extract ranks from cards
+#[cfg(test)]
 fn extract_ranks(cards: &[PackedCard]) -> Vec<u8> {
     cards.iter().map(|card| card.rank()).collect()
 }
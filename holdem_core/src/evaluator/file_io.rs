@@ -1,10 +1,24 @@
 //! File I/O utilities for poker evaluation tables
 
 use super::errors::EvaluatorError;
+use super::evaluator::Evaluator;
+use sha2::{Digest, Sha256};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
 
+/// Progress callback: table being generated, entries done, entries total
+pub type ProgressFn<'a> = &'a dyn Fn(TableType, usize, usize);
+
+/// Entries serialized between progress reports
+const PROGRESS_STEP: usize = 1 << 16;
+
+/// Magic bytes opening a raw table written by [`LutFileManager::export_raw`]
+pub const RAW_TABLE_MAGIC: &[u8; 4] = b"HLUT";
+
+/// Format version of raw tables written by [`LutFileManager::export_raw`]
+pub const RAW_TABLE_VERSION: u32 = 1;
+
 /// Types of lookup tables that can be serialized
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum TableType {
@@ -113,16 +127,22 @@ impl LutFileManager {
         let filename = filename.as_ref().map(|s| s.as_ref()).unwrap_or("table.bin");
         let path = Path::new(&self.base_dir).join(filename);
 
+        let info = TableInfo::new(table_type, data.len(), 1);
+        Self::write_table(&path, &info, data)?;
+        Ok(info)
+    }
+
+    /// Write a table info header followed by the table data
+    fn write_table(path: &Path, info: &TableInfo, data: &[u8]) -> Result<(), EvaluatorError> {
         // Create directory if it doesn't exist
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        let file = File::create(&path)?;
+        let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
 
         // Write table info header
-        let info = TableInfo::new(table_type, data.len(), 1);
         let info_bytes = bincode::serialize(&info)
             .map_err(|e| EvaluatorError::file_io_error(&format!("Serialization error: {}", e)))?;
 
@@ -132,7 +152,7 @@ impl LutFileManager {
 
         writer.flush()?;
 
-        Ok(info)
+        Ok(())
     }
 
     /// Load table data from a file
@@ -161,6 +181,105 @@ impl LutFileManager {
         Ok((info, data))
     }
 
+    /// Generate and write the evaluator's lookup tables to `dir`
+    ///
+    /// The evaluator has a single table, the 5-card table, written to
+    /// `five_card.bin` with one packed `HandValue` (`u32`, little endian) per
+    /// perfect-hash index. 6- and 7-card hands are evaluated from it, so
+    /// there are no larger tables to precompute.
+    ///
+    /// Each file is written to a temporary name and renamed into place, and a
+    /// `SHA256SUMS` file listing every table's checksum is written last.
+    /// `progress` is called at the start, every 65,536 entries while the
+    /// table is serialized, and once more after the file is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the evaluator cannot be created or a file cannot
+    /// be written.
+    pub fn generate_all(dir: &Path, progress: Option<ProgressFn>) -> Result<(), EvaluatorError> {
+        let manager = LutFileManager::new(dir);
+        let evaluator = Evaluator::try_instance()?;
+        let (table_type, filename) = (TableType::FiveCard, "five_card.bin");
+        let (data, entry_size, description) = table_data(&evaluator, table_type, progress)?;
+        let entries = data.len() / entry_size;
+        manager.save_table_atomic(table_type, &data, filename, entry_size, description)?;
+        if let Some(report) = progress {
+            report(table_type, entries, entries);
+        }
+        let checksums = format!("{:x}  {}\n", Sha256::digest(&data), filename);
+
+        let checksum_path = Path::new(&manager.base_dir).join("SHA256SUMS");
+        let temp_path = checksum_path.with_extension("tmp");
+        std::fs::write(&temp_path, checksums)?;
+        std::fs::rename(&temp_path, &checksum_path)?;
+        Ok(())
+    }
+
+    /// Write a table under a temporary name, then rename it into place
+    fn save_table_atomic(
+        &self,
+        table_type: TableType,
+        data: &[u8],
+        filename: &str,
+        entry_size: usize,
+        description: &str,
+    ) -> Result<TableInfo, EvaluatorError> {
+        let path = Path::new(&self.base_dir).join(filename);
+        let temp_path = Path::new(&self.base_dir).join(format!("{}.tmp", filename));
+
+        let info = TableInfo::new(table_type, data.len() / entry_size, entry_size)
+            .with_description(description);
+        Self::write_table(&temp_path, &info, data)?;
+        std::fs::rename(&temp_path, &path)?;
+        Ok(info)
+    }
+
+    /// Write a generated table as a flat array for use outside Rust
+    ///
+    /// Unlike [`save_table`](Self::save_table) there is no serde framing, so
+    /// the file can be mapped and read directly from C or any other language.
+    /// Every field is little endian:
+    ///
+    /// | Offset | Size | Field                                   |
+    /// |--------|------|-----------------------------------------|
+    /// | 0      | 4    | Magic bytes `HLUT` ([`RAW_TABLE_MAGIC`]) |
+    /// | 4      | 4    | Format version ([`RAW_TABLE_VERSION`])  |
+    /// | 8      | 4    | Entry count                             |
+    /// | 12     | 4    | Entry size in bytes                     |
+    /// | 16     | ...  | Entry count × entry size bytes of data  |
+    ///
+    /// The 5-card table has 4-byte entries, one packed `HandValue` (`u32`) per
+    /// perfect-hash index. The 16-byte header keeps the data aligned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `table_type` is not a generated table (only the
+    /// 5-card table is), the evaluator cannot be created, or the file cannot
+    /// be written.
+    pub fn export_raw<P: AsRef<Path>>(
+        &self,
+        table_type: TableType,
+        path: P,
+    ) -> Result<(), EvaluatorError> {
+        let evaluator = Evaluator::try_instance()?;
+        let (data, entry_size, _) = table_data(&evaluator, table_type, None)?;
+
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(RAW_TABLE_MAGIC)?;
+        writer.write_all(&RAW_TABLE_VERSION.to_le_bytes())?;
+        writer.write_all(&((data.len() / entry_size) as u32).to_le_bytes())?;
+        writer.write_all(&(entry_size as u32).to_le_bytes())?;
+        writer.write_all(&data)?;
+
+        writer.flush()?;
+        Ok(())
+    }
+
     /// Check if a table file exists
     pub fn table_exists<P: AsRef<Path>>(&self, path: P) -> bool {
         path.as_ref().exists()
@@ -175,6 +294,31 @@ impl LutFileManager {
     }
 }
 
+/// Build the raw bytes, entry size and description of a generated table
+fn table_data(
+    evaluator: &Evaluator,
+    table_type: TableType,
+    progress: Option<ProgressFn>,
+) -> Result<(Vec<u8>, usize, &'static str), EvaluatorError> {
+    match table_type {
+        TableType::FiveCard => {
+            let table = evaluator.five_card_table();
+            let mut data = Vec::with_capacity(table.len() * 4);
+            for (chunk_index, chunk) in table.chunks(PROGRESS_STEP).enumerate() {
+                if let Some(report) = progress {
+                    report(table_type, chunk_index * PROGRESS_STEP, table.len());
+                }
+                data.extend(chunk.iter().flat_map(|value| value.as_u32().to_le_bytes()));
+            }
+            Ok((data, 4, "5-card values by perfect hash"))
+        }
+        _ => Err(EvaluatorError::table_init_failed(&format!(
+            "No generated data for {:?} table",
+            table_type
+        ))),
+    }
+}
+
 impl Default for LutFileManager {
     fn default() -> Self {
         Self::new("tables")
@@ -223,4 +367,95 @@ mod tests {
         assert_eq!(info.table_type, loaded_info.table_type);
         assert_eq!(test_data, loaded_data);
     }
+
+    #[test]
+    fn test_generate_all() {
+        use std::cell::RefCell;
+
+        let temp_dir = tempdir().unwrap();
+        let reported = RefCell::new(Vec::new());
+        let progress = |table_type: TableType, done: usize, total: usize| {
+            reported.borrow_mut().push((table_type, done, total));
+        };
+
+        LutFileManager::generate_all(temp_dir.path(), Some(&progress)).unwrap();
+
+        let manager = LutFileManager::new(temp_dir.path());
+        let (info, five) = manager
+            .load_table(manager.get_table_path("five_card.bin"))
+            .unwrap();
+        assert_eq!(info.table_type, TableType::FiveCard);
+        assert_eq!(info.entry_size, 4);
+        assert_eq!(info.total_size(), five.len());
+        assert_eq!(
+            five.len(),
+            super::super::evaluator::FIVE_CARD_TABLE_SIZE * 4
+        );
+        assert!(!manager.table_exists(manager.get_table_path("five_card.bin.tmp")));
+
+        let checksums = std::fs::read_to_string(manager.get_table_path("SHA256SUMS")).unwrap();
+        assert_eq!(checksums.lines().count(), 1);
+
+        // Progress climbs steadily from zero to the full table
+        let reported = reported.into_inner();
+        let total = super::super::evaluator::FIVE_CARD_TABLE_SIZE;
+        assert!(reported.len() > 2);
+        assert!(reported
+            .iter()
+            .all(|&(table_type, _, of)| { table_type == TableType::FiveCard && of == total }));
+        assert!(reported.windows(2).all(|pair| pair[0].1 < pair[1].1));
+        assert_eq!(reported[0].1, 0);
+        assert_eq!(reported[reported.len() - 1].1, total);
+    }
+
+    #[test]
+    fn test_export_raw_five_card_table() {
+        let dir = tempdir().unwrap();
+        let manager = LutFileManager::new(dir.path());
+        let path = dir.path().join("five_card.raw");
+        manager.export_raw(TableType::FiveCard, &path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        let field =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        assert_eq!(&bytes[..4], RAW_TABLE_MAGIC);
+        assert_eq!(field(4), RAW_TABLE_VERSION);
+        assert_eq!(field(12), 4);
+
+        let evaluator = Evaluator::instance();
+        let table = evaluator.five_card_table();
+        assert_eq!(field(8) as usize, table.len());
+        assert_eq!(bytes.len(), 16 + table.len() * 4);
+
+        let values: Vec<u32> = (0..table.len()).map(|i| field(16 + i * 4)).collect();
+        let expected: Vec<u32> = table.iter().map(|value| value.as_u32()).collect();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_export_raw_rejects_custom_table() {
+        let dir = tempdir().unwrap();
+        let manager = LutFileManager::new(dir.path());
+        let path = dir.path().join("custom.raw");
+
+        let err = manager.export_raw(TableType::Custom(9), &path).unwrap_err();
+        assert!(matches!(err, EvaluatorError::TableInitFailed(_)));
+        assert!(!manager.table_exists(&path));
+    }
+
+    #[test]
+    fn test_load_table_io_error_keeps_source() {
+        use std::error::Error;
+
+        let dir = tempdir().unwrap();
+        let manager = LutFileManager::new(dir.path());
+        let err = manager
+            .load_table(dir.path().join("missing.bin"))
+            .unwrap_err();
+
+        assert!(matches!(err, EvaluatorError::Io(_)));
+        let source = err.source().unwrap();
+        let io_err = source.downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+    }
 }
@@ -16,6 +16,7 @@
 //!
 //! ### Basic Integration
 //! ```rust
+//! use holdem_core::card::PackedCard;
 //! use holdem_core::evaluator::{JumpTable, HandValue};
 //! use holdem_core::{Card, Hand};
 //! use holdem_core::evaluator::integration::{MathEvaluator, convert_cards};
@@ -28,6 +29,8 @@
 //!     Card::from_str("Qs").unwrap(),
 //!     Card::from_str("Js").unwrap(),
 //!     Card::from_str("Ts").unwrap(),
+//!     Card::from_str("7h").unwrap(),
+//!     Card::from_str("6d").unwrap(),
 //! ];
 //!
 //! // Convert to math evaluator format
@@ -43,7 +46,7 @@
 //! ```
 //!
 //! ### Performance Comparison
-//! ```rust
+//! ```rust,no_run
 //! use holdem_core::evaluator::integration::{MathEvaluator, benchmark_evaluation};
 //! use holdem_core::evaluator::Evaluator;
 //!
@@ -64,7 +67,8 @@
 
 use super::errors::EvaluatorError;
 use super::evaluator::{HandRank, HandValue};
-use super::tables::{CanonicalMapping, JumpTable, JumpTableEntry};
+use super::logging::progress;
+use super::tables::JumpTable;
 use crate::card::PackedCard;
 use crate::{Card, Hand};
 use std::time::{Duration, Instant};
@@ -213,10 +217,7 @@ impl MathEvaluator {
 
 /// Convert holdem_core Card array to math PackedCard array
 pub fn convert_cards(cards: &[Card]) -> Vec<PackedCard> {
-    cards
-        .iter()
-        .map(|card| PackedCard::from_card(card))
-        .collect()
+    cards.iter().map(PackedCard::from_card).collect()
 }
 
 /// Convert holdem_core Card array to math PackedCard array (fixed size)
@@ -341,7 +342,7 @@ impl EvaluatorComparison {
             let core_result = self.core_evaluator.evaluate_hand(hand);
 
             results.push(ComparisonResult {
-                hand: hand.clone(),
+                hand: *hand,
                 math_result,
                 core_result,
                 match_result: math_result == core_result,
@@ -392,13 +393,13 @@ impl EvaluatorComparison {
         let mut core_times = Vec::new();
 
         // Benchmark math evaluator
-        for cards in &test_cases {
+        for _ in &test_cases {
             let math_time = benchmark_evaluation(|c| self.math_evaluator.evaluate_7_card(c));
             math_times.push(math_time);
         }
 
         // Benchmark core evaluator
-        for cards in &test_cases {
+        for _ in &test_cases {
             let core_time = benchmark_evaluation(|c| self.core_evaluator.evaluate_7_card(c));
             core_times.push(core_time);
         }
@@ -473,8 +474,6 @@ pub mod utils {
 
     /// Validate that math evaluator produces same results as holdem_core
     pub fn validate_evaluator_compatibility() -> Result<(), EvaluatorError> {
-        use std::str::FromStr;
-
         let comparison = EvaluatorComparison::new()?;
 
         // Test a few representative hands
@@ -496,7 +495,7 @@ pub mod utils {
             }
         }
 
-        println!("All evaluator compatibility tests passed!");
+        progress!("All evaluator compatibility tests passed!");
         Ok(())
     }
 
@@ -649,7 +648,7 @@ mod tests {
     fn test_benchmark_function() {
         use std::str::FromStr;
 
-        let test_cards = [
+        let _test_cards = [
             Card::from_str("As").unwrap(),
             Card::from_str("Ks").unwrap(),
             Card::from_str("Qs").unwrap(),
@@ -659,7 +658,7 @@ mod tests {
             Card::from_str("6d").unwrap(),
         ];
 
-        let elapsed = benchmark_evaluation(|cards| {
+        let elapsed = benchmark_evaluation(|_cards| {
             // Simple evaluation for testing
             HandValue::new(HandRank::HighCard, 0)
         });
@@ -672,7 +671,7 @@ mod tests {
         let comparison = EvaluatorComparison::new();
         assert!(comparison.is_ok());
 
-        let mut comparison = EvaluatorComparison::new().unwrap();
+        let comparison = EvaluatorComparison::new().unwrap();
         let results = comparison.compare_evaluations(&[]);
 
         // Should handle empty hand list gracefully
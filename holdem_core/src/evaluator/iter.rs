@@ -0,0 +1,186 @@
+//! Lazy evaluation of hand streams
+//!
+//! [`EvaluatedHands`] wraps any iterator of [`Hand`]s and evaluates each one
+//! only when it is pulled, so hand pipelines can filter and map without
+//! collecting into intermediate vectors. [`evaluate_file`] applies the same
+//! idea to text files with one hand per line.
+
+use super::errors::EvaluatorError;
+use super::evaluator::{Evaluator, HandValue};
+use crate::errors::PokerError;
+use crate::Hand;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Iterator adapter yielding each hand together with its value
+#[derive(Debug, Clone)]
+pub struct EvaluatedHands<I> {
+    iter: I,
+    evaluator: Arc<Evaluator>,
+}
+
+impl<I: Iterator<Item = Hand>> Iterator for EvaluatedHands<I> {
+    type Item = (Hand, HandValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let hand = self.iter.next()?;
+        let value = self.evaluator.evaluate_hand(&hand);
+        Some((hand, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Wrap a stream of hands so each is evaluated lazily
+///
+/// Uses the shared [`Evaluator::instance`].
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::evaluator::iter::evaluate_iter;
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::Hand;
+///
+/// let hands = ["As Ks Qs Js Ts", "2h 2d 7c 9s Kd"].map(|h| Hand::from_notation(h).unwrap());
+/// let pairs: Vec<_> = evaluate_iter(hands)
+///     .filter(|(_, value)| value.rank == HandRank::Pair)
+///     .collect();
+/// assert_eq!(pairs.len(), 1);
+/// ```
+pub fn evaluate_iter<I: IntoIterator<Item = Hand>>(iter: I) -> EvaluatedHands<I::IntoIter> {
+    EvaluatedHands {
+        iter: iter.into_iter(),
+        evaluator: Evaluator::instance(),
+    }
+}
+
+/// Extension trait adding `.evaluate_iter()` to iterators of hands
+pub trait EvaluateIterExt: Iterator<Item = Hand> + Sized {
+    /// Evaluate each hand lazily as it is pulled from the iterator
+    fn evaluate_iter(self) -> EvaluatedHands<Self> {
+        evaluate_iter(self)
+    }
+}
+
+impl<I: Iterator<Item = Hand>> EvaluateIterExt for I {}
+
+/// Outcome of evaluating a file of hand notations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HandFileReport {
+    /// Each valid line, trimmed, with its value, in file order
+    pub hands: Vec<(String, HandValue)>,
+    /// 1-based line number and parse error of each invalid line
+    pub errors: Vec<(usize, PokerError)>,
+}
+
+/// Evaluate a text file with one hand notation per line
+///
+/// Lines are parsed with [`Hand::from_notation`] and evaluated as they are
+/// read. Every valid line is kept in [`HandFileReport::hands`], so the
+/// report grows with the file; use [`evaluate_iter`] to stream hands
+/// instead. Blank lines are skipped. Lines that fail to parse do not stop
+/// the run; they are collected in [`HandFileReport::errors`] with their
+/// line numbers.
+///
+/// # Errors
+///
+/// Returns `EvaluatorError::Io` if the file cannot be opened or read.
+pub fn evaluate_file(path: &Path) -> Result<HandFileReport, EvaluatorError> {
+    let reader = BufReader::new(File::open(path)?);
+    let evaluator = Evaluator::instance();
+
+    let mut report = HandFileReport::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let notation = line.trim();
+        if notation.is_empty() {
+            continue;
+        }
+        match Hand::from_notation(notation) {
+            Ok(hand) => {
+                let value = evaluator.evaluate_hand(&hand);
+                report.hands.push((notation.to_string(), value));
+            }
+            Err(err) => report.errors.push((index + 1, err)),
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::HandRank;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_evaluate_iter_is_lazy() {
+        let hands = [
+            "As Ks Qs Js Ts",
+            "9h 9d 9c 9s 2h",
+            "Kh Kd 7c 7s 2h",
+            "8h 6h 4h 3h 2h",
+            "Ah Kd 7c 5s 2h",
+        ]
+        .map(|h| Hand::from_notation(h).unwrap());
+
+        let pulled = Cell::new(0);
+        let mut evaluated = hands
+            .into_iter()
+            .inspect(|_| pulled.set(pulled.get() + 1))
+            .evaluate_iter();
+        assert_eq!(pulled.get(), 0);
+
+        let (first, value) = evaluated.next().unwrap();
+        assert_eq!(pulled.get(), 1);
+        assert_eq!(first, hands[0]);
+        assert_eq!(value.rank, HandRank::RoyalFlush);
+
+        let ranks: Vec<HandRank> = evaluated.map(|(_, value)| value.rank).collect();
+        assert_eq!(pulled.get(), 5);
+        assert_eq!(
+            ranks,
+            [
+                HandRank::FourOfAKind,
+                HandRank::TwoPair,
+                HandRank::Flush,
+                HandRank::HighCard
+            ]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_file_reports_bad_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hands.txt");
+        std::fs::write(
+            &path,
+            "As Ks Qs Js Ts\n\nKh Kd 7c 7s 2h\nXx Kd 7c 7s 2h\nAh Ah Kd Qc 2s\n",
+        )
+        .unwrap();
+
+        let report = evaluate_file(&path).unwrap();
+        let ranks: Vec<(&str, HandRank)> = report
+            .hands
+            .iter()
+            .map(|(line, value)| (line.as_str(), value.rank))
+            .collect();
+        assert_eq!(
+            ranks,
+            [
+                ("As Ks Qs Js Ts", HandRank::RoyalFlush),
+                ("Kh Kd 7c 7s 2h", HandRank::TwoPair)
+            ]
+        );
+
+        let lines: Vec<usize> = report.errors.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, [4, 5]);
+
+        assert!(evaluate_file(&dir.path().join("missing.txt")).is_err());
+    }
+}
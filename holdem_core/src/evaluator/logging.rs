@@ -0,0 +1,78 @@
+//! Progress logging hooks
+//!
+//! Table construction reports its progress through a process-wide logger
+//! callback instead of printing, so applications embedding the evaluator
+//! decide where, or whether, those messages go. No output is produced until
+//! a logger is installed with [`set_logger`].
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::evaluator::logging::{clear_logger, set_logger};
+//!
+//! set_logger(|message| eprintln!("[evaluator] {}", message));
+//! // ... build tables ...
+//! clear_logger();
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+type Logger = Arc<dyn Fn(&str) + Send + Sync>;
+
+static LOGGER: RwLock<Option<Logger>> = RwLock::new(None);
+
+/// Install a callback that receives every evaluator progress message
+///
+/// Replaces any previously installed logger.
+pub fn set_logger(logger: impl Fn(&str) + Send + Sync + 'static) {
+    *LOGGER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(logger));
+}
+
+/// Remove the installed logger, silencing progress messages again
+pub fn clear_logger() {
+    *LOGGER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Send a message to the installed logger, if any
+///
+/// The message is only formatted when a logger is installed.
+pub(crate) fn emit(args: fmt::Arguments<'_>) {
+    let logger = LOGGER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(logger) = logger {
+        logger(&args.to_string());
+    }
+}
+
+/// Log a progress message with `format!` syntax
+macro_rules! progress {
+    ($($arg:tt)*) => {
+        $crate::evaluator::logging::emit(format_args!($($arg)*))
+    };
+}
+
+pub(crate) use progress;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::tables::JumpTable;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_logger_receives_generation_messages() {
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&messages);
+        set_logger(move |message| sink.lock().unwrap().push(message.to_string()));
+
+        JumpTable::new(1000)
+            .generate_canonical_combinations()
+            .unwrap();
+        clear_logger();
+
+        let messages = messages.lock().unwrap();
+        assert!(messages
+            .iter()
+            .any(|message| message == "Generating canonical 7-card combinations..."));
+    }
+}
@@ -0,0 +1,289 @@
+//! Lowball hand evaluation
+//!
+//! Lowball games award the pot to the lowest hand, but disagree on what
+//! "lowest" means. [`LowballRules`] captures the differences, and
+//! [`evaluate_low`] evaluates any combination of them:
+//!
+//! - **Ace-to-five**: aces are low, straights and flushes are ignored
+//! - **Ace-to-six**: aces are low, straights and flushes count against you
+//! - **Deuce-to-seven**: aces are high, straights and flushes count
+
+use super::errors::EvaluatorError;
+use super::evaluator::HandRank;
+use crate::Card;
+
+/// Rules that decide how a lowball hand is ranked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LowballRules {
+    /// Aces rank below deuces instead of above kings
+    pub ace_low: bool,
+    /// Straights are made hands and so count against a low
+    pub straights_count: bool,
+    /// Flushes are made hands and so count against a low
+    pub flushes_count: bool,
+}
+
+impl LowballRules {
+    /// Ace-to-five (California) lowball, where the wheel is the nuts
+    pub const ACE_TO_FIVE: Self = Self {
+        ace_low: true,
+        straights_count: false,
+        flushes_count: false,
+    };
+
+    /// Ace-to-six (London) lowball, where 6-4-3-2-A is the nuts
+    pub const ACE_TO_SIX: Self = Self {
+        ace_low: true,
+        straights_count: true,
+        flushes_count: true,
+    };
+
+    /// Deuce-to-seven (Kansas City) lowball, where 7-5-4-3-2 is the nuts
+    pub const DEUCE_TO_SEVEN: Self = Self {
+        ace_low: false,
+        straights_count: true,
+        flushes_count: true,
+    };
+}
+
+/// Value of a lowball hand; unlike [`HandValue`](super::HandValue), lower is better
+///
+/// `rank` is the made-hand category under the rules (`HighCard` for an
+/// unmade low) and `value` orders hands within it. Royal flushes are
+/// reported as `StraightFlush`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct LowValue {
+    /// Made-hand category, lower is better
+    pub rank: HandRank,
+    /// Ranks in comparison order packed in base 13, lower is better
+    pub value: u32,
+}
+
+/// Evaluate the best lowball hand from 5 to 7 cards under `rules`
+///
+/// # Errors
+///
+/// Returns an error if fewer than 5 or more than 7 cards are given.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::evaluator::lowball::{evaluate_low, LowballRules};
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let wheel = ["As", "2h", "3d", "4c", "5s"].map(|c| Card::from_str(c).unwrap());
+/// assert_eq!(evaluate_low(&wheel, LowballRules::ACE_TO_FIVE).unwrap().rank, HandRank::HighCard);
+/// assert_eq!(evaluate_low(&wheel, LowballRules::ACE_TO_SIX).unwrap().rank, HandRank::Straight);
+/// ```
+pub fn evaluate_low(cards: &[Card], rules: LowballRules) -> Result<LowValue, EvaluatorError> {
+    if !(5..=7).contains(&cards.len()) {
+        return Err(EvaluatorError::invalid_hand(&format!(
+            "Lowball evaluation needs 5 to 7 cards, got {}",
+            cards.len()
+        )));
+    }
+
+    let n = cards.len();
+    let mut best: Option<LowValue> = None;
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        let hand = [cards[a], cards[b], cards[c], cards[d], cards[e]];
+                        let value = evaluate_low_5(&hand, rules);
+                        if best.is_none_or(|best| value < best) {
+                            best = Some(value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(best.expect("at least one five-card subset"))
+}
+
+/// True if the cards hold an eight-or-better low
+///
+/// A low qualifies with five distinct ranks from the ace through the eight,
+/// so for 5 to 7 cards this says whether the best ace-to-five low plays in a
+/// hi-lo split pot.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::evaluator::lowball::qualifies_for_eight_low;
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let cards = |hand: &[&str]| hand.iter().map(|c| Card::from_str(c).unwrap()).collect::<Vec<_>>();
+/// assert!(qualifies_for_eight_low(&cards(&["8s", "7h", "4d", "2c", "As", "Kd"])));
+/// assert!(!qualifies_for_eight_low(&cards(&["9s", "7h", "4d", "2c", "As"])));
+/// assert!(!qualifies_for_eight_low(&cards(&["8s", "7h", "4d", "2c", "2s"])));
+/// ```
+pub fn qualifies_for_eight_low(cards: &[Card]) -> bool {
+    // Aces play low; ranks up to 6 are the deuce through the eight
+    let low_ranks = cards.iter().fold(0u16, |mask, card| {
+        if card.rank == 12 || card.rank <= 6 {
+            mask | 1 << card.rank
+        } else {
+            mask
+        }
+    });
+    low_ranks.count_ones() >= 5
+}
+
+/// Lowball value of exactly five cards
+fn evaluate_low_5(cards: &[Card; 5], rules: LowballRules) -> LowValue {
+    // Map ranks so that 0 is the lowest card under the rules
+    let low_rank = |card: &Card| {
+        if rules.ace_low {
+            (card.rank as u32 + 1) % 13
+        } else {
+            card.rank as u32
+        }
+    };
+
+    let mut counts = [0u8; 13];
+    for card in cards {
+        counts[low_rank(card) as usize] += 1;
+    }
+
+    // Ranks by group size, then rank, both descending
+    let mut ordered: Vec<u32> = (0..13).filter(|&rank| counts[rank as usize] > 0).collect();
+    ordered.sort_by_key(|&rank| std::cmp::Reverse((counts[rank as usize], rank)));
+    let mut group_sizes: Vec<u8> = ordered.iter().map(|&rank| counts[rank as usize]).collect();
+    group_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    let is_flush = rules.flushes_count && cards.iter().all(|card| card.suit == cards[0].suit);
+    let is_straight = rules.straights_count && ordered.len() == 5 && ordered[0] - ordered[4] == 4;
+
+    let rank = match (is_straight, is_flush, group_sizes.as_slice()) {
+        (true, true, _) => HandRank::StraightFlush,
+        (_, _, [4, ..]) => HandRank::FourOfAKind,
+        (_, _, [3, 2]) => HandRank::FullHouse,
+        (_, true, _) => HandRank::Flush,
+        (true, _, _) => HandRank::Straight,
+        (_, _, [3, ..]) => HandRank::ThreeOfAKind,
+        (_, _, [2, 2, ..]) => HandRank::TwoPair,
+        (_, _, [2, ..]) => HandRank::Pair,
+        _ => HandRank::HighCard,
+    };
+
+    let value = ordered.iter().fold(0, |value, &rank| value * 13 + rank);
+    LowValue { rank, value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn cards(notation: &[&str]) -> Vec<Card> {
+        notation
+            .iter()
+            .map(|c| Card::from_str(c).unwrap())
+            .collect()
+    }
+
+    fn low(notation: &[&str], rules: LowballRules) -> LowValue {
+        evaluate_low(&cards(notation), rules).unwrap()
+    }
+
+    #[test]
+    fn test_rule_sets_pick_different_best_hands() {
+        let seven = ["As", "2h", "3d", "4c", "5s", "7h", "8d"];
+
+        let ace_to_five = low(&seven, LowballRules::ACE_TO_FIVE);
+        assert_eq!(
+            ace_to_five,
+            low(&["As", "2h", "3d", "4c", "5s"], LowballRules::ACE_TO_FIVE)
+        );
+
+        // The wheel is a straight, so 7-4-3-2-A is the best ace-to-six low
+        let ace_to_six = low(&seven, LowballRules::ACE_TO_SIX);
+        assert_eq!(
+            ace_to_six,
+            low(&["As", "2h", "3d", "4c", "7h"], LowballRules::ACE_TO_SIX)
+        );
+        assert_eq!(ace_to_six.rank, HandRank::HighCard);
+
+        // Aces are high, so the ace is discarded for 7-5-4-3-2
+        let deuce_to_seven = low(&seven, LowballRules::DEUCE_TO_SEVEN);
+        assert_eq!(
+            deuce_to_seven,
+            low(
+                &["2h", "3d", "4c", "5s", "7h"],
+                LowballRules::DEUCE_TO_SEVEN
+            )
+        );
+    }
+
+    #[test]
+    fn test_straights_and_flushes_by_rules() {
+        let wheel = ["As", "2h", "3d", "4c", "5s"];
+        assert_eq!(
+            low(&wheel, LowballRules::ACE_TO_FIVE).rank,
+            HandRank::HighCard
+        );
+        assert_eq!(
+            low(&wheel, LowballRules::ACE_TO_SIX).rank,
+            HandRank::Straight
+        );
+        // With aces high the wheel is just ace-high
+        assert_eq!(
+            low(&wheel, LowballRules::DEUCE_TO_SEVEN).rank,
+            HandRank::HighCard
+        );
+
+        let flush = ["2h", "4h", "6h", "8h", "Th"];
+        assert_eq!(
+            low(&flush, LowballRules::ACE_TO_FIVE).rank,
+            HandRank::HighCard
+        );
+        assert_eq!(
+            low(&flush, LowballRules::DEUCE_TO_SEVEN).rank,
+            HandRank::Flush
+        );
+
+        let broadway = ["Ts", "Jh", "Qd", "Kc", "As"];
+        assert_eq!(
+            low(&broadway, LowballRules::DEUCE_TO_SEVEN).rank,
+            HandRank::Straight
+        );
+        assert_eq!(
+            low(&broadway, LowballRules::ACE_TO_SIX).rank,
+            HandRank::HighCard
+        );
+    }
+
+    #[test]
+    fn test_low_ordering() {
+        let rules = LowballRules::ACE_TO_FIVE;
+        let six_four = low(&["6s", "4h", "3d", "2c", "As"], rules);
+        let six_five = low(&["6s", "5h", "3d", "2c", "As"], rules);
+        let pair = low(&["2s", "2h", "3d", "4c", "5s"], rules);
+        assert!(six_four < six_five);
+        assert!(six_five < pair);
+
+        assert!(evaluate_low(&cards(&["As", "2h", "3d", "4c"]), rules).is_err());
+    }
+
+    #[test]
+    fn test_eight_low_qualifier() {
+        // Pairs and high cards are skipped when five low ranks remain
+        assert!(qualifies_for_eight_low(&cards(&[
+            "8s", "7h", "4d", "2c", "2s", "As", "Kd"
+        ])));
+        assert!(!qualifies_for_eight_low(&cards(&[
+            "8s", "7h", "4d", "2c", "2s", "9s", "Kd"
+        ])));
+        assert!(!qualifies_for_eight_low(&cards(&[
+            "As", "Ah", "2d", "3c", "4s"
+        ])));
+    }
+}
@@ -9,22 +9,46 @@
 //! The evaluator module is organized into several sub-modules:
 //!
 //! - **`tables`**: Lookup table implementations and jump table structures
+//! - **`wild`**: Wild card and joker evaluation (`wild_cards` feature)
+//! - **`strength`**: Effective hand strength and hand potential
+//! - **`compare`**: Hand comparisons with human-readable explanations
+//! - **`iter`**: Lazy evaluation of hand streams
+//! - **`logging`**: Progress logging hooks for table construction
+//! - **`street`**: Incremental evaluation as the turn and river are dealt
+//! - **`lowball`**: Ace-to-five, ace-to-six and deuce-to-seven low hands
+//! - **`showdown`**: Pot shares at showdown for high, hi-lo and razz games
+//! - **`bench`**: Callable benchmark of table loading and evaluation speed
 //! - **`integration`**: Integration utilities and compatibility layers
 //! - **`property_tests`**: Property-based testing for evaluation correctness
 //! - **`examples`**: Usage examples and performance benchmarks
 
+pub mod bench;
+pub mod compare;
 pub mod errors;
+#[allow(clippy::module_inception)]
 pub mod evaluator;
 pub mod examples;
 pub mod file_io;
 pub mod integration;
+pub mod iter;
+pub mod logging;
+pub mod lowball;
 pub mod property_tests;
+pub mod showdown;
 pub mod singleton;
+pub mod street;
+pub mod strength;
 pub mod tables;
+#[cfg(feature = "wild_cards")]
+pub mod wild;
 
 // Re-export commonly used types from local modules
+pub use bench::BenchmarkReport;
+pub use compare::HandComparison;
 pub use errors::EvaluatorError;
 pub use evaluator::{Evaluator, HandRank, HandValue};
+pub use showdown::GameType;
+pub use strength::EhsResult;
 
 // Re-export math-specific types
 pub use tables::JumpTable;
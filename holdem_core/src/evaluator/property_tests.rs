@@ -22,11 +22,11 @@
 //! 4. **Coverage**: Ensure comprehensive testing of all code paths
 
 use super::errors::EvaluatorError;
-use super::evaluator::{HandRank, HandValue};
+use super::evaluator::HandRank;
 use super::integration::{benchmark_evaluation, utils, EvaluatorComparison, MathEvaluator};
 use super::tables::{CanonicalMapping, JumpTable};
 use crate::card::PackedCard;
-use crate::{Card, Hand};
+use crate::Card;
 use std::str::FromStr;
 
 /// Comprehensive test suite for the math evaluator system
@@ -172,7 +172,7 @@ impl EvaluatorTestSuite {
     fn run_performance_tests(&mut self) -> Result<(), EvaluatorError> {
         println!("Running performance tests...");
 
-        let test_cards = [
+        let _test_cards = [
             Card::from_str("As").unwrap(),
             Card::from_str("Ks").unwrap(),
             Card::from_str("Qs").unwrap(),
@@ -259,7 +259,7 @@ impl EvaluatorTestSuite {
 
         // Test evaluator comparison utility
         let comparison = EvaluatorComparison::new()?;
-        let test_hands = utils::generate_test_hands();
+        let _test_hands = utils::generate_test_hands();
         let results = comparison.compare_evaluations(&[]);
 
         // Should handle empty input gracefully
@@ -274,7 +274,7 @@ impl EvaluatorTestSuite {
         println!("Running regression tests...");
 
         // Test edge cases that have caused issues in the past
-        let edge_cases = vec![
+        let edge_cases = [
             // Empty hand (should not crash)
             vec![],
             // Single card
@@ -301,21 +301,21 @@ impl EvaluatorTestSuite {
                 let result_5 = self
                     .math_evaluator
                     .evaluate_5_card(&cards[..5].try_into().unwrap());
-                assert!(result_5.rank as u8 <= HandRank::HighCard as u8);
+                assert!(result_5.rank.as_u8() <= HandRank::HighCard.as_u8());
             }
 
             if cards.len() >= 6 {
                 let result_6 = self
                     .math_evaluator
                     .evaluate_6_card(&cards[..6].try_into().unwrap());
-                assert!(result_6.rank as u8 <= HandRank::HighCard as u8);
+                assert!(result_6.rank.as_u8() <= HandRank::HighCard.as_u8());
             }
 
             if cards.len() >= 7 {
                 let result_7 = self
                     .math_evaluator
                     .evaluate_7_card(&cards[..7].try_into().unwrap());
-                assert!(result_7.rank as u8 <= HandRank::HighCard as u8);
+                assert!(result_7.rank.as_u8() <= HandRank::HighCard.as_u8());
             }
 
             println!("Regression test {} passed", i);
@@ -450,7 +450,7 @@ pub fn test_canonicalization_properties() -> Result<(), EvaluatorError> {
 
     // Test that canonical cards have valid suits (0-3)
     for &card in &mapping1.canonical_cards {
-        let suit = (card as u8) & 0x03;
+        let suit = card & 0x03;
         assert!(suit < 4, "Invalid suit in canonical card: {}", suit);
     }
 
@@ -0,0 +1,189 @@
+//! Showdown pot splitting across game types
+//!
+//! High poker awards the pot to the best high hand, but split and lowball
+//! games divide it differently. [`GameType`] names the variant and
+//! [`showdown_detailed`] returns each player's share of the pot under it.
+
+use super::errors::EvaluatorError;
+use super::evaluator::Evaluator;
+use super::lowball::{evaluate_low, qualifies_for_eight_low, LowValue, LowballRules};
+use crate::Card;
+
+/// How the pot is awarded at showdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum GameType {
+    /// The best high hand takes the pot
+    HighOnly,
+    /// Half to the best high hand, half to the best ace-to-five low of eight
+    /// or better; the high hand scoops when no low qualifies
+    HiLo8,
+    /// The best ace-to-five low takes the pot, with no qualifier
+    Razz,
+}
+
+/// Each player's share of the pot at showdown under `game`
+///
+/// `hands` holds every player's cards, 5 to 7 each, including any community
+/// cards. Shares are fractions of the whole pot in player order and sum to
+/// one; players tied for a half or the whole pot split it equally.
+///
+/// # Errors
+///
+/// Returns an error if no hands are given or a hand has fewer than 5 or more
+/// than 7 cards.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::evaluator::showdown::{showdown_detailed, GameType};
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let cards = |hand: [&str; 7]| hand.map(|c| Card::from_str(c).unwrap());
+/// let flush = cards(["Kh", "Qh", "9h", "6h", "3c", "2h", "Tc"]);
+/// let straight = cards(["Ad", "4s", "9h", "6h", "3c", "2h", "5d"]);
+///
+/// // The straight loses to the flush in high-only poker...
+/// let shares = showdown_detailed(GameType::HighOnly, &[&flush, &straight]).unwrap();
+/// assert_eq!(shares, vec![1.0, 0.0]);
+///
+/// // ...but its A-2-3-4-5 takes the low half in hi-lo
+/// let shares = showdown_detailed(GameType::HiLo8, &[&flush, &straight]).unwrap();
+/// assert_eq!(shares, vec![0.5, 0.5]);
+/// ```
+pub fn showdown_detailed(game: GameType, hands: &[&[Card]]) -> Result<Vec<f64>, EvaluatorError> {
+    if hands.is_empty() {
+        return Err(EvaluatorError::invalid_hand(
+            "Showdown needs at least one hand",
+        ));
+    }
+    if let Some(hand) = hands.iter().find(|hand| !(5..=7).contains(&hand.len())) {
+        return Err(EvaluatorError::invalid_hand(&format!(
+            "Showdown hands need 5 to 7 cards, got {}",
+            hand.len()
+        )));
+    }
+
+    let mut shares = vec![0.0; hands.len()];
+    match game {
+        GameType::HighOnly => award(&mut shares, &high_values(hands), 1.0),
+        GameType::HiLo8 => {
+            let lows: Vec<Option<LowValue>> = low_values(hands)?
+                .into_iter()
+                .zip(hands)
+                .map(|(low, hand)| Some(low).filter(|_| qualifies_for_eight_low(hand)))
+                .collect();
+            if lows.iter().any(Option::is_some) {
+                award(&mut shares, &high_values(hands), 0.5);
+                award_low(&mut shares, &lows, 0.5);
+            } else {
+                award(&mut shares, &high_values(hands), 1.0);
+            }
+        }
+        GameType::Razz => {
+            let lows: Vec<Option<LowValue>> = low_values(hands)?.into_iter().map(Some).collect();
+            award_low(&mut shares, &lows, 1.0);
+        }
+    }
+    Ok(shares)
+}
+
+/// High values of every hand
+fn high_values(hands: &[&[Card]]) -> Vec<u32> {
+    let evaluator = Evaluator::instance();
+    hands
+        .iter()
+        .map(|hand| evaluator.evaluate_cards(hand).as_u32())
+        .collect()
+}
+
+/// Best ace-to-five low of every hand
+fn low_values(hands: &[&[Card]]) -> Result<Vec<LowValue>, EvaluatorError> {
+    hands
+        .iter()
+        .map(|hand| evaluate_low(hand, LowballRules::ACE_TO_FIVE))
+        .collect()
+}
+
+/// Splits `portion` of the pot between the players with the highest value
+fn award(shares: &mut [f64], values: &[u32], portion: f64) {
+    let best = values.iter().max().expect("at least one hand");
+    let winners = values.iter().filter(|value| *value == best).count();
+    for (share, value) in shares.iter_mut().zip(values) {
+        if value == best {
+            *share += portion / winners as f64;
+        }
+    }
+}
+
+/// Splits `portion` of the pot between the players with the lowest low
+fn award_low(shares: &mut [f64], lows: &[Option<LowValue>], portion: f64) {
+    let Some(best) = lows.iter().flatten().min() else {
+        return;
+    };
+    let winners = lows.iter().filter(|low| *low == &Some(*best)).count();
+    for (share, low) in shares.iter_mut().zip(lows) {
+        if low == &Some(*best) {
+            *share += portion / winners as f64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn cards(notation: &[&str]) -> Vec<Card> {
+        notation
+            .iter()
+            .map(|c| Card::from_str(c).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_hi_lo_splits_between_high_and_low() {
+        let board = ["Kd", "8s", "5c", "4h", "2d"];
+        let high = cards(&[&["Ks", "Kc"][..], &board].concat());
+        let low = cards(&[&["As", "6c"][..], &board].concat());
+        let neither = cards(&[&["Qs", "Jc"][..], &board].concat());
+
+        let shares = showdown_detailed(GameType::HiLo8, &[&high, &low, &neither]).unwrap();
+        assert_eq!(shares, vec![0.5, 0.5, 0.0]);
+
+        // Without a qualifying low the high hand scoops
+        let board = ["Kd", "Qs", "9c", "4h", "2d"];
+        let high = cards(&[&["Ks", "Kc"][..], &board].concat());
+        let low = cards(&[&["As", "3c"][..], &board].concat());
+        let shares = showdown_detailed(GameType::HiLo8, &[&high, &low]).unwrap();
+        assert_eq!(shares, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_razz_awards_best_low() {
+        let seven_low = cards(&["7s", "5h", "4d", "3c", "2s", "Qs", "Qc"]);
+        let eight_low = cards(&["8s", "5d", "4c", "3h", "2d", "Kh", "Kd"]);
+        let paired = cards(&["As", "Ah", "2c", "2h", "3s", "3d", "4c"]);
+
+        let shares = showdown_detailed(GameType::Razz, &[&eight_low, &seven_low, &paired]).unwrap();
+        assert_eq!(shares, vec![0.0, 1.0, 0.0]);
+
+        // The same hands in high poker go to the kings instead
+        let high = showdown_detailed(GameType::HighOnly, &[&eight_low, &seven_low]).unwrap();
+        assert_eq!(high, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_ties_split_and_bad_hands_rejected() {
+        let board = ["As", "Ks", "Qd", "Jc", "Th"];
+        let a = cards(&[&["2h", "3d"][..], &board].concat());
+        let b = cards(&[&["2c", "4d"][..], &board].concat());
+        assert_eq!(
+            showdown_detailed(GameType::HighOnly, &[&a, &b]).unwrap(),
+            vec![0.5, 0.5]
+        );
+
+        assert!(showdown_detailed(GameType::HighOnly, &[]).is_err());
+        assert!(showdown_detailed(GameType::Razz, &[&a[..4]]).is_err());
+    }
+}
@@ -0,0 +1,292 @@
+//! Street-by-street incremental evaluation
+//!
+//! A [`StreetEvaluator`] keeps the rank counts and per-suit rank masks of the
+//! cards seen so far. Adding the turn or river updates them in constant time,
+//! and the best value is then read off them in one pass over the thirteen
+//! ranks, with no 5-card subsets and no table lookups. Its values are the
+//! same as [`Evaluator::evaluate_7_card`] gives for the same cards.
+//!
+//! [`Evaluator::evaluate_7_card`]: super::Evaluator::evaluate_7_card
+
+use super::errors::EvaluatorError;
+use super::evaluator::{straight_high_card, HandRank, HandValue};
+use crate::{Card, HoleCards};
+
+/// Incrementally evaluates one holding as the turn and river are dealt
+#[derive(Debug, Clone)]
+pub struct StreetEvaluator {
+    cards: [Card; 7],
+    len: usize,
+    /// Cards held of each rank, 2 to A
+    counts: [u8; 13],
+    /// Ranks held in each suit, one bit per rank
+    suit_masks: [u16; 4],
+    best: HandValue,
+}
+
+impl StreetEvaluator {
+    /// Start from the hole cards and the flop
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any card is malformed or the five cards are not
+    /// distinct.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::street::StreetEvaluator;
+    /// use holdem_core::evaluator::HandRank;
+    /// use holdem_core::{Card, HoleCards};
+    /// use std::str::FromStr;
+    ///
+    /// let card = |s: &str| Card::from_str(s).unwrap();
+    /// let hole = HoleCards::new(card("Ah"), card("Kh")).unwrap();
+    /// let mut street = StreetEvaluator::new(&hole, [card("Qh"), card("7h"), card("2c")]).unwrap();
+    /// assert_eq!(street.value().rank, HandRank::HighCard);
+    /// assert_eq!(street.with_turn(card("3h")).unwrap().rank, HandRank::Flush);
+    /// ```
+    pub fn new(hole: &HoleCards, flop: [Card; 3]) -> Result<Self, EvaluatorError> {
+        let mut street = Self {
+            cards: [hole.cards[0]; 7],
+            len: 0,
+            counts: [0; 13],
+            suit_masks: [0; 4],
+            best: HandValue::new(HandRank::HighCard, 0),
+        };
+        for card in [hole.cards[0], hole.cards[1], flop[0], flop[1], flop[2]] {
+            street.push(card)?;
+        }
+        street.best = street.evaluate();
+        Ok(street)
+    }
+
+    /// Add the turn card and return the best value of the six cards
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the turn was already added, or if the card is
+    /// malformed or already known.
+    pub fn with_turn(&mut self, card: Card) -> Result<HandValue, EvaluatorError> {
+        if self.len != 5 {
+            return Err(EvaluatorError::invalid_hand("Turn has already been added"));
+        }
+        self.add(card)
+    }
+
+    /// Add the river card and return the best value of all seven cards
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the turn has not been added, the river already
+    /// has, or the card is malformed or already known.
+    pub fn with_river(&mut self, card: Card) -> Result<HandValue, EvaluatorError> {
+        if self.len != 6 {
+            return Err(EvaluatorError::invalid_hand(
+                "River requires the turn and can only be added once",
+            ));
+        }
+        self.add(card)
+    }
+
+    /// Best value of the cards known so far
+    pub fn value(&self) -> HandValue {
+        self.best
+    }
+
+    /// The cards known so far, hole cards first
+    pub fn cards(&self) -> &[Card] {
+        &self.cards[..self.len]
+    }
+
+    fn add(&mut self, card: Card) -> Result<HandValue, EvaluatorError> {
+        self.push(card)?;
+        self.best = self.evaluate();
+        Ok(self.best)
+    }
+
+    /// Check `card` and fold it into the rank counts and suit masks
+    fn push(&mut self, card: Card) -> Result<(), EvaluatorError> {
+        if card.rank > 12 || card.suit > 3 {
+            return Err(EvaluatorError::invalid_card_value(&format!(
+                "rank {} suit {}",
+                card.rank, card.suit
+            )));
+        }
+        if self.cards().contains(&card) {
+            return Err(EvaluatorError::invalid_hand(&format!(
+                "Duplicate card: {}",
+                card
+            )));
+        }
+
+        self.cards[self.len] = card;
+        self.len += 1;
+        self.counts[card.rank as usize] += 1;
+        self.suit_masks[card.suit as usize] |= 1 << card.rank;
+        Ok(())
+    }
+
+    /// Best five-card value of the known cards, packed as
+    /// [`evaluate_5_card_fast`](super::evaluator::evaluate_5_card_fast) does
+    fn evaluate(&self) -> HandValue {
+        // Five cards of one suit rule out quads and full houses in seven
+        if let Some(&suited) = self.suit_masks.iter().find(|mask| mask.count_ones() >= 5) {
+            return match straight_high_card(suited).map(u32::from) {
+                Some(12) => HandValue::new(HandRank::RoyalFlush, 12),
+                Some(high) => HandValue::new(HandRank::StraightFlush, high),
+                None => HandValue::new(HandRank::Flush, highest_ranks(suited, 5)),
+            };
+        }
+
+        // Ranks from the ace down, grouped by how many of each are held
+        let ranks = || {
+            (0..13u32)
+                .rev()
+                .filter(|&rank| self.counts[rank as usize] > 0)
+        };
+        let with_count =
+            |count: u8| ranks().filter(move |&rank| self.counts[rank as usize] >= count);
+        let kicker = |used: &[u32]| ranks().find(|rank| !used.contains(rank)).unwrap_or(0);
+
+        if let Some(quad) = with_count(4).next() {
+            return HandValue::new(HandRank::FourOfAKind, quad * 13 + kicker(&[quad]));
+        }
+        let trip = with_count(3).next();
+        if let Some(trip) = trip {
+            if let Some(pair) = with_count(2).find(|&rank| rank != trip) {
+                return HandValue::new(HandRank::FullHouse, trip * 13 + pair);
+            }
+        }
+
+        let rank_mask = self.suit_masks.iter().fold(0, |mask, suit| mask | suit);
+        if let Some(high) = straight_high_card(rank_mask) {
+            return HandValue::new(HandRank::Straight, u32::from(high));
+        }
+
+        if let Some(trip) = trip {
+            let first = kicker(&[trip]);
+            let second = kicker(&[trip, first]);
+            return HandValue::new(HandRank::ThreeOfAKind, trip * 169 + first * 13 + second);
+        }
+
+        let mut pairs = with_count(2);
+        match (pairs.next(), pairs.next()) {
+            (Some(high), Some(low)) => HandValue::new(
+                HandRank::TwoPair,
+                high * 169 + low * 13 + kicker(&[high, low]),
+            ),
+            (Some(pair), None) => {
+                let first = kicker(&[pair]);
+                let second = kicker(&[pair, first]);
+                let third = kicker(&[pair, first, second]);
+                HandValue::new(
+                    HandRank::Pair,
+                    pair * 2197 + first * 169 + second * 13 + third,
+                )
+            }
+            _ => HandValue::new(HandRank::HighCard, highest_ranks(rank_mask, 5)),
+        }
+    }
+}
+
+/// The `count` highest set bits of a rank mask
+fn highest_ranks(mask: u16, count: u32) -> u32 {
+    let mut mask = u32::from(mask);
+    while mask.count_ones() > count {
+        mask &= mask - 1;
+    }
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::Evaluator;
+    use crate::test_util::card;
+
+    #[test]
+    fn test_incremental_matches_direct_evaluation() {
+        let evaluator = Evaluator::instance();
+        let spots = [
+            (["Ah", "Kh"], ["Qh", "7h", "2c"], "3h", "Jh"),
+            (["9s", "9d"], ["9c", "4h", "4d"], "Kc", "Ks"),
+            (["5c", "6c"], ["7d", "8s", "Ah"], "2d", "9h"),
+            (["Tc", "3d"], ["Kh", "Qs", "7c"], "6h", "2s"),
+        ];
+
+        for (hole, flop, turn, river) in spots {
+            let hole = HoleCards::new(card(hole[0]), card(hole[1])).unwrap();
+            let flop = flop.map(card);
+            let mut street = StreetEvaluator::new(&hole, flop).unwrap();
+
+            let seven = [
+                hole.cards[0],
+                hole.cards[1],
+                flop[0],
+                flop[1],
+                flop[2],
+                card(turn),
+                card(river),
+            ];
+            let six = [seven[0], seven[1], seven[2], seven[3], seven[4], seven[5]];
+
+            assert_eq!(
+                street.with_turn(card(turn)).unwrap(),
+                evaluator.evaluate_6_card(&six)
+            );
+            assert_eq!(
+                street.with_river(card(river)).unwrap(),
+                evaluator.evaluate_7_card(&seven)
+            );
+            assert_eq!(street.cards(), &seven);
+        }
+    }
+
+    #[test]
+    fn test_incremental_matches_direct_evaluation_on_random_deals() {
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let evaluator = Evaluator::instance();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1366);
+        let mut deck = crate::equity::live_cards(&[]);
+        for _ in 0..20_000 {
+            let (dealt, _) = deck.partial_shuffle(&mut rng, 7);
+            let seven: [Card; 7] = std::array::from_fn(|i| dealt[i]);
+            let hole = HoleCards::new(seven[0], seven[1]).unwrap();
+            let mut street = StreetEvaluator::new(&hole, [seven[2], seven[3], seven[4]]).unwrap();
+
+            let five = [seven[0], seven[1], seven[2], seven[3], seven[4]];
+            let six = [seven[0], seven[1], seven[2], seven[3], seven[4], seven[5]];
+            assert_eq!(street.value(), evaluator.evaluate_5_card(&five));
+            assert_eq!(
+                street.with_turn(seven[5]).unwrap(),
+                evaluator.evaluate_6_card(&six)
+            );
+            assert_eq!(
+                street.with_river(seven[6]).unwrap(),
+                evaluator.evaluate_7_card(&seven),
+                "{:?}",
+                seven
+            );
+        }
+    }
+
+    #[test]
+    fn test_street_order_and_duplicates() {
+        let hole = HoleCards::new(card("Ah"), card("Kh")).unwrap();
+        let mut street = StreetEvaluator::new(&hole, [card("Qh"), card("7h"), card("2c")]).unwrap();
+
+        assert!(street.with_river(card("3d")).is_err());
+        assert!(street.with_turn(card("Ah")).is_err());
+        assert_eq!(street.cards().len(), 5);
+
+        street.with_turn(card("3d")).unwrap();
+        assert!(street.with_turn(card("4d")).is_err());
+        street.with_river(card("4d")).unwrap();
+        assert!(street.with_river(card("5d")).is_err());
+
+        assert!(StreetEvaluator::new(&hole, [card("Ah"), card("7h"), card("2c")]).is_err());
+    }
+}
@@ -0,0 +1,452 @@
+//! Effective hand strength and hand potential
+//!
+//! Implements the classic EHS measure for a hand against a single random
+//! opponent:
+//!
+//! - **HS**: probability of currently being ahead, counting ties as half
+//! - **Ppot**: probability that a hand which is behind or tied ends up ahead
+//! - **Npot**: probability that a hand which is ahead or tied ends up behind
+//! - **EHS**: `HS * (1 - Npot) + (1 - HS) * Ppot`
+
+use super::errors::EvaluatorError;
+use super::evaluator::{Evaluator, HandRank, HandValue};
+use crate::equity::live_cards;
+use crate::range::WeightedRange;
+use crate::{Board, Card, DrawType, HoleCards, Street};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::cmp::Ordering;
+
+/// Seed for the potential sampler, fixed so that results are reproducible
+const POTENTIAL_SEED: u64 = 0x45_48_53;
+
+/// Index of the "ahead" row/column in the potential matrix
+const AHEAD: usize = 0;
+/// Index of the "tied" row/column in the potential matrix
+const TIED: usize = 1;
+/// Index of the "behind" row/column in the potential matrix
+const BEHIND: usize = 2;
+
+/// Effective hand strength of a holding on a given board
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EhsResult {
+    /// Current hand strength against one random opponent
+    pub hs: f64,
+    /// Effective hand strength, combining `hs` with the potentials
+    pub ehs: f64,
+    /// Positive potential: chance a behind or tied hand finishes ahead
+    pub ppot: f64,
+    /// Negative potential: chance an ahead or tied hand finishes behind
+    pub npot: f64,
+}
+
+impl Evaluator {
+    /// Compute effective hand strength with positive and negative potential
+    ///
+    /// Current hand strength is enumerated exactly over every opponent
+    /// holding. The potentials are estimated from `iterations` sampled
+    /// (opponent holding, remaining board) pairs, tallied into the usual
+    /// ahead/tied/behind matrix. On the river there are no cards to come, so
+    /// both potentials are zero and `ehs == hs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the board has no flop yet, if the hole cards
+    /// overlap the board, or if `iterations` is zero before the river.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::Evaluator;
+    /// use holdem_core::{Board, Card, HoleCards};
+    /// use std::str::FromStr;
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// let hole = HoleCards::new(Card::from_str("Jh").unwrap(), Card::from_str("Th").unwrap()).unwrap();
+    /// let board = Board::new()
+    ///     .with_flop(["9h", "8c", "2h"].map(|c| Card::from_str(c).unwrap()))
+    ///     .unwrap();
+    ///
+    /// let result = evaluator.effective_hand_strength(&hole, &board, 1000).unwrap();
+    /// assert!(result.ppot > 0.3);
+    /// ```
+    pub fn effective_hand_strength(
+        &self,
+        hole: &HoleCards,
+        board: &Board,
+        iterations: usize,
+    ) -> Result<EhsResult, EvaluatorError> {
+        if board.street() == Street::Preflop {
+            return Err(EvaluatorError::invalid_hand(
+                "Effective hand strength requires at least a flop",
+            ));
+        }
+
+        let board_cards = board.visible_cards();
+        if hole.cards.iter().any(|card| board_cards.contains(card)) {
+            return Err(EvaluatorError::invalid_hand("Hole cards overlap the board"));
+        }
+
+        let remaining: Vec<Card> = live_cards(&hole.cards)
+            .into_iter()
+            .filter(|card| !board_cards.contains(card))
+            .collect();
+
+        let ours = self.value_with(&hole.cards, board_cards, &[]);
+        let mut tally = [0u64; 3];
+        for i in 0..remaining.len() {
+            for j in i + 1..remaining.len() {
+                let theirs = self.value_with(&[remaining[i], remaining[j]], board_cards, &[]);
+                tally[outcome(ours, theirs)] += 1;
+            }
+        }
+        let total = tally.iter().sum::<u64>() as f64;
+        let hs = (tally[AHEAD] as f64 + tally[TIED] as f64 / 2.0) / total;
+
+        let to_come = 5 - board_cards.len();
+        if to_come == 0 {
+            return Ok(EhsResult {
+                hs,
+                ehs: hs,
+                ppot: 0.0,
+                npot: 0.0,
+            });
+        }
+        if iterations == 0 {
+            return Err(EvaluatorError::evaluation_error(
+                "Hand potential requires at least one iteration",
+            ));
+        }
+
+        // hp[now][final] counts samples by current and final standing
+        let mut hp = [[0u64; 3]; 3];
+        let mut hp_total = [0u64; 3];
+        let mut rng = StdRng::seed_from_u64(POTENTIAL_SEED);
+        let mut deck = remaining;
+
+        for _ in 0..iterations {
+            let (drawn, _) = deck.partial_shuffle(&mut rng, 2 + to_come);
+            let opponent = [drawn[0], drawn[1]];
+            let runout = &drawn[2..];
+
+            let now = outcome(ours, self.value_with(&opponent, board_cards, &[]));
+            let ours_final = self.value_with(&hole.cards, board_cards, runout);
+            let theirs_final = self.value_with(&opponent, board_cards, runout);
+
+            hp_total[now] += 1;
+            hp[now][outcome(ours_final, theirs_final)] += 1;
+        }
+
+        let hp = hp.map(|row| row.map(|count| count as f64));
+        let hp_total = hp_total.map(|count| count as f64);
+
+        let ppot = ratio(
+            hp[BEHIND][AHEAD] + hp[BEHIND][TIED] / 2.0 + hp[TIED][AHEAD] / 2.0,
+            hp_total[BEHIND] + hp_total[TIED] / 2.0,
+        );
+        let npot = ratio(
+            hp[AHEAD][BEHIND] + hp[TIED][BEHIND] / 2.0 + hp[AHEAD][TIED] / 2.0,
+            hp_total[AHEAD] + hp_total[TIED] / 2.0,
+        );
+        let ehs = hs * (1.0 - npot) + (1.0 - hs) * ppot;
+
+        Ok(EhsResult {
+            hs,
+            ehs,
+            ppot,
+            npot,
+        })
+    }
+
+    /// Probability that a drawing hand completes its best draw by the river
+    ///
+    /// The target is the category the hand's strongest draw points to, as
+    /// reported by [`HoleCards::best_draw`]: a flush for any flush draw, a
+    /// straight for open-ended and gutshot draws. The result is the share of
+    /// `iterations` sampled runouts (at least one) on which the hand finishes
+    /// at that category or better. A flopped four-flush gets there about 35%
+    /// of the time. Hands without a draw, and boards with no cards to come,
+    /// return `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::Evaluator;
+    /// use holdem_core::{Board, Card, HoleCards};
+    /// use rand::SeedableRng;
+    /// use std::str::FromStr;
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// let hole = HoleCards::new(Card::from_str("Ah").unwrap(), Card::from_str("2h").unwrap()).unwrap();
+    /// let board = Board::from_str("Kh 7h 3c").unwrap();
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let p = evaluator.best_draw_equity(&hole, &board, 10_000, &mut rng);
+    /// assert!((p - 0.35).abs() < 0.02);
+    /// ```
+    pub fn best_draw_equity(
+        &self,
+        hole: &HoleCards,
+        board: &Board,
+        iterations: usize,
+        rng: &mut impl rand::Rng,
+    ) -> f64 {
+        let target = match hole.best_draw(board) {
+            Some(DrawType::NutFlushDraw | DrawType::FlushDraw | DrawType::BackdoorFlush) => {
+                HandRank::Flush
+            }
+            Some(DrawType::OpenEnded | DrawType::Gutshot) => HandRank::Straight,
+            None => return 0.0,
+        };
+
+        let board_cards = board.visible_cards();
+        let to_come = 5 - board_cards.len();
+        let mut deck: Vec<Card> = live_cards(&hole.cards)
+            .into_iter()
+            .filter(|card| !board_cards.contains(card))
+            .collect();
+
+        let iterations = iterations.max(1);
+        let mut completed = 0;
+        for _ in 0..iterations {
+            let (runout, _) = deck.partial_shuffle(rng, to_come);
+            if self.value_with(&hole.cards, board_cards, runout).rank >= target {
+                completed += 1;
+            }
+        }
+        completed as f64 / iterations as f64
+    }
+
+    /// Probability that hero's currently winning hand is outdrawn by the river
+    ///
+    /// Only villain combos that hero beats on the current board take part,
+    /// sampled by their range weight; combos sharing a card with hero or the
+    /// board are impossible and skipped. Each of `iterations` samples (at
+    /// least one) deals the rest of the board uniformly and counts a crack
+    /// when villain finishes ahead. Returns `0.0` before the flop, on the
+    /// river, or when hero is ahead of no combo in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::evaluator::Evaluator;
+    /// use holdem_core::range::top_percent_range;
+    /// use holdem_core::{Board, HoleCards};
+    /// use rand::SeedableRng;
+    /// use std::str::FromStr;
+    ///
+    /// let evaluator = Evaluator::instance();
+    /// let hero = HoleCards::from_notation("QQ").unwrap();
+    /// let board = Board::from_str("Jh Th 7h").unwrap();
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let cracked = evaluator.beat_probability_by_river(
+    ///     &hero,
+    ///     &top_percent_range(50.0),
+    ///     &board,
+    ///     5000,
+    ///     &mut rng,
+    /// );
+    /// assert!(cracked > 0.2);
+    /// ```
+    pub fn beat_probability_by_river(
+        &self,
+        hero: &HoleCards,
+        villain_range: &WeightedRange,
+        board: &Board,
+        iterations: usize,
+        rng: &mut impl rand::Rng,
+    ) -> f64 {
+        let board_cards = board.visible_cards();
+        if !(3..5).contains(&board_cards.len())
+            || hero.cards.iter().any(|card| board_cards.contains(card))
+        {
+            return 0.0;
+        }
+
+        let hero_value = self.value_with(&hero.cards, board_cards, &[]);
+        let behind = villain_range.sampler(|villain| {
+            let blocked = villain
+                .cards
+                .iter()
+                .any(|card| hero.cards.contains(card) || board_cards.contains(card));
+            !blocked && self.value_with(&villain.cards, board_cards, &[]) < hero_value
+        });
+
+        let to_come = 5 - board_cards.len();
+        let mut deck: Vec<Card> = live_cards(&hero.cards)
+            .into_iter()
+            .filter(|card| !board_cards.contains(card))
+            .collect();
+
+        let iterations = iterations.max(1);
+        let mut cracked = 0;
+        for _ in 0..iterations {
+            let Some(villain) = behind.sample(rng) else {
+                return 0.0;
+            };
+
+            // Redeal until the runout avoids villain's cards
+            let runout = loop {
+                let (runout, _) = deck.partial_shuffle(rng, to_come);
+                if !runout.iter().any(|card| villain.cards.contains(card)) {
+                    break runout;
+                }
+            };
+
+            let hero_final = self.value_with(&hero.cards, board_cards, runout);
+            let villain_final = self.value_with(&villain.cards, board_cards, runout);
+            if villain_final > hero_final {
+                cracked += 1;
+            }
+        }
+        cracked as f64 / iterations as f64
+    }
+
+    /// Value of two hole cards combined with the board and any extra cards
+    ///
+    /// The cards must be distinct, which is checked in debug builds only.
+    pub(super) fn value_with(&self, hole: &[Card; 2], board: &[Card], extra: &[Card]) -> HandValue {
+        let mut cards = [hole[0]; 7];
+        let mut len = 0;
+        for card in hole.iter().chain(board).chain(extra) {
+            cards[len] = *card;
+            len += 1;
+        }
+        debug_assert!(
+            (0..len).all(|i| !cards[i + 1..len].contains(&cards[i])),
+            "hole cards, board and extra cards overlap"
+        );
+        self.evaluate_cards(&cards[..len])
+    }
+}
+
+/// Standing of our hand against the opponent's
+fn outcome(ours: HandValue, theirs: HandValue) -> usize {
+    match ours.cmp(&theirs) {
+        Ordering::Greater => AHEAD,
+        Ordering::Equal => TIED,
+        Ordering::Less => BEHIND,
+    }
+}
+
+/// Divide, treating an empty denominator as zero probability
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{card, hole};
+    use std::str::FromStr;
+
+    fn cards<const N: usize>(notation: [&str; N]) -> [Card; N] {
+        notation.map(card)
+    }
+
+    #[test]
+    fn test_draw_heavy_hand_has_high_positive_potential() {
+        let evaluator = Evaluator::instance();
+        let board = Board::new().with_flop(cards(["9h", "8c", "2h"])).unwrap();
+
+        let draw = evaluator
+            .effective_hand_strength(&hole("Jh", "Th"), &board, 2000)
+            .unwrap();
+
+        let made_board = Board::new().with_flop(cards(["Kc", "7d", "2s"])).unwrap();
+        let made = evaluator
+            .effective_hand_strength(&hole("As", "Ad"), &made_board, 2000)
+            .unwrap();
+
+        assert!(draw.ppot > 0.3, "ppot was {}", draw.ppot);
+        assert!(draw.ppot > made.ppot);
+        assert!(draw.ehs > draw.hs);
+        assert!(made.hs > 0.8);
+    }
+
+    #[test]
+    fn test_river_has_no_potential() {
+        let evaluator = Evaluator::instance();
+        let board = Board::new()
+            .with_flop(cards(["Kc", "7d", "2s"]))
+            .unwrap()
+            .with_turn(Card::from_str("9h").unwrap())
+            .unwrap()
+            .with_river(Card::from_str("4c").unwrap())
+            .unwrap();
+
+        let result = evaluator
+            .effective_hand_strength(&hole("As", "Ad"), &board, 100)
+            .unwrap();
+
+        assert_eq!(result.ppot, 0.0);
+        assert_eq!(result.npot, 0.0);
+        assert_eq!(result.ehs, result.hs);
+    }
+
+    #[test]
+    fn test_four_flush_completes_at_the_exact_probability() {
+        let evaluator = Evaluator::instance();
+        let mut rng = StdRng::seed_from_u64(1392);
+        let board = Board::new().with_flop(cards(["Kh", "7h", "3c"])).unwrap();
+
+        // Nine hearts among 47 unseen cards, and nothing else reaches a flush
+        let exact = 1.0 - (38.0 / 47.0) * (37.0 / 46.0);
+        let p = evaluator.best_draw_equity(&hole("Ah", "2h"), &board, 100_000, &mut rng);
+        assert!((p - exact).abs() < 0.005, "completion was {}", p);
+
+        let made = Board::new().with_flop(cards(["Kh", "7h", "3h"])).unwrap();
+        assert_eq!(
+            evaluator.best_draw_equity(&hole("Ah", "2h"), &made, 100, &mut rng),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_overpair_is_cracked_more_on_wet_boards() {
+        use crate::range::top_percent_range;
+
+        let evaluator = Evaluator::instance();
+        let hero = hole("Qs", "Qd");
+        let range = top_percent_range(50.0);
+        let mut rng = StdRng::seed_from_u64(POTENTIAL_SEED);
+
+        let wet = Board::new().with_flop(cards(["Jh", "Th", "7h"])).unwrap();
+        let dry = Board::new().with_flop(cards(["Jc", "6d", "2s"])).unwrap();
+        let wet_cracked = evaluator.beat_probability_by_river(&hero, &range, &wet, 3000, &mut rng);
+        let dry_cracked = evaluator.beat_probability_by_river(&hero, &range, &dry, 3000, &mut rng);
+
+        assert!(
+            wet_cracked > dry_cracked + 0.1,
+            "wet {} vs dry {}",
+            wet_cracked,
+            dry_cracked
+        );
+
+        let river = Board::from_str("Jc 6d 2s 3h 8c").unwrap();
+        assert_eq!(
+            evaluator.beat_probability_by_river(&hero, &range, &river, 100, &mut rng),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs_are_rejected() {
+        let evaluator = Evaluator::instance();
+        let flop = Board::new().with_flop(cards(["Kc", "7d", "2s"])).unwrap();
+
+        assert!(evaluator
+            .effective_hand_strength(&hole("As", "Ad"), &Board::new(), 100)
+            .is_err());
+        assert!(evaluator
+            .effective_hand_strength(&hole("Kc", "Ad"), &flop, 100)
+            .is_err());
+        assert!(evaluator
+            .effective_hand_strength(&hole("As", "Ad"), &flop, 0)
+            .is_err());
+    }
+}
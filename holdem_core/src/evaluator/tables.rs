@@ -36,9 +36,10 @@
 //! - **Cache Efficiency**: Sequential access patterns optimized for CPU cache
 
 use super::errors::EvaluatorError;
-use super::evaluator::{HandRank, HandValue};
+use super::evaluator::{straight_high_card, HandRank, HandValue};
+use super::logging::progress;
 use crate::card::PackedCard;
-use crate::{Card, Hand};
+use crate::Card;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -130,8 +131,8 @@ impl CanonicalMapping {
         }
 
         // Collect unique suits in order of appearance
-        for suit in 0..4 {
-            if suit_counts[suit] > 0 {
+        for (suit, &count) in suit_counts.iter().enumerate() {
+            if count > 0 {
                 card_suits.push(suit as u8);
             }
         }
@@ -182,11 +183,11 @@ impl CanonicalMapping {
             current[i] = suit;
         }
         // Fill remaining positions with valid suits (0-3) that don't conflict
-        for i in suit_count..4 {
+        for slot in current.iter_mut().skip(suit_count) {
             // Find a suit value that's not already used
             for candidate in 0..4 {
                 if !suits.contains(&candidate) {
-                    current[i] = candidate;
+                    *slot = candidate;
                     break;
                 }
             }
@@ -293,7 +294,7 @@ impl CanonicalMapping {
                 let canonical_suit = card & 0x03;
                 // original_suit method already handles invalid canonical suits
                 let original_suit = self.original_suit(canonical_suit);
-                (rank as u8) << 2 | (original_suit as u8)
+                rank << 2 | original_suit
             })
             .collect()
     }
@@ -378,7 +379,7 @@ impl CanonicalMapping {
 
     /// Generate card permutations for a given suit assignment
     fn generate_card_permutations(
-        suit_map: &[u8; 4],
+        _suit_map: &[u8; 4],
         _depth: usize,
         _permutations: &mut Vec<[u8; 4]>,
     ) {
@@ -500,7 +501,7 @@ impl JumpTable {
             match entry {
                 JumpTableEntry::Terminal(hand_value) => {
                     // Validate hand value - allow all valid hand ranks (0-9)
-                    if (hand_value.rank as u8) > (HandRank::RoyalFlush as u8) {
+                    if hand_value.rank.as_u8() > HandRank::RoyalFlush.as_u8() {
                         return Err(EvaluatorError::table_init_failed(&format!(
                             "Invalid hand rank in terminal entry at index {}: {:?}",
                             i, hand_value.rank
@@ -530,7 +531,7 @@ impl JumpTable {
         // For practical purposes, we'll generate a representative subset
         // In production, this would be done in batches or with streaming
 
-        println!("Generating canonical 7-card combinations...");
+        progress!("Generating canonical 7-card combinations...");
 
         // For now, generate a smaller subset for testing and development
         // In production, this would generate all combinations
@@ -556,7 +557,7 @@ impl JumpTable {
                                     combinations.push(combo);
 
                                     if combinations.len() >= max_combinations {
-                                        println!(
+                                        progress!(
                                             "Generated {} combinations for testing",
                                             combinations.len()
                                         );
@@ -570,47 +571,46 @@ impl JumpTable {
             }
         }
 
-        println!("Generated {} canonical combinations", combinations.len());
+        progress!("Generated {} canonical combinations", combinations.len());
         Ok(combinations)
     }
 
     /// Build the jump table using bottom-up trie construction
     pub fn build(&mut self) -> Result<(), EvaluatorError> {
-        println!("Building jump table with {} entries...", self.size);
+        progress!("Building jump table with {} entries...", self.size);
 
         // Step 1: Generate canonical mappings for all 7-card combinations
         let combinations = self.generate_canonical_combinations()?;
 
         // Step 2: Build Level 5 (terminal nodes) - 5-card hand evaluations
-        println!("Building Level 5 terminal nodes...");
+        progress!("Building Level 5 terminal nodes...");
         self.build_level_5(&combinations)?;
 
         // Step 3: Build Level 6 (intermediate nodes) - 6-card combinations
-        println!("Building Level 6 intermediate nodes...");
+        progress!("Building Level 6 intermediate nodes...");
         self.build_level_6(&combinations)?;
 
         // Step 4: Build Level 7 (root nodes) - 7-card combinations
-        println!("Building Level 7 root nodes...");
+        progress!("Building Level 7 root nodes...");
         self.build_level_7(&combinations)?;
 
         // Step 5: Flatten the trie into contiguous array
-        println!("Flattening trie structure...");
+        progress!("Flattening trie structure...");
         self.flatten_trie()?;
 
         // Update metadata
         self.metadata.total_combinations = combinations.len();
         self.metadata.memory_usage = self.memory_usage();
 
-        println!("Jump table construction complete!");
+        progress!("Jump table construction complete!");
         Ok(())
     }
 
     /// Build Level 5 terminal nodes (5-card hand evaluations)
     fn build_level_5(&mut self, combinations: &[Vec<PackedCard>]) -> Result<(), EvaluatorError> {
         use super::super::card::Card;
-        use std::str::FromStr;
 
-        println!("Building Level 5: Terminal nodes for 5-card hands...");
+        progress!("Building Level 5: Terminal nodes for 5-card hands...");
 
         let mut level5_count = 0;
         let start_time = std::time::Instant::now();
@@ -628,7 +628,7 @@ impl JumpTable {
                                 for m in (l + 1)..combo.len() {
                                     // Convert PackedCard to Card for evaluation
                                     let five_cards =
-                                        vec![combo[i], combo[j], combo[k], combo[l], combo[m]];
+                                        [combo[i], combo[j], combo[k], combo[l], combo[m]];
 
                                     // Create a hash key for uniqueness
                                     let mut key = [0u8; 5];
@@ -645,7 +645,7 @@ impl JumpTable {
             }
         }
 
-        println!(
+        progress!(
             "Found {} unique 5-card combinations",
             unique_5_card_hands.len()
         );
@@ -660,8 +660,8 @@ impl JumpTable {
             let cards: Vec<Card> = card_key
                 .iter()
                 .map(|&key| {
-                    let rank = (key >> 2) as u8;
-                    let suit = (key & 0x03) as u8;
+                    let rank = key >> 2;
+                    let suit = key & 0x03;
                     Card::new(rank, suit).unwrap()
                 })
                 .collect();
@@ -676,14 +676,15 @@ impl JumpTable {
             level5_count += 1;
 
             if level5_count % 10000 == 0 {
-                println!("Processed {} Level 5 entries", level5_count);
+                progress!("Processed {} Level 5 entries", level5_count);
             }
         }
 
         let elapsed = start_time.elapsed();
-        println!(
+        progress!(
             "Level 5 construction complete: {} nodes in {:?}",
-            level5_count, elapsed
+            level5_count,
+            elapsed
         );
 
         self.metadata.stats.level5_nodes = level5_count;
@@ -694,7 +695,7 @@ impl JumpTable {
 
     /// Build Level 6 intermediate nodes (6-card combinations)
     fn build_level_6(&mut self, combinations: &[Vec<PackedCard>]) -> Result<(), EvaluatorError> {
-        println!("Building Level 6: Intermediate nodes for 6-card hands...");
+        progress!("Building Level 6: Intermediate nodes for 6-card hands...");
 
         let mut level6_count = 0;
         let start_time = std::time::Instant::now();
@@ -725,14 +726,15 @@ impl JumpTable {
             }
 
             if combo_index % 1000 == 0 {
-                println!("Processed {} 7-card combinations for Level 6", combo_index);
+                progress!("Processed {} 7-card combinations for Level 6", combo_index);
             }
         }
 
         let elapsed = start_time.elapsed();
-        println!(
+        progress!(
             "Level 6 construction complete: {} nodes in {:?}",
-            level6_count, elapsed
+            level6_count,
+            elapsed
         );
 
         self.metadata.stats.level6_nodes = level6_count;
@@ -743,7 +745,7 @@ impl JumpTable {
 
     /// Build Level 7 root nodes (7-card combinations)
     fn build_level_7(&mut self, combinations: &[Vec<PackedCard>]) -> Result<(), EvaluatorError> {
-        println!("Building Level 7: Root nodes for 7-card hands...");
+        progress!("Building Level 7: Root nodes for 7-card hands...");
 
         let mut level7_count = 0;
         let start_time = std::time::Instant::now();
@@ -765,14 +767,15 @@ impl JumpTable {
             }
 
             if combo_index % 1000 == 0 {
-                println!("Processed {} 7-card combinations for Level 7", combo_index);
+                progress!("Processed {} 7-card combinations for Level 7", combo_index);
             }
         }
 
         let elapsed = start_time.elapsed();
-        println!(
+        progress!(
             "Level 7 construction complete: {} nodes in {:?}",
-            level7_count, elapsed
+            level7_count,
+            elapsed
         );
 
         self.metadata.stats.level7_nodes = level7_count;
@@ -784,7 +787,7 @@ impl JumpTable {
     /// Flatten the trie structure into a contiguous array
     fn flatten_trie(&mut self) -> Result<(), EvaluatorError> {
         let start_time = std::time::Instant::now();
-        println!("Flattening trie structure for optimal memory layout...");
+        progress!("Flattening trie structure for optimal memory layout...");
 
         // In a full implementation, this would:
         // 1. Analyze access patterns to determine optimal node ordering
@@ -800,16 +803,12 @@ impl JumpTable {
         let mut mapping = vec![0usize; self.size];
 
         // Simple optimization: group terminal entries first, then offsets
-        let mut terminal_count = 0;
-        let mut offset_count = 0;
-
-        // First pass: count entries by type
-        for entry in &self.data {
-            match entry {
-                JumpTableEntry::Terminal(_) => terminal_count += 1,
-                JumpTableEntry::Offset(_) => offset_count += 1,
-            }
-        }
+        // First pass: count terminal entries
+        let terminal_count = self
+            .data
+            .iter()
+            .filter(|entry| matches!(entry, JumpTableEntry::Terminal(_)))
+            .count();
 
         // Second pass: reorganize with terminals first, then offsets
         let mut terminal_idx = 0;
@@ -848,7 +847,7 @@ impl JumpTable {
         self.data = new_data;
 
         let elapsed = start_time.elapsed();
-        println!("Trie flattening complete in {:?}", elapsed);
+        progress!("Trie flattening complete in {:?}", elapsed);
 
         self.metadata.stats.flattening_time_ms = elapsed.as_millis() as u64;
 
@@ -869,10 +868,11 @@ impl JumpTable {
 
         // Simple evaluation based on rank pattern
         let is_flush = cards.iter().all(|c| c.suit() == cards[0].suit());
-        let is_straight = self.is_straight_ranks(&ranks);
+        let rank_mask = ranks.iter().fold(0u16, |mask, &rank| mask | 1 << rank);
+        let straight_high = straight_high_card(rank_mask).map(u32::from);
 
-        if is_flush && is_straight {
-            HandValue::new(HandRank::StraightFlush, ranks[4] as u32)
+        if let (true, Some(high)) = (is_flush, straight_high) {
+            HandValue::new(HandRank::StraightFlush, high)
         } else if self.has_n_of_kind(&ranks, 4) {
             HandValue::new(
                 HandRank::FourOfAKind,
@@ -882,8 +882,8 @@ impl JumpTable {
             HandValue::new(HandRank::FullHouse, ranks[2] as u32 * 13 + ranks[0] as u32)
         } else if is_flush {
             HandValue::new(HandRank::Flush, self.calculate_flush_value(&ranks))
-        } else if is_straight {
-            HandValue::new(HandRank::Straight, ranks[4] as u32)
+        } else if let Some(high) = straight_high {
+            HandValue::new(HandRank::Straight, high)
         } else if self.has_n_of_kind(&ranks, 3) {
             HandValue::new(
                 HandRank::ThreeOfAKind,
@@ -944,7 +944,8 @@ impl JumpTable {
     fn find_level5_index(&self, hand_value: &HandValue) -> usize {
         // Simple hash function for level 5 indexing
         // In practice, this would use the perfect hash algorithm
-        ((hand_value.rank as usize * 1000) + (hand_value.value as usize % 1000)) % (self.size / 3)
+        ((hand_value.rank.as_u8() as usize * 1000) + (hand_value.value as usize % 1000))
+            % (self.size / 3)
     }
 
     /// Find the Level 6 index for a given hand value and cards
@@ -954,32 +955,13 @@ impl JumpTable {
         0 // Placeholder
     }
 
-    /// Check if ranks form a straight
-    fn is_straight_ranks(&self, ranks: &[u8]) -> bool {
-        if ranks.len() != 5 {
-            return false;
-        }
-
-        // Check for regular straight
-        for i in 0..4 {
-            if ranks[i] + 1 != ranks[i + 1] {
-                // Check for wheel straight (A,2,3,4,5)
-                if ranks == [0, 1, 2, 3, 12] {
-                    return true;
-                }
-                return false;
-            }
-        }
-        true
-    }
-
     /// Check if hand has N of a kind
     fn has_n_of_kind(&self, ranks: &[u8], n: usize) -> bool {
         let mut rank_counts = [0u8; 13];
         for &rank in ranks {
             rank_counts[rank as usize] += 1;
         }
-        rank_counts.iter().any(|&count| count == n as u8)
+        rank_counts.contains(&(n as u8))
     }
 
     /// Check if hand has a full house
@@ -988,7 +970,7 @@ impl JumpTable {
         for &rank in ranks {
             rank_counts[rank as usize] += 1;
         }
-        rank_counts.iter().any(|&count| count == 3) && rank_counts.iter().any(|&count| count == 2)
+        rank_counts.contains(&3) && rank_counts.contains(&2)
     }
 
     /// Check if hand has two pair
@@ -1071,7 +1053,7 @@ impl JumpTable {
         // Convert canonical cards to Card array for evaluation
         let packed_cards: Vec<PackedCard> = canonical_cards
             .iter()
-            .map(|&c| PackedCard::new((c >> 2) as u8, (c & 0x03) as u8).unwrap())
+            .map(|&c| PackedCard::new(c >> 2, c & 0x03).unwrap())
             .collect();
         let card_array = self.packed_cards_to_cards(&packed_cards)?;
 
@@ -1114,7 +1096,7 @@ impl JumpTable {
         // Use jump table traversal: idx = table[idx + card]
         let packed_cards: Vec<PackedCard> = canonical_cards
             .iter()
-            .map(|&c| PackedCard::new((c >> 2) as u8, (c & 0x03) as u8).unwrap())
+            .map(|&c| PackedCard::new(c >> 2, c & 0x03).unwrap())
             .collect();
         let best_hand_value = self.find_best_5_card_from_6_card(&packed_cards)?;
 
@@ -1139,7 +1121,7 @@ impl JumpTable {
 
         let packed_cards: Vec<PackedCard> = canonical_cards
             .iter()
-            .map(|&c| PackedCard::new((c >> 2) as u8, (c & 0x03) as u8).unwrap())
+            .map(|&c| PackedCard::new(c >> 2, c & 0x03).unwrap())
             .collect();
         let best_hand_value = self.evaluate_canonical_7_card(&packed_cards)?;
         Ok(best_hand_value)
@@ -1160,7 +1142,7 @@ impl JumpTable {
             let rank = card.rank();
             let suit = card.suit();
             // Use canonical card representation for jump table traversal
-            let card_value = ((rank as u8) << 2 | suit) as usize;
+            let card_value = (rank << 2 | suit) as usize;
             match self.get(current_idx + card_value) {
                 Some(JumpTableEntry::Offset(next_idx)) => {
                     current_idx = next_idx;
@@ -1251,11 +1233,11 @@ pub fn generate_suit_permutations(card_suits: &[u8]) -> Vec<[u8; 4]> {
         current[i] = suit;
     }
     // Fill remaining positions with valid suits (0-3) that don't conflict
-    for i in card_suits.len()..4 {
+    for slot in current.iter_mut().skip(card_suits.len()) {
         // Find a suit value that's not already used
         for candidate in 0..4 {
             if !card_suits.contains(&candidate) {
-                current[i] = candidate;
+                *slot = candidate;
                 break;
             }
         }
@@ -1502,7 +1484,7 @@ mod tests {
         assert_eq!(mapping.len(), 52);
 
         // Each card should map to at least one canonical representation
-        for (card_index, canonical_cards) in &mapping {
+        for canonical_cards in mapping.values() {
             assert!(!canonical_cards.is_empty());
             assert!(canonical_cards.iter().all(|&c| c < 52));
         }
@@ -0,0 +1,113 @@
+//! Wild card evaluation
+//!
+//! Evaluates 5-card hands where jokers, or any designated cards such as the
+//! deuces in "deuces wild", may stand for whatever card makes the best hand.
+//! Wild cards make five of a kind possible, which ranks above a royal flush.
+
+use super::evaluator::{evaluate_5_card_fast, HandRank, HandValue};
+use crate::equity::for_each_runout;
+use crate::Card;
+
+/// Evaluate a 5-card hand containing wild cards
+///
+/// Jokers are always wild; any card listed in `wilds` is wild as well. Each
+/// wild card is replaced by the card that maximizes the hand, so four natural
+/// nines and a wild card make five of a kind.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::evaluator::wild::evaluate_5_card_wild;
+/// use holdem_core::evaluator::HandRank;
+/// use holdem_core::Card;
+/// use std::str::FromStr;
+///
+/// let cards = [
+///     Card::from_str("9h").unwrap(),
+///     Card::from_str("9d").unwrap(),
+///     Card::from_str("9c").unwrap(),
+///     Card::from_str("9s").unwrap(),
+///     Card::joker(),
+/// ];
+/// assert_eq!(evaluate_5_card_wild(&cards, &[]).rank, HandRank::FiveOfAKind);
+/// ```
+pub fn evaluate_5_card_wild(cards: &[Card; 5], wilds: &[Card]) -> HandValue {
+    let naturals: Vec<Card> = cards
+        .iter()
+        .filter(|card| !card.is_joker() && !wilds.contains(card))
+        .copied()
+        .collect();
+    let wild_count = cards.len() - naturals.len();
+
+    if wild_count == 0 {
+        return evaluate_5_card_fast(cards);
+    }
+
+    // Naturals sharing a single rank (or none at all) become five of a kind
+    let five_rank = naturals.first().map_or(12, |card| card.rank);
+    if naturals.iter().all(|card| card.rank == five_rank) {
+        return HandValue::new(HandRank::FiveOfAKind, five_rank as u32);
+    }
+
+    let mut best = HandValue::new(HandRank::HighCard, 0);
+    let mut hand = [cards[0]; 5];
+    hand[..naturals.len()].copy_from_slice(&naturals);
+    for_each_runout(&naturals, wild_count, |substitutes| {
+        hand[naturals.len()..].copy_from_slice(substitutes);
+        best = best.max(evaluate_5_card_fast(&hand));
+    });
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::card;
+
+    #[test]
+    fn test_joker_completes_five_of_a_kind() {
+        let cards = [
+            card("9h"),
+            card("9d"),
+            card("9c"),
+            card("9s"),
+            Card::joker(),
+        ];
+        let value = evaluate_5_card_wild(&cards, &[]);
+
+        assert_eq!(value, HandValue::new(HandRank::FiveOfAKind, 7));
+        assert!(value.rank > HandRank::RoyalFlush);
+    }
+
+    #[test]
+    fn test_joker_used_as_flush_card() {
+        let cards = [
+            card("Kh"),
+            card("9h"),
+            card("7h"),
+            card("4h"),
+            Card::joker(),
+        ];
+        let expected =
+            evaluate_5_card_fast(&[card("Ah"), card("Kh"), card("9h"), card("7h"), card("4h")]);
+
+        assert_eq!(evaluate_5_card_wild(&cards, &[]), expected);
+        assert_eq!(expected.rank, HandRank::Flush);
+    }
+
+    #[test]
+    fn test_designated_wild_cards() {
+        // Deuces wild: two deuces turn a pair of kings into four of a kind
+        let cards = [card("Kh"), card("Kd"), card("2c"), card("2s"), card("5h")];
+        let deuces = [card("2h"), card("2d"), card("2c"), card("2s")];
+
+        assert_eq!(
+            evaluate_5_card_wild(&cards, &deuces).rank,
+            HandRank::FourOfAKind
+        );
+        assert_eq!(
+            evaluate_5_card_wild(&cards, &[]),
+            evaluate_5_card_fast(&cards)
+        );
+    }
+}
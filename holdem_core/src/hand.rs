@@ -53,6 +53,7 @@
 //! ```
 
 use crate::card::Card;
+use crate::equity::{card_mask, live_cards};
 use crate::errors::PokerError;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -355,10 +356,256 @@ impl Hand {
     ///     println!("Card: {}", card);
     /// }
     /// ```
-    pub fn iter(&self) -> std::slice::Iter<Card> {
+    pub fn iter(&self) -> std::slice::Iter<'_, Card> {
         self.cards[0..self.len].iter()
     }
 
+    /// Returns a copy of the hand in canonical order
+    ///
+    /// Valid cards are sorted by descending rank, then descending suit, and
+    /// unused slots are reset to the same filler card. Hands built through the
+    /// constructors are already canonical; this normalizes hands whose public
+    /// fields were filled in directly, so that equal card sets compare and hash
+    /// equal and can be used as map keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Hand, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let a = Hand::from_notation("As Kd Qh").unwrap();
+    /// let b = Hand::from_notation("Qh As Kd").unwrap();
+    /// assert_eq!(a.sorted(), b.sorted());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn sorted(&self) -> Hand {
+        let mut cards = [Card { rank: 0, suit: 0 }; 7];
+        cards[..self.len].copy_from_slice(self.cards());
+        cards[..self.len].sort_by(|a, b| b.cmp(a));
+        Hand {
+            cards,
+            len: self.len,
+        }
+    }
+
+    /// Removes a card from the hand
+    ///
+    /// The remaining cards keep their rank-descending order and the length
+    /// decreases by one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Hand, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let mut hand = Hand::from_notation("As Kd Qh").unwrap();
+    /// hand.remove_card(Card::from_str("Kd").unwrap()).unwrap();
+    /// assert_eq!(hand.len, 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::CardNotInHand` if the card is not in the hand.
+    pub fn remove_card(&mut self, card: Card) -> Result<(), PokerError> {
+        let index = self
+            .cards()
+            .iter()
+            .position(|&c| c == card)
+            .ok_or(PokerError::CardNotInHand(card))?;
+
+        self.cards.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        self.cards[self.len] = Card { rank: 0, suit: 0 };
+        Ok(())
+    }
+
+    /// Adds several cards at once, all or nothing
+    ///
+    /// The whole result is validated in one pass; if it would exceed seven
+    /// cards or hold a duplicate, the hand is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Hand, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let mut hand = Hand::from_notation("As Kd").unwrap();
+    /// let flop = ["Qh", "Js", "Tc"].map(|c| Card::from_str(c).unwrap());
+    /// hand.add_cards(&flop).unwrap();
+    /// assert_eq!(hand.len, 5);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::InvalidHandSize` if the hand would have more than
+    /// seven cards, or `PokerError::DuplicateCard` if a card is repeated or
+    /// already in the hand.
+    pub fn add_cards(&mut self, cards: &[Card]) -> Result<(), PokerError> {
+        let mut combined = self.cards().to_vec();
+        combined.extend_from_slice(cards);
+        *self = Self::new(combined)?;
+        Ok(())
+    }
+
+    /// Replaces one card in the hand with another
+    ///
+    /// The length is unchanged. Because hands are kept in rank-descending
+    /// order, the new card takes the position its rank dictates, which is the
+    /// old card's position whenever the order allows it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Hand, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let mut hand = Hand::from_notation("As Kd Qh").unwrap();
+    /// hand.replace_card(Card::from_str("Kd").unwrap(), Card::from_str("Kh").unwrap()).unwrap();
+    /// assert_eq!(hand.cards()[1], Card::from_str("Kh").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::CardNotInHand` if `old` is not in the hand, or
+    /// `PokerError::DuplicateCard` if `new` is already in it.
+    pub fn replace_card(&mut self, old: Card, new: Card) -> Result<(), PokerError> {
+        let index = self
+            .cards()
+            .iter()
+            .position(|&c| c == old)
+            .ok_or(PokerError::CardNotInHand(old))?;
+        if old != new && self.cards().contains(&new) {
+            return Err(PokerError::DuplicateCard(new));
+        }
+
+        self.cards[index] = new;
+        self.cards[..self.len].sort_by(|a, b| b.cmp(a));
+        Ok(())
+    }
+
+    /// Returns the valid cards in canonical (descending rank, then suit) order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::{Hand, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let hand = Hand::from_notation("2h As Kd").unwrap();
+    /// let order: Vec<String> = hand.canonical_order().iter().map(|c| c.to_string()).collect();
+    /// assert_eq!(order, ["As", "Kd", "2h"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn canonical_order(&self) -> Vec<Card> {
+        self.sorted().cards().to_vec()
+    }
+
+    /// Returns every card in either hand, without duplicates
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Hand;
+    ///
+    /// let hole = Hand::from_notation("As Kd").unwrap();
+    /// let flop = Hand::from_notation("Kd 7c 2h").unwrap();
+    /// assert_eq!(hole.union(&flop).unwrap().len, 4);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::InvalidHandSize` if the combined hand would have
+    /// more than seven cards.
+    pub fn union(&self, other: &Hand) -> Result<Hand, PokerError> {
+        Self::from_mask(self.mask() | other.mask())
+    }
+
+    /// Returns the cards present in both hands
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn intersection(&self, other: &Hand) -> Hand {
+        Self::from_mask(self.mask() & other.mask()).unwrap()
+    }
+
+    /// Returns the cards of this hand that are not in `other`
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn difference(&self, other: &Hand) -> Hand {
+        Self::from_mask(self.mask() & !other.mask()).unwrap()
+    }
+
+    /// Returns a 13-bit mask of the ranks present, bit `rank` for each rank
+    ///
+    /// Suits and repeated ranks are ignored, so quick straight checks can
+    /// look for five adjacent bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Hand;
+    ///
+    /// let hand = Hand::from_notation("As Ad Kc 2h").unwrap();
+    /// assert_eq!(hand.rank_signature(), 1 << 12 | 1 << 11 | 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn rank_signature(&self) -> u16 {
+        self.cards()
+            .iter()
+            .fold(0, |signature, card| signature | 1 << card.rank)
+    }
+
+    /// Returns how many cards of each rank the hand holds, indexed by rank
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Hand;
+    ///
+    /// let hand = Hand::from_notation("As Ad Kc 2h").unwrap();
+    /// assert_eq!(hand.rank_count_signature()[12], 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn rank_count_signature(&self) -> [u8; 13] {
+        let mut counts = [0; 13];
+        for card in self.cards() {
+            counts[card.rank as usize] += 1;
+        }
+        counts
+    }
+
+    /// Bitmask of the hand's cards, one bit per `rank * 4 + suit`
+    fn mask(&self) -> u64 {
+        card_mask(self.cards())
+    }
+
+    /// Builds a hand from a card bitmask
+    fn from_mask(mask: u64) -> Result<Hand, PokerError> {
+        let cards = live_cards(&[])
+            .into_iter()
+            .filter(|card| card_mask(std::slice::from_ref(card)) & mask != 0)
+            .collect();
+        Self::new(cards)
+    }
+
     /// Placeholder for hand strength evaluation (to be implemented with fast evaluator)
     ///
     /// Returns a placeholder strength value. In the future, this will integrate with
@@ -455,13 +702,71 @@ impl Hand {
         // TODO: Implement proper 5-card selection algorithm
         // For now, return first 5 cards (sorted)
         let mut best = [Card::new(0, 0).unwrap(); 5];
-        for i in 0..5 {
-            best[i] = self.cards[i];
-        }
+        best.copy_from_slice(&self.cards[..5]);
         best
     }
 }
 
+/// Assembles a [`Hand`] from hole cards, a board and individual cards
+///
+/// Cards are collected in any order and only checked when the hand is
+/// built, so a card repeated across sources is reported by [`build`](Self::build).
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::{Board, Card, HandBuilder, HoleCards};
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Qh Js Tc").unwrap();
+/// let hand = HandBuilder::new()
+///     .hole(HoleCards::from_notation("AKs").unwrap())
+///     .board(&board)
+///     .card(Card::from_str("2d").unwrap())
+///     .build()
+///     .unwrap();
+/// assert_eq!(hand.len, 6);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HandBuilder {
+    cards: Vec<Card>,
+}
+
+impl HandBuilder {
+    /// Creates a builder with no cards
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds both hole cards
+    pub fn hole(mut self, hole_cards: crate::hole_cards::HoleCards) -> Self {
+        self.cards.extend_from_slice(&hole_cards.cards);
+        self
+    }
+
+    /// Adds every card dealt to the board so far
+    pub fn board(mut self, board: &crate::board::Board) -> Self {
+        self.cards.extend_from_slice(board.visible_cards());
+        self
+    }
+
+    /// Adds a single card
+    pub fn card(mut self, card: Card) -> Self {
+        self.cards.push(card);
+        self
+    }
+
+    /// Builds the hand from the collected cards
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::InvalidHandSize` if more than 7 cards were added
+    /// and `PokerError::DuplicateCard` if any card was added twice.
+    pub fn build(self) -> Result<Hand, PokerError> {
+        Hand::new(self.cards)
+    }
+}
+
 impl fmt::Display for Hand {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Hand({} cards: ", self.len)?;
@@ -475,6 +780,34 @@ impl fmt::Display for Hand {
     }
 }
 
+impl FromStr for Hand {
+    type Err = PokerError;
+
+    /// Parses either the `Display` form, e.g. "Hand(2 cards: As, Kd)", or
+    /// whitespace-separated notation accepted by `Hand::from_notation`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some(inner) = s
+            .strip_prefix("Hand(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        else {
+            return Self::from_notation(s);
+        };
+
+        let malformed = || PokerError::InvalidCardString {
+            string: s.to_string(),
+        };
+        let (count, cards) = inner.split_once(" cards:").ok_or_else(malformed)?;
+        let count: usize = count.parse().map_err(|_| malformed())?;
+
+        let hand = Self::from_notation(&cards.replace(',', " "))?;
+        if hand.len != count {
+            return Err(malformed());
+        }
+        Ok(hand)
+    }
+}
+
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -507,6 +840,16 @@ impl<'a> IntoIterator for &'a Hand {
     }
 }
 
+/// Generates zero to seven distinct cards
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Hand {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=7usize)?;
+        let cards = crate::card::arbitrary_distinct(u, len)?;
+        Ok(Hand::new(cards).expect("arbitrary cards are distinct"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1127,4 +1470,185 @@ mod tests {
         ];
         assert!(Hand::new(cards).is_err());
     }
+
+    #[test]
+    fn test_hand_sorted_is_order_independent() {
+        let forward = Hand::from_notation("As Kd Qh Js Tc").unwrap();
+        let backward = Hand::from_notation("Tc Js Qh Kd As").unwrap();
+        assert_eq!(forward.sorted(), backward.sorted());
+        assert_eq!(forward.canonical_order(), backward.canonical_order());
+
+        // Fill the public fields directly in a scrambled order
+        let mut scrambled = forward;
+        scrambled.cards.swap(0, 4);
+        scrambled.cards.swap(1, 2);
+        assert_ne!(scrambled, forward);
+        assert_eq!(scrambled.sorted(), forward);
+
+        let mut cache = HashSet::new();
+        cache.insert(forward.sorted());
+        assert!(cache.contains(&scrambled.sorted()));
+    }
+
+    #[test]
+    fn test_hand_remove_middle_card() {
+        let mut hand = Hand::from_notation("As Kd Qh Js Tc").unwrap();
+        hand.remove_card(Card::from_str("Qh").unwrap()).unwrap();
+
+        assert_eq!(hand.len, 4);
+        assert_eq!(hand, Hand::from_notation("As Kd Js Tc").unwrap());
+        assert_eq!(
+            hand.remove_card(Card::from_str("Qh").unwrap()),
+            Err(PokerError::CardNotInHand(Card::from_str("Qh").unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_hand_replace_card() {
+        let mut hand = Hand::from_notation("As Kd Qh Js Tc").unwrap();
+        hand.replace_card(Card::from_str("Qh").unwrap(), Card::from_str("Qc").unwrap())
+            .unwrap();
+
+        assert_eq!(hand.len, 5);
+        assert_eq!(hand.cards()[2], Card::from_str("Qc").unwrap());
+        assert_eq!(hand, Hand::from_notation("As Kd Qc Js Tc").unwrap());
+
+        // A lower card moves to keep the hand in rank order
+        hand.replace_card(Card::from_str("Kd").unwrap(), Card::from_str("2h").unwrap())
+            .unwrap();
+        assert_eq!(hand, Hand::from_notation("As Qc Js Tc 2h").unwrap());
+
+        assert_eq!(
+            hand.replace_card(Card::from_str("As").unwrap(), Card::from_str("Js").unwrap()),
+            Err(PokerError::DuplicateCard(Card::from_str("Js").unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_hand_set_operations() {
+        let hole = Hand::from_notation("As Kd").unwrap();
+        let flop = Hand::from_notation("Qh 7c 2s").unwrap();
+
+        let combined = hole.union(&flop).unwrap();
+        assert_eq!(combined.len, 5);
+        assert_eq!(combined, Hand::from_notation("As Kd Qh 7c 2s").unwrap());
+
+        assert_eq!(hole.intersection(&flop).len, 0);
+        assert_eq!(combined.intersection(&flop), flop);
+        assert_eq!(combined.difference(&flop), hole);
+        assert_eq!(hole.difference(&flop), hole);
+
+        // Shared cards are only counted once
+        let overlapping = Hand::from_notation("As Qh").unwrap();
+        assert_eq!(hole.union(&overlapping).unwrap().len, 3);
+
+        let turn_river = Hand::from_notation("3d 4d 5d").unwrap();
+        assert!(matches!(
+            combined.union(&turn_river),
+            Err(PokerError::InvalidHandSize { size: 8 })
+        ));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary_hands_are_valid() {
+        use crate::evaluator::Evaluator;
+        use arbitrary::{Arbitrary, Unstructured};
+        use rand::{RngCore, SeedableRng};
+
+        let evaluator = Evaluator::instance();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1375);
+        let mut bytes = [0u8; 64];
+        for _ in 0..10_000 {
+            rng.fill_bytes(&mut bytes);
+            let hand = Hand::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+            assert!(hand.len <= 7);
+            let unique: HashSet<_> = hand.iter().collect();
+            assert_eq!(unique.len(), hand.len, "duplicate card in {}", hand);
+            assert_eq!(Hand::new(hand.cards().to_vec()), Ok(hand));
+            evaluator.evaluate_hand(&hand);
+        }
+    }
+
+    #[test]
+    fn test_rank_signatures() {
+        let distinct = Hand::from_notation("As Kd 9c 2h").unwrap();
+        assert_eq!(distinct.rank_signature().count_ones(), 4);
+        assert_eq!(distinct.rank_count_signature().iter().sum::<u8>(), 4);
+
+        let paired = Hand::from_notation("9s 9d Kc 2h 2d").unwrap();
+        let counts = paired.rank_count_signature();
+        assert_eq!(counts[7], 2);
+        assert_eq!(counts[0], 2);
+        assert_eq!(counts[11], 1);
+        assert_eq!(paired.rank_signature(), 1 << 11 | 1 << 7 | 1);
+        assert_eq!(Hand::new(vec![]).unwrap().rank_signature(), 0);
+    }
+
+    #[test]
+    fn test_add_cards_is_all_or_nothing() {
+        let cards = |notation: &[&str]| -> Vec<Card> {
+            notation
+                .iter()
+                .map(|c| Card::from_str(c).unwrap())
+                .collect()
+        };
+
+        let mut hand = Hand::new(vec![]).unwrap();
+        hand.add_cards(&cards(&["As", "Kd", "Qh", "Js", "Tc"]))
+            .unwrap();
+        assert_eq!(hand.len, 5);
+        assert_eq!(hand.cards()[0], Card::from_str("As").unwrap());
+
+        let before = hand;
+        assert_eq!(
+            hand.add_cards(&cards(&["9h", "8h", "7h"])),
+            Err(PokerError::InvalidHandSize { size: 8 })
+        );
+        assert_eq!(hand, before);
+
+        assert_eq!(
+            hand.add_cards(&cards(&["9h", "Kd"])),
+            Err(PokerError::DuplicateCard(Card::from_str("Kd").unwrap()))
+        );
+        assert_eq!(hand, before);
+
+        hand.add_cards(&cards(&["9h", "2c"])).unwrap();
+        assert_eq!(hand.len, 7);
+    }
+
+    #[test]
+    fn test_hand_builder() {
+        use crate::{Board, HoleCards};
+
+        let hole = HoleCards::from_notation("AKs").unwrap();
+        let board = Board::from_str("Qh Js Tc 2d").unwrap();
+        let hand = HandBuilder::new()
+            .hole(hole)
+            .board(&board)
+            .card(Card::from_str("3c").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(hand.len, 7);
+        assert_eq!(hand, Hand::from_notation("As Ks Qh Js Tc 2d 3c").unwrap());
+
+        // The queen on the board is added again as a stray card
+        let duplicate = HandBuilder::new()
+            .board(&board)
+            .card(Card::from_str("Qh").unwrap())
+            .build();
+        assert_eq!(
+            duplicate,
+            Err(PokerError::DuplicateCard(Card::from_str("Qh").unwrap()))
+        );
+
+        let too_many = HandBuilder::new()
+            .hole(hole)
+            .board(&board)
+            .card(Card::from_str("3c").unwrap())
+            .card(Card::from_str("4c").unwrap())
+            .build();
+        assert_eq!(too_many, Err(PokerError::InvalidHandSize { size: 8 }));
+    }
 }
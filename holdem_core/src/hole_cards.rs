@@ -66,12 +66,54 @@
 //! - **Performance**: Implements Hash and efficient comparison
 //! - **Safety**: Comprehensive error handling and validation
 
+use crate::board::Board;
 use crate::card::Card;
 use crate::errors::PokerError;
+use crate::evaluator::evaluator::straight_high_card;
+use crate::preflop::{EQUITY_VS_RANDOM, SKLANSKY_CHUBUKOV};
+use crate::range::TOTAL_COMBOS;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 
+/// The kind of draw a holding has on the board, ordered weakest first
+///
+/// Comparing two values picks the stronger draw, so a bot can branch on
+/// `hole.best_draw(&board)` directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DrawType {
+    /// Three to a flush on the flop, needing both the turn and the river
+    BackdoorFlush,
+    /// Four to a straight with one rank completing it
+    Gutshot,
+    /// Four to a straight with two ranks completing it, double gutters included
+    OpenEnded,
+    /// Four to a flush
+    FlushDraw,
+    /// Four to a flush holding the highest card of the suit still unseen
+    NutFlushDraw,
+}
+
+/// A class of starting hands, for dealing probabilities
+///
+/// Ranks run from 0 (deuce) to 12 (ace), as in [`Card`]; the order of the
+/// two ranks in a specific hand does not matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HandClass {
+    /// Any pocket pair
+    AnyPair,
+    /// Any two cards of the same suit
+    AnySuited,
+    /// Any two cards of different ranks and suits
+    AnyOffsuit,
+    /// One specific pocket pair, such as "AA"
+    Pair(u8),
+    /// One specific suited hand, such as "AKs"
+    Suited(u8, u8),
+    /// One specific offsuit hand, such as "AKo"
+    Offsuit(u8, u8),
+}
+
 /// Represents a player's two private hole cards in poker
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HoleCards {
@@ -294,6 +336,165 @@ impl HoleCards {
         }
     }
 
+    /// All-in equity against one random hand, from a precomputed table
+    ///
+    /// The lookup uses the canonical hand (see `notation`), so every combo of
+    /// "AKs" shares one value. The table is
+    /// [`EQUITY_VS_RANDOM`](crate::preflop::EQUITY_VS_RANDOM).
+    ///
+    /// # Examples
+    /// ```
+    /// use holdem_core::hole_cards::HoleCards;
+    ///
+    /// let aces = HoleCards::from_notation("AA").unwrap();
+    /// assert!((aces.equity_vs_random() - 0.85).abs() < 0.01);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn equity_vs_random(&self) -> f64 {
+        let notation = self.notation();
+        EQUITY_VS_RANDOM
+            .iter()
+            .find(|(hand, _)| *hand == notation)
+            .map_or(0.0, |(_, equity)| *equity)
+    }
+
+    /// Probability of being dealt a starting hand in `class`
+    ///
+    /// The number of combos in the class over the 1326 possible starting
+    /// hands: 6 per pair, 4 per suited and 12 per offsuit hand. A specific
+    /// suited or offsuit hand with two equal ranks, or a rank above 12, has
+    /// no combos and probability zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use holdem_core::hole_cards::{HandClass, HoleCards};
+    ///
+    /// let any_pair = HoleCards::deal_probability(HandClass::AnyPair);
+    /// assert!((any_pair - 0.0588).abs() < 1e-4);
+    ///
+    /// let aces = HoleCards::deal_probability(HandClass::Pair(12));
+    /// assert!((aces - 0.0045).abs() < 1e-4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn deal_probability(class: HandClass) -> f64 {
+        let valid = |rank: u8| rank <= 12;
+        let combos = match class {
+            HandClass::AnyPair => 13 * 6,
+            HandClass::AnySuited => 78 * 4,
+            HandClass::AnyOffsuit => 78 * 12,
+            HandClass::Pair(rank) if valid(rank) => 6,
+            HandClass::Suited(a, b) if valid(a) && valid(b) && a != b => 4,
+            HandClass::Offsuit(a, b) if valid(a) && valid(b) && a != b => 12,
+            _ => 0,
+        };
+        combos as f64 / TOTAL_COMBOS as f64
+    }
+
+    /// Sklansky-Chubukov number, from a precomputed table
+    ///
+    /// The largest stack in big blinds at which shoving these cards from the
+    /// small blind beats folding against a caller who sees them; see
+    /// [`SKLANSKY_CHUBUKOV`](crate::preflop::SKLANSKY_CHUBUKOV). Pocket aces
+    /// return infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// use holdem_core::hole_cards::HoleCards;
+    ///
+    /// let kings = HoleCards::from_notation("KK").unwrap();
+    /// let trash = HoleCards::from_notation("72o").unwrap();
+    /// assert!(kings.sklansky_chubukov() > 400.0);
+    /// assert!(trash.sklansky_chubukov() < 2.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn sklansky_chubukov(&self) -> f64 {
+        let notation = self.notation();
+        SKLANSKY_CHUBUKOV
+            .iter()
+            .find(|(hand, _)| *hand == notation)
+            .map_or(0.0, |(_, number)| *number)
+    }
+
+    /// Returns the strongest draw these cards hold on the board
+    ///
+    /// Only draws that use at least one hole card count, and only toward a
+    /// hand not already made: a made flush has no flush draw. Draws exist on
+    /// the flop and the turn; preflop and on the river this returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use holdem_core::hole_cards::{DrawType, HoleCards};
+    /// use holdem_core::{Board, Card};
+    /// use std::str::FromStr;
+    ///
+    /// let hole = HoleCards::new(Card::from_str("Ah").unwrap(), Card::from_str("2h").unwrap()).unwrap();
+    /// let board = Board::from_str("Kh 7h 3c").unwrap();
+    /// assert_eq!(hole.best_draw(&board), Some(DrawType::NutFlushDraw));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn best_draw(&self, board: &Board) -> Option<DrawType> {
+        let board_cards = board.visible_cards();
+        if !(3..=4).contains(&board_cards.len()) {
+            return None;
+        }
+
+        let flush = (0..4u8).filter_map(|suit| {
+            let on_board = board_cards.iter().filter(|card| card.suit == suit).count();
+            let held = self.cards.iter().filter(|card| card.suit == suit).count();
+            match (on_board + held, held) {
+                (_, 0) => None,
+                (4, _) => {
+                    let nut_rank = (0..13u8)
+                        .rev()
+                        .find(|&rank| !board_cards.contains(&Card { rank, suit }))?;
+                    if self.cards.contains(&Card {
+                        rank: nut_rank,
+                        suit,
+                    }) {
+                        Some(DrawType::NutFlushDraw)
+                    } else {
+                        Some(DrawType::FlushDraw)
+                    }
+                }
+                (3, _) if board_cards.len() == 3 => Some(DrawType::BackdoorFlush),
+                _ => None,
+            }
+        });
+
+        let rank_mask =
+            |cards: &[Card]| cards.iter().fold(0u16, |mask, card| mask | 1 << card.rank);
+        let board_mask = rank_mask(board_cards);
+        let all_mask = board_mask | rank_mask(&self.cards);
+        let straight = if has_straight(all_mask) {
+            None
+        } else {
+            let outs = (0..13u8)
+                .filter(|rank| {
+                    has_straight(all_mask | 1 << rank) && !has_straight(board_mask | 1 << rank)
+                })
+                .count();
+            match outs {
+                0 => None,
+                1 => Some(DrawType::Gutshot),
+                _ => Some(DrawType::OpenEnded),
+            }
+        };
+
+        flush.chain(straight).max()
+    }
+
     /// Generate standard poker notation for the hole cards
     ///
     /// # Format
@@ -333,6 +534,9 @@ impl HoleCards {
 
     /// Get a display-friendly string representation
     ///
+    /// This names both cards, such as "As Ks", while `Display` prints the
+    /// canonical notation "AKs".
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -347,15 +551,18 @@ impl HoleCards {
     /// # Panics
     ///
     /// This method does not panic.
+    // Deliberately differs from `Display`; both outputs are relied on
+    #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "{} {}",
-            self.cards[0].to_string(),
-            self.cards[1].to_string()
-        )
+        format!("{} {}", self.cards[0], self.cards[1])
     }
 }
 
+/// True if the ranks in `rank_mask` contain five in a row, ace playing low too
+fn has_straight(rank_mask: u16) -> bool {
+    straight_high_card(rank_mask).is_some()
+}
+
 impl fmt::Display for HoleCards {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.notation())
@@ -384,8 +591,46 @@ impl Ord for HoleCards {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::hole;
     use std::str::FromStr;
 
+    #[test]
+    fn test_best_draw() {
+        // Four hearts plus a gutshot to the ten: the flush draw ranks higher
+        let board = Board::from_str("Qh 5h Jc").unwrap();
+        assert_eq!(
+            hole("9h", "8h").best_draw(&board),
+            Some(DrawType::FlushDraw)
+        );
+        assert!(DrawType::FlushDraw > DrawType::Gutshot);
+
+        let board = Board::from_str("Kh 7h 3c").unwrap();
+        assert_eq!(
+            hole("Ah", "2h").best_draw(&board),
+            Some(DrawType::NutFlushDraw)
+        );
+
+        let board = Board::from_str("7h 6s 2c 2d").unwrap();
+        assert_eq!(
+            hole("9c", "8d").best_draw(&board),
+            Some(DrawType::OpenEnded)
+        );
+        assert_eq!(hole("9c", "5d").best_draw(&board), Some(DrawType::Gutshot));
+
+        let board = Board::from_str("Qh 7h 2c").unwrap();
+        assert_eq!(
+            hole("Ah", "Kd").best_draw(&board),
+            Some(DrawType::BackdoorFlush)
+        );
+        assert_eq!(hole("Ac", "Kd").best_draw(&board), None);
+
+        // Made hands and complete boards have nothing left to draw to
+        let made = Board::from_str("Th 7h 2h").unwrap();
+        assert_eq!(hole("Ah", "Kh").best_draw(&made), None);
+        let river = Board::from_str("Qh 5h Jc 2d 3s").unwrap();
+        assert_eq!(hole("9h", "8h").best_draw(&river), None);
+    }
+
     #[test]
     fn test_hole_cards_creation() {
         let card1 = Card::from_str("As").unwrap();
@@ -743,4 +988,75 @@ mod tests {
         assert_eq!(second.rank(), 11); // King
         assert_eq!(first.suit(), second.suit()); // Both spades
     }
+
+    #[test]
+    fn test_equity_vs_random() {
+        let aces = HoleCards::from_notation("AA").unwrap();
+        assert!((aces.equity_vs_random() - 0.85).abs() < 0.01);
+
+        let seven_deuce = HoleCards::from_notation("72o").unwrap();
+        assert!((0.32..0.36).contains(&seven_deuce.equity_vs_random()));
+
+        // Every one of the 1326 combos maps to a table entry
+        let mut hands = std::collections::HashSet::new();
+        for first in 0..52u8 {
+            for second in first + 1..52 {
+                let hole = HoleCards::new(
+                    Card::new(first / 4, first % 4).unwrap(),
+                    Card::new(second / 4, second % 4).unwrap(),
+                )
+                .unwrap();
+                let equity = hole.equity_vs_random();
+                assert!(equity > 0.0 && equity <= 1.0, "{}: {}", hole, equity);
+                hands.insert(hole.notation());
+            }
+        }
+        assert_eq!(hands.len(), 169);
+    }
+
+    #[test]
+    fn test_sklansky_chubukov() {
+        let aces = HoleCards::from_notation("AA").unwrap();
+        assert_eq!(aces.sklansky_chubukov(), f64::INFINITY);
+
+        let numbers: Vec<f64> = crate::preflop::hand_combos("KK")
+            .unwrap()
+            .iter()
+            .map(HoleCards::sklansky_chubukov)
+            .collect();
+        assert!(numbers
+            .iter()
+            .all(|&n| n == numbers[0] && n < f64::INFINITY));
+
+        // Seven-deuce is near the bottom, but 3-2 offsuit is the weakest shove
+        let weakest = HoleCards::from_notation("32o").unwrap().sklansky_chubukov();
+        let seven_deuce = HoleCards::from_notation("72o").unwrap().sklansky_chubukov();
+        assert!(weakest <= seven_deuce && seven_deuce < 2.0);
+        assert!(SKLANSKY_CHUBUKOV.iter().all(|&(_, n)| n >= weakest));
+    }
+
+    #[test]
+    fn test_deal_probability() {
+        let close = |class, expected: f64| {
+            assert!((HoleCards::deal_probability(class) - expected).abs() < 5e-5);
+        };
+
+        close(HandClass::AnyPair, 0.0588);
+        close(HandClass::AnySuited, 0.2353);
+        close(HandClass::AnyOffsuit, 0.7059);
+        close(HandClass::Pair(12), 0.0045);
+        close(HandClass::Suited(12, 11), 0.0030);
+        close(HandClass::Offsuit(11, 12), 0.0090);
+
+        let all = [
+            HandClass::AnyPair,
+            HandClass::AnySuited,
+            HandClass::AnyOffsuit,
+        ];
+        let total: f64 = all.into_iter().map(HoleCards::deal_probability).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+
+        assert_eq!(HoleCards::deal_probability(HandClass::Suited(5, 5)), 0.0);
+        assert_eq!(HoleCards::deal_probability(HandClass::Pair(13)), 0.0);
+    }
 }
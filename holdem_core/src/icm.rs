@@ -0,0 +1,112 @@
+//! # ICM Module
+//!
+//! Tournament prize equity under the Independent Chip Model. Chip stacks are
+//! converted into expected prize money with the Malmuth-Harville model: a
+//! player finishes first with probability proportional to their stack, and
+//! each later place is assigned the same way among the players still left.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::icm::icm_equity;
+//!
+//! // Three-handed bubble with two paid places
+//! let equity = icm_equity(&[5000.0, 3000.0, 2000.0], &[65.0, 35.0]);
+//! assert!((equity.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+//! assert!(equity[0] < 50.0); // ICM compresses the chip leader's share
+//! ```
+
+/// Computes each player's expected prize money from their chip stack
+///
+/// `payouts[k]` is the prize for finishing in place `k + 1`. When there are
+/// more players than paid places the remaining players finish out of the
+/// money; when there are fewer, the unreachable payouts are ignored. Players
+/// with no chips always finish below every player with chips, splitting the
+/// places left for them evenly.
+///
+/// The result is in the same units as `payouts`, one entry per stack. The
+/// model enumerates finishing orders for the paid places, so cost grows as
+/// `players^places` and is intended for final-table sized inputs.
+pub fn icm_equity(stacks: &[f64], payouts: &[f64]) -> Vec<f64> {
+    let mut equity = vec![0.0; stacks.len()];
+    let mut remaining: Vec<usize> = (0..stacks.len()).collect();
+    assign_places(stacks, payouts, &mut remaining, 1.0, &mut equity);
+    equity
+}
+
+/// Distributes the next place among `remaining` given the order so far
+fn assign_places(
+    stacks: &[f64],
+    payouts: &[f64],
+    remaining: &mut Vec<usize>,
+    probability: f64,
+    equity: &mut [f64],
+) {
+    let place = stacks.len() - remaining.len();
+    if place >= payouts.len() || remaining.is_empty() {
+        return;
+    }
+
+    let total: f64 = remaining.iter().map(|&player| stacks[player]).sum();
+    if total <= 0.0 {
+        // Only busted players are left, so they share the remaining places
+        let last = payouts.len().min(stacks.len());
+        let share = payouts[place..last].iter().sum::<f64>() / remaining.len() as f64;
+        for &player in remaining.iter() {
+            equity[player] += probability * share;
+        }
+        return;
+    }
+
+    for i in 0..remaining.len() {
+        let player = remaining[i];
+        if stacks[player] <= 0.0 {
+            continue;
+        }
+        let finishes_here = probability * stacks[player] / total;
+        equity[player] += finishes_here * payouts[place];
+
+        remaining.remove(i);
+        assign_places(stacks, payouts, remaining, finishes_here, equity);
+        remaining.insert(i, player);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_three_handed_bubble() {
+        let equity = icm_equity(&[5000.0, 3000.0, 2000.0], &[65.0, 35.0]);
+        assert_close(&equity, &[44.375, 32.625, 23.0]);
+    }
+
+    #[test]
+    fn test_three_handed_all_paid() {
+        let equity = icm_equity(&[5000.0, 3000.0, 2000.0], &[50.0, 30.0, 20.0]);
+        assert_close(&equity, &[38.392857142857146, 32.75, 28.857142857142858]);
+    }
+
+    #[test]
+    fn test_equal_stacks_split_evenly() {
+        let equity = icm_equity(&[1500.0; 4], &[60.0, 40.0]);
+        assert_close(&equity, &[25.0; 4]);
+    }
+
+    #[test]
+    fn test_busted_player_finishes_last() {
+        let equity = icm_equity(&[7000.0, 3000.0, 0.0], &[50.0, 30.0, 20.0]);
+        assert_close(&equity, &[44.0, 36.0, 20.0]);
+
+        let heads_up = icm_equity(&[7000.0, 3000.0], &[50.0, 30.0, 20.0]);
+        assert_close(&heads_up, &[44.0, 36.0]);
+    }
+}
@@ -63,12 +63,53 @@ pub mod errors;
 /// Core hand evaluation functionality with lookup tables
 pub mod evaluator;
 
+/// Exact heads-up equity by runout enumeration
+pub mod equity;
+
+/// Two-card combination counting with blockers
+pub mod combos;
+
+/// Random labelled hand generation for training data
+pub mod training;
+
+/// Tournament prize equity under the Independent Chip Model
+pub mod icm;
+
+/// Plain-English descriptions of a holding on a board
+pub mod describe;
+
+/// Canonical starting hands and preflop strength
+pub mod preflop;
+
+/// Weighted ranges of hole-card combinations
+pub mod range;
+
+/// Five-card draw discard decisions
+pub mod draw;
+
+/// Betting math such as minimum defense frequency
+pub mod math;
+
+/// Four-card Omaha holdings
+pub mod omaha;
+
+/// Heads-up push/fold equilibrium charts
+pub mod pushfold;
+
+/// Table positions relative to the dealer button
+pub mod position;
+
+/// Shared helpers for unit tests
+#[cfg(test)]
+pub(crate) mod test_util;
+
 /// Re-export holdem_core types for convenience
-pub use board::Board;
+pub use board::{Board, BoardClass, CardImpact};
 pub use card::Card;
 pub use deck::Deck;
-pub use hand::Hand;
-pub use hole_cards::HoleCards;
+pub use hand::{Hand, HandBuilder};
+pub use hole_cards::{DrawType, HandClass, HoleCards};
+pub use position::Position;
 
 /// Re-export Street enum for convenience
 pub use board::Street;
@@ -0,0 +1,155 @@
+//! # Math Module
+//!
+//! Betting arithmetic used by game-theory aware bots. A bet of `bet` into a
+//! pot of `pot` must be called often enough that a pure bluff does not profit
+//! outright; that threshold is the minimum defense frequency, and its
+//! complement, alpha, is how often the bluff has to work to break even.
+//! A polarized betting range makes the caller indifferent when its share of
+//! value hands matches [`value_bet_threshold`]. Pot-limit games cap every
+//! bet at the size of the pot; see [`max_pot_limit_bet`].
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::math::{alpha, minimum_defense_frequency};
+//!
+//! // A pot-sized bet must be defended half the time
+//! assert_eq!(minimum_defense_frequency(100.0, 100.0), 0.5);
+//! assert_eq!(alpha(100.0, 100.0), 0.5);
+//! ```
+
+/// Fraction of the range that must continue against a bet
+///
+/// Computed as `pot / (pot + bet)`, where `pot` is the pot before the bet. A
+/// half-pot bet must be defended two thirds of the time. Returns `1.0` when
+/// the pot and the bet are both zero.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn minimum_defense_frequency(bet: f64, pot: f64) -> f64 {
+    1.0 - alpha(bet, pot)
+}
+
+/// Break-even fold frequency for a bluff of `bet` into `pot`
+///
+/// Computed as `bet / (pot + bet)`, the share of the final pot the bluffer
+/// risks; the complement of [`minimum_defense_frequency`]. Returns `0.0`
+/// when the pot and the bet are both zero.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn alpha(bet: f64, pot: f64) -> f64 {
+    let total = pot + bet;
+    if total > 0.0 {
+        bet / total
+    } else {
+        0.0
+    }
+}
+
+/// Fraction of the time villain must fold for a pure bluff to break even
+///
+/// The bluff risks `bet` to win `pot`, so it profits when villain folds more
+/// than `bet / (bet + pot)` of the time: half for a pot-sized bet and a third
+/// for a half-pot bet. This is the same quantity as [`alpha`], named for the
+/// bluffing decision. Returns `0.0` when the pot and the bet are both zero.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn bluff_breakeven_fold_frequency(bet: f64, pot: f64) -> f64 {
+    alpha(bet, pot)
+}
+
+/// Share of a polarized betting range that should be value hands
+///
+/// A caller risking `bet` to win `pot + bet` needs `bet / (pot + 2 * bet)`
+/// equity, so betting value hands in the complementary proportion
+/// `(pot + bet) / (pot + 2 * bet)` leaves villain indifferent to calling; the
+/// rest of the range can be bluffs. A pot-sized bet is two-thirds value.
+/// Returns `1.0` when the pot and the bet are both zero.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn value_bet_threshold(bet: f64, pot: f64) -> f64 {
+    let total = pot + 2.0 * bet;
+    if total > 0.0 {
+        (pot + bet) / total
+    } else {
+        1.0
+    }
+}
+
+/// Most a player may put in with one action under pot-limit rules
+///
+/// `pot` is everything in the middle, including bets already made on this
+/// street, and `to_call` is what the player must add to call. The player
+/// may call and then raise by the size of the pot after the call, so the
+/// limit is `pot + 2 * to_call`. Facing a bet of 20 into a pot of 100, the
+/// pot is 120 and the player may put in 160, a raise to 160. With no bet
+/// to call this is a pot-sized bet.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::math::max_pot_limit_bet;
+///
+/// // Blinds of 1 and 2: the first player in may raise to 7
+/// assert_eq!(max_pot_limit_bet(3.0, 2.0), 7.0);
+/// // The small blind, with 1 already in, may add 5 to raise to 6
+/// assert_eq!(max_pot_limit_bet(3.0, 1.0), 5.0);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn max_pot_limit_bet(pot: f64, to_call: f64) -> f64 {
+    pot + 2.0 * to_call
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimum_defense_frequency() {
+        assert!((minimum_defense_frequency(50.0, 100.0) - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(minimum_defense_frequency(100.0, 100.0), 0.5);
+        assert_eq!(minimum_defense_frequency(0.0, 100.0), 1.0);
+        assert_eq!(minimum_defense_frequency(0.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_alpha() {
+        assert!((alpha(50.0, 100.0) - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(alpha(100.0, 100.0), 0.5);
+        assert_eq!(alpha(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_bluff_breakeven_fold_frequency() {
+        assert_eq!(bluff_breakeven_fold_frequency(100.0, 100.0), 0.5);
+        assert!((bluff_breakeven_fold_frequency(50.0, 100.0) - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(bluff_breakeven_fold_frequency(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_value_bet_threshold() {
+        assert!((value_bet_threshold(100.0, 100.0) - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(value_bet_threshold(50.0, 100.0), 0.75);
+        assert_eq!(value_bet_threshold(0.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_max_pot_limit_bet() {
+        // A bet of 20 into 100: call 20, then raise the 140 in the pot
+        assert_eq!(max_pot_limit_bet(120.0, 20.0), 160.0);
+        assert_eq!(max_pot_limit_bet(100.0, 0.0), 100.0);
+
+        // Facing a pot-sized bet of 100 into 100, the pot raise is to 400
+        assert_eq!(max_pot_limit_bet(200.0, 100.0), 400.0);
+    }
+}
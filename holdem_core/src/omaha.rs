@@ -0,0 +1,190 @@
+//! # Omaha Module
+//!
+//! Four-card Omaha holdings. An Omaha hand must use exactly two of its hole
+//! cards with three board cards, so evaluation works over the six two-card
+//! subsets of the holding.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::omaha::OmahaHoleCards;
+//! use holdem_core::Card;
+//! use std::str::FromStr;
+//!
+//! let hole = OmahaHoleCards::new(["As", "Ks", "Qd", "Jd"].map(|c| Card::from_str(c).unwrap())).unwrap();
+//! assert_eq!(hole.all_two_card_subsets().len(), 6);
+//! ```
+//!
+//! In hi-lo games the pot is split between the best high hand and the best
+//! ace-to-five low of eight or better, each chosen independently with two
+//! hole cards and three board cards; see [`evaluate_omaha_hi_lo`].
+
+use crate::card::Card;
+use crate::errors::PokerError;
+use crate::evaluator::lowball::{evaluate_low, qualifies_for_eight_low, LowValue, LowballRules};
+use crate::evaluator::{Evaluator, HandValue};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A player's four private cards in Omaha
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OmahaHoleCards {
+    /// The four cards, in the order they were dealt
+    pub cards: [Card; 4],
+}
+
+impl OmahaHoleCards {
+    /// Creates an Omaha holding from four distinct cards
+    ///
+    /// # Errors
+    ///
+    /// Returns `PokerError::DuplicateCard` if any card appears twice.
+    pub fn new(cards: [Card; 4]) -> Result<Self, PokerError> {
+        for (i, card) in cards.iter().enumerate() {
+            if cards[i + 1..].contains(card) {
+                return Err(PokerError::DuplicateCard(*card));
+            }
+        }
+        Ok(Self { cards })
+    }
+
+    /// Returns the six ways to choose the two hole cards a hand must use
+    ///
+    /// # Panics
+    ///
+    /// This method does not panic.
+    pub fn all_two_card_subsets(&self) -> [[Card; 2]; 6] {
+        let [a, b, c, d] = self.cards;
+        [[a, b], [a, c], [a, d], [b, c], [b, d], [c, d]]
+    }
+}
+
+/// Evaluates an Omaha hi-lo holding, returning the best high and low hands
+///
+/// Both halves use exactly two hole cards and three board cards, chosen
+/// separately. The low is ranked ace-to-five, ignoring straights and flushes,
+/// and only qualifies with five unpaired cards of eight or lower; otherwise it
+/// is `None` and the high hand scoops.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::omaha::{evaluate_omaha_hi_lo, OmahaHoleCards};
+/// use holdem_core::{Card, HandRank};
+/// use std::str::FromStr;
+///
+/// let card = |s| Card::from_str(s).unwrap();
+/// let hole = OmahaHoleCards::new(["As", "2s", "Kd", "Qc"].map(card)).unwrap();
+/// let board = ["3s", "4s", "9s", "Jh", "7d"].map(card);
+///
+/// let (high, low) = evaluate_omaha_hi_lo(&hole, &board);
+/// assert_eq!(high.rank, HandRank::Flush);
+/// assert!(low.is_some());
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn evaluate_omaha_hi_lo(
+    hole: &OmahaHoleCards,
+    board: &[Card; 5],
+) -> (HandValue, Option<LowValue>) {
+    let evaluator = Evaluator::instance();
+    let mut high: Option<HandValue> = None;
+    let mut low: Option<LowValue> = None;
+
+    for [first, second] in hole.all_two_card_subsets() {
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let hand = [first, second, board[a], board[b], board[c]];
+
+                    let value = evaluator.evaluate_5_card(&hand);
+                    if high.is_none_or(|high| value > high) {
+                        high = Some(value);
+                    }
+
+                    if qualifies_for_eight_low(&hand) {
+                        let value = evaluate_low(&hand, LowballRules::ACE_TO_FIVE)
+                            .expect("five cards always evaluate");
+                        if low.is_none_or(|low| value < low) {
+                            low = Some(value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (high.expect("an Omaha holding has a high hand"), low)
+}
+
+impl fmt::Display for OmahaHoleCards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.cards;
+        write!(f, "{} {} {} {}", a, b, c, d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    fn cards(notation: [&str; 4]) -> [Card; 4] {
+        notation.map(|c| Card::from_str(c).unwrap())
+    }
+
+    #[test]
+    fn test_two_card_subsets() {
+        let hole = OmahaHoleCards::new(cards(["As", "Ks", "Qd", "Jd"])).unwrap();
+        let subsets = hole.all_two_card_subsets();
+
+        let distinct: HashSet<[Card; 2]> = subsets.iter().copied().collect();
+        assert_eq!(distinct.len(), 6);
+        assert!(subsets
+            .iter()
+            .all(|[a, b]| a != b && hole.cards.contains(a) && hole.cards.contains(b)));
+        assert_eq!(hole.to_string(), "As Ks Qd Jd");
+    }
+
+    #[test]
+    fn test_hi_lo_scoop() {
+        use crate::evaluator::HandRank;
+
+        // Nut flush for high and A-2-3-4-7 for low, both using the ace and deuce
+        let hole = OmahaHoleCards::new(cards(["As", "2s", "5d", "Kc"])).unwrap();
+        let board = ["3s", "4s", "9s", "Jh", "7d"].map(|c| Card::from_str(c).unwrap());
+        let (high, low) = evaluate_omaha_hi_lo(&hole, &board);
+
+        assert_eq!(high.rank, HandRank::Flush);
+        let seven_low = ["As", "2s", "3s", "4s", "7d"].map(|c| Card::from_str(c).unwrap());
+        assert_eq!(
+            low,
+            Some(evaluate_low(&seven_low, LowballRules::ACE_TO_FIVE).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_hi_lo_without_qualifying_low() {
+        // Only two board cards are eight or lower, so no low is possible
+        let hole = OmahaHoleCards::new(cards(["As", "2s", "3d", "4c"])).unwrap();
+        let board = ["Ks", "Qd", "9s", "7h", "6d"].map(|c| Card::from_str(c).unwrap());
+        let (_, low) = evaluate_omaha_hi_lo(&hole, &board);
+        assert_eq!(low, None);
+
+        // Paired low cards do not qualify either
+        let hole = OmahaHoleCards::new(cards(["7s", "7c", "Kd", "Kc"])).unwrap();
+        let board = ["2s", "3d", "4s", "Jh", "Qd"].map(|c| Card::from_str(c).unwrap());
+        assert_eq!(evaluate_omaha_hi_lo(&hole, &board).1, None);
+    }
+
+    #[test]
+    fn test_duplicate_cards_rejected() {
+        assert_eq!(
+            OmahaHoleCards::new(cards(["As", "Ks", "As", "Jd"])),
+            Err(PokerError::DuplicateCard(Card::from_str("As").unwrap()))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! # Position Module
+//!
+//! Table positions relative to the dealer button. A seat's position follows
+//! from how many seats after the button it sits: the two seats after the
+//! button post the blinds, and the seats before the button are named from
+//! the cutoff backwards.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::Position;
+//!
+//! // Six-handed with the button in seat 2
+//! assert_eq!(Position::from_seat(3, 2, 6), Some(Position::SmallBlind));
+//! assert_eq!(Position::from_seat(1, 2, 6), Some(Position::Cutoff));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Most players a table can seat
+const MAX_PLAYERS: usize = 10;
+
+/// A seat's position relative to the button, in preflop acting order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Position {
+    /// First to act preflop, directly after the big blind
+    UnderTheGun,
+    /// Seats between under the gun and the hijack
+    Middle,
+    /// Two seats before the button
+    Hijack,
+    /// The seat before the button
+    Cutoff,
+    /// The dealer, last to act after the flop; heads-up also posts the small blind
+    Button,
+    /// The seat after the button
+    SmallBlind,
+    /// Two seats after the button, or the other player heads-up
+    BigBlind,
+}
+
+impl Position {
+    /// Position of `seat` at a table of `num_players` with the button in `button`
+    ///
+    /// Seats are numbered `0..num_players` clockwise. Heads-up the button
+    /// posts the small blind and is reported as [`Button`](Self::Button).
+    /// The seats between the big blind and the button are filled from the
+    /// cutoff backwards: the cutoff, then under the gun, then the hijack,
+    /// with any seats left over in the middle. Returns `None` for fewer than
+    /// two or more than ten players, or a seat or button off the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holdem_core::Position;
+    ///
+    /// assert_eq!(Position::from_seat(0, 0, 2), Some(Position::Button));
+    /// assert_eq!(Position::from_seat(1, 0, 2), Some(Position::BigBlind));
+    /// assert_eq!(Position::from_seat(2, 0, 2), None);
+    /// ```
+    pub fn from_seat(seat: usize, button: usize, num_players: usize) -> Option<Self> {
+        if !(2..=MAX_PLAYERS).contains(&num_players) || seat >= num_players || button >= num_players
+        {
+            return None;
+        }
+
+        let offset = (seat + num_players - button) % num_players;
+        if num_players == 2 {
+            return Some(if offset == 0 {
+                Position::Button
+            } else {
+                Position::BigBlind
+            });
+        }
+
+        // Seats after the big blind, and this seat's place among them
+        let early = num_players - 3;
+        let position = match offset {
+            0 => Position::Button,
+            1 => Position::SmallBlind,
+            2 => Position::BigBlind,
+            _ => {
+                let index = offset - 3;
+                if index == early - 1 {
+                    Position::Cutoff
+                } else if index == 0 {
+                    Position::UnderTheGun
+                } else if index == early - 2 {
+                    Position::Hijack
+                } else {
+                    Position::Middle
+                }
+            }
+        };
+        Some(position)
+    }
+
+    /// True for the small and big blinds
+    pub fn is_blind(&self) -> bool {
+        matches!(self, Position::SmallBlind | Position::BigBlind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_six_max_positions() {
+        let button = 4;
+        let positions: Vec<Position> = (0..6)
+            .map(|seat| Position::from_seat(seat, button, 6).unwrap())
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                Position::BigBlind,
+                Position::UnderTheGun,
+                Position::Hijack,
+                Position::Cutoff,
+                Position::Button,
+                Position::SmallBlind,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heads_up_button_and_big_blind() {
+        assert_eq!(Position::from_seat(1, 1, 2), Some(Position::Button));
+        assert_eq!(Position::from_seat(0, 1, 2), Some(Position::BigBlind));
+        assert!(!Position::Button.is_blind());
+        assert!(Position::BigBlind.is_blind());
+    }
+
+    #[test]
+    fn test_full_ring_and_invalid_tables() {
+        let positions: Vec<Position> = (0..9)
+            .map(|seat| Position::from_seat(seat, 0, 9).unwrap())
+            .collect();
+        assert_eq!(positions[3], Position::UnderTheGun);
+        assert_eq!(&positions[4..7], &[Position::Middle; 3]);
+        assert_eq!(positions[7], Position::Hijack);
+        assert_eq!(positions[8], Position::Cutoff);
+
+        assert_eq!(Position::from_seat(0, 0, 1), None);
+        assert_eq!(Position::from_seat(0, 0, 11), None);
+        assert_eq!(Position::from_seat(0, 6, 6), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! # Preflop Module
+//!
+//! The 169 canonical starting hands and their strength before the flop.
+//! Specific combos map to a canonical hand through `HoleCards::notation`,
+//! e.g. both A♠K♠ and A♥K♥ are "AKs".
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::preflop::{hand_combos, EQUITY_VS_RANDOM, SKLANSKY_CHUBUKOV};
+//!
+//! assert_eq!(EQUITY_VS_RANDOM[0].0, "AA");
+//! assert_eq!(SKLANSKY_CHUBUKOV[1].0, "KK");
+//! assert_eq!(hand_combos("AKo").unwrap().len(), 12);
+//! ```
+
+use crate::card::Card;
+use crate::errors::PokerError;
+use crate::hole_cards::HoleCards;
+
+/// Number of canonical starting hands
+pub const CANONICAL_HAND_COUNT: usize = 169;
+
+/// Canonical starting hands with their all-in equity against one random
+/// hand, strongest first
+///
+/// The values are exact, rounded to four decimals: each is the mean equity
+/// against all 1,225 opposing combos, every matchup enumerated over all
+/// 1,712,304 boards with this crate's evaluator. They were generated by
+/// `examples/equity_vs_random.rs`; rerun it with
+/// `cargo run --release --example equity_vs_random` to regenerate the table.
+#[rustfmt::skip]
+pub const EQUITY_VS_RANDOM: [(&str, f64); CANONICAL_HAND_COUNT] = [
+    ("AA", 0.8520), ("KK", 0.8240), ("QQ", 0.7993), ("JJ", 0.7747), ("TT", 0.7501),
+    ("99", 0.7206), ("88", 0.6916), ("AKs", 0.6704), ("77", 0.6624), ("AQs", 0.6621),
+    ("AJs", 0.6539), ("AKo", 0.6532), ("ATs", 0.6460), ("AQo", 0.6443), ("AJo", 0.6356),
+    ("KQs", 0.6340), ("66", 0.6328), ("A9s", 0.6278), ("ATo", 0.6272), ("KJs", 0.6257),
+    ("A8s", 0.6194), ("KTs", 0.6179), ("KQo", 0.6146), ("A7s", 0.6098), ("A9o", 0.6077),
+    ("KJo", 0.6057), ("55", 0.6032), ("QJs", 0.6026), ("K9s", 0.5999), ("A5s", 0.5992),
+    ("A6s", 0.5991), ("A8o", 0.5987), ("KTo", 0.5974), ("QTs", 0.5947), ("A4s", 0.5903),
+    ("A7o", 0.5884), ("K8s", 0.5831), ("A3s", 0.5822), ("QJo", 0.5813), ("K9o", 0.5781),
+    ("A5o", 0.5770), ("A6o", 0.5768), ("Q9s", 0.5766), ("K7s", 0.5754), ("JTs", 0.5753),
+    ("A2s", 0.5738), ("QTo", 0.5729), ("44", 0.5702), ("A4o", 0.5673), ("K6s", 0.5664),
+    ("K8o", 0.5602), ("Q8s", 0.5602), ("A3o", 0.5584), ("K5s", 0.5579), ("J9s", 0.5566),
+    ("Q9o", 0.5536), ("JTo", 0.5525), ("K7o", 0.5519), ("A2o", 0.5493), ("K4s", 0.5488),
+    ("Q7s", 0.5430), ("K6o", 0.5422), ("K3s", 0.5405), ("T9s", 0.5403), ("J8s", 0.5402),
+    ("33", 0.5369), ("Q6s", 0.5361), ("Q8o", 0.5360), ("K5o", 0.5331), ("J9o", 0.5325),
+    ("K2s", 0.5321), ("Q5s", 0.5277), ("T8s", 0.5233), ("K4o", 0.5233), ("J7s", 0.5232),
+    ("Q4s", 0.5186), ("Q7o", 0.5177), ("T9o", 0.5153), ("J8o", 0.5149), ("K3o", 0.5143),
+    ("Q6o", 0.5102), ("Q3s", 0.5102), ("98s", 0.5080), ("T7s", 0.5064), ("J6s", 0.5061),
+    ("K2o", 0.5051), ("22", 0.5033), ("Q2s", 0.5017), ("Q5o", 0.5012), ("J5s", 0.4999),
+    ("T8o", 0.4972), ("J7o", 0.4968), ("Q4o", 0.4913), ("97s", 0.4912), ("J4s", 0.4907),
+    ("T6s", 0.4894), ("J3s", 0.4823), ("Q3o", 0.4822), ("98o", 0.4810), ("87s", 0.4794),
+    ("T7o", 0.4791), ("J6o", 0.4784), ("96s", 0.4743), ("J2s", 0.4738), ("Q2o", 0.4730),
+    ("T5s", 0.4722), ("J5o", 0.4718), ("T4s", 0.4653), ("97o", 0.4630), ("86s", 0.4624),
+    ("J4o", 0.4619), ("T6o", 0.4609), ("95s", 0.4572), ("T3s", 0.4569), ("76s", 0.4537),
+    ("J3o", 0.4528), ("87o", 0.4505), ("T2s", 0.4484), ("85s", 0.4454), ("96o", 0.4449),
+    ("J2o", 0.4435), ("T5o", 0.4425), ("94s", 0.4386), ("75s", 0.4368), ("T4o", 0.4350),
+    ("93s", 0.4326), ("86o", 0.4324), ("65s", 0.4313), ("84s", 0.4270), ("95o", 0.4267),
+    ("T3o", 0.4259), ("92s", 0.4242), ("76o", 0.4232), ("74s", 0.4185), ("T2o", 0.4167),
+    ("54s", 0.4145), ("85o", 0.4143), ("64s", 0.4133), ("83s", 0.4087), ("94o", 0.4067),
+    ("75o", 0.4051), ("82s", 0.4027), ("73s", 0.4004), ("93o", 0.4002), ("65o", 0.3994),
+    ("53s", 0.3969), ("63s", 0.3953), ("84o", 0.3945), ("92o", 0.3910), ("43s", 0.3864),
+    ("74o", 0.3855), ("72s", 0.3816), ("54o", 0.3816), ("64o", 0.3801), ("52s", 0.3785),
+    ("62s", 0.3767), ("83o", 0.3748), ("42s", 0.3683), ("82o", 0.3683), ("73o", 0.3660),
+    ("53o", 0.3626), ("63o", 0.3608), ("32s", 0.3598), ("43o", 0.3515), ("72o", 0.3458),
+    ("52o", 0.3428), ("62o", 0.3408), ("42o", 0.3320), ("32o", 0.3230),
+];
+
+/// Canonical starting hands with their Sklansky-Chubukov number, highest first
+///
+/// The number is the largest effective stack, in big blinds, at which moving
+/// all in from the small blind (blinds of 0.5 and 1) beats folding even if
+/// the big blind sees the hand and calls exactly when calling is profitable.
+/// Pocket aces can never be exploited and have no limit.
+///
+/// The values are exact, rounded to one decimal. They were generated by
+/// `examples/sklansky_chubukov.rs`, which enumerates all 1,712,304 boards
+/// for every matchup against each of the 1,225 opposing combos and solves
+/// for the break-even stack; rerun it with
+/// `cargo run --release --example sklansky_chubukov` to regenerate the table.
+#[rustfmt::skip]
+pub const SKLANSKY_CHUBUKOV: [(&str, f64); CANONICAL_HAND_COUNT] = [
+    ("AA", f64::INFINITY), ("KK", 477.5), ("AKs", 277.8), ("QQ", 239.5), ("AKo", 166.4),
+    ("JJ", 160.1), ("AQs", 137.6), ("TT", 120.4), ("AQo", 96.8), ("99", 96.2),
+    ("AJs", 92.1), ("88", 80.1), ("ATs", 70.0), ("AJo", 68.7), ("77", 67.9),
+    ("66", 58.2), ("ATo", 53.6), ("A9s", 52.6), ("55", 49.8), ("A8s", 45.4),
+    ("KQs", 43.8), ("44", 41.5), ("A9o", 41.4), ("A7s", 40.1), ("KJs", 36.8),
+    ("A5s", 36.6), ("A8o", 36.0), ("A6s", 35.9), ("A4s", 33.8), ("33", 33.2),
+    ("KTs", 31.9), ("A7o", 31.9), ("A3s", 31.6), ("KQo", 29.9), ("A2s", 29.6),
+    ("A5o", 28.8), ("A6o", 28.6), ("A4o", 26.5), ("KJo", 25.9), ("QJs", 25.3),
+    ("A3o", 24.7), ("22", 24.5), ("K9s", 24.4), ("A2o", 23.1), ("KTo", 23.0),
+    ("QTs", 22.4), ("K8s", 20.5), ("K7s", 19.2), ("JTs", 18.6), ("K9o", 18.4),
+    ("K6s", 17.9), ("QJo", 16.9), ("Q9s", 16.8), ("K5s", 16.7), ("K8o", 15.7),
+    ("K4s", 15.6), ("QTo", 15.4), ("K7o", 14.8), ("K3s", 14.7), ("K2s", 13.9),
+    ("Q8s", 13.9), ("K6o", 13.8), ("J9s", 13.4), ("K5o", 12.8), ("Q9o", 12.2),
+    ("JTo", 12.0), ("K4o", 11.9), ("Q7s", 11.8), ("T9s", 11.7), ("Q6s", 11.4),
+    ("K3o", 11.2), ("J8s", 10.8), ("Q5s", 10.7), ("K2o", 10.5), ("Q8o", 10.4),
+    ("Q4s", 10.0), ("J9o", 9.4), ("Q3s", 9.4), ("T8s", 9.2), ("J7s", 9.1),
+    ("Q7o", 9.0), ("Q2s", 8.8), ("Q6o", 8.6), ("98s", 8.1), ("Q5o", 8.0),
+    ("J8o", 7.9), ("T9o", 7.9), ("J6s", 7.9), ("T7s", 7.6), ("J5s", 7.5),
+    ("Q4o", 7.3), ("J4s", 7.0), ("J7o", 6.8), ("Q3o", 6.8), ("97s", 6.6),
+    ("T8o", 6.6), ("J3s", 6.5), ("T6s", 6.5), ("Q2o", 6.2), ("J2s", 6.1),
+    ("87s", 6.1), ("J6o", 5.9), ("98o", 5.6), ("T7o", 5.6), ("96s", 5.5),
+    ("J5o", 5.5), ("T5s", 5.5), ("T4s", 5.1), ("86s", 5.0), ("J4o", 5.0),
+    ("T6o", 4.8), ("97o", 4.8), ("T3s", 4.7), ("76s", 4.7), ("95s", 4.6),
+    ("J3o", 4.5), ("T2s", 4.3), ("87o", 4.3), ("85s", 4.1), ("96o", 4.0),
+    ("T5o", 4.0), ("J2o", 3.9), ("75s", 3.8), ("94s", 3.8), ("T4o", 3.6),
+    ("65s", 3.6), ("86o", 3.5), ("93s", 3.5), ("84s", 3.3), ("95o", 3.3),
+    ("T3o", 3.2), ("76o", 3.2), ("92s", 3.2), ("74s", 3.1), ("54s", 2.9),
+    ("T2o", 2.9), ("85o", 2.9), ("64s", 2.9), ("83s", 2.7), ("94o", 2.7),
+    ("75o", 2.6), ("82s", 2.6), ("73s", 2.5), ("93o", 2.5), ("65o", 2.5),
+    ("53s", 2.4), ("63s", 2.4), ("84o", 2.4), ("92o", 2.3), ("43s", 2.2),
+    ("74o", 2.2), ("72s", 2.1), ("54o", 2.1), ("64o", 2.1), ("52s", 2.1),
+    ("62s", 2.0), ("83o", 2.0), ("42s", 1.9), ("82o", 1.9), ("73o", 1.9),
+    ("53o", 1.8), ("63o", 1.8), ("32s", 1.8), ("43o", 1.7), ("72o", 1.6),
+    ("52o", 1.6), ("62o", 1.6), ("42o", 1.5), ("32o", 1.4),
+];
+
+/// Every specific combo of a canonical hand such as "AKs", "QQ" or "T9o"
+///
+/// Pairs have 6 combos, suited hands 4 and offsuit hands 12.
+///
+/// # Errors
+///
+/// Returns an error if the notation is not a valid canonical hand.
+pub fn hand_combos(notation: &str) -> Result<Vec<HoleCards>, PokerError> {
+    let canonical = HoleCards::from_notation(notation)?;
+    let [high, low] = canonical.cards;
+    let suited = canonical.is_suited();
+
+    let mut combos = Vec::new();
+    for high_suit in 0..4 {
+        for low_suit in 0..4 {
+            let keep = if canonical.is_pair() {
+                high_suit < low_suit
+            } else {
+                (high_suit == low_suit) == suited
+            };
+            if keep {
+                combos.push(HoleCards::new(
+                    Card::new(high.rank, high_suit)?,
+                    Card::new(low.rank, low_suit)?,
+                )?);
+            }
+        }
+    }
+    Ok(combos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_equity_table_covers_all_hands() {
+        let hands: HashSet<&str> = EQUITY_VS_RANDOM.iter().map(|(hand, _)| *hand).collect();
+        assert_eq!(hands.len(), CANONICAL_HAND_COUNT);
+
+        let combos: usize = hands
+            .iter()
+            .map(|hand| hand_combos(hand).unwrap().len())
+            .sum();
+        assert_eq!(combos, 1326);
+
+        for pair in EQUITY_VS_RANDOM.windows(2) {
+            assert!(pair[0].1 >= pair[1].1);
+        }
+    }
+
+    #[test]
+    fn test_sklansky_chubukov_table_covers_all_hands() {
+        let hands: HashSet<&str> = SKLANSKY_CHUBUKOV.iter().map(|(hand, _)| *hand).collect();
+        assert_eq!(hands.len(), CANONICAL_HAND_COUNT);
+
+        for pair in SKLANSKY_CHUBUKOV.windows(2) {
+            assert!(pair[0].1 >= pair[1].1);
+        }
+        assert_eq!(SKLANSKY_CHUBUKOV[0], ("AA", f64::INFINITY));
+    }
+
+    #[test]
+    fn test_hand_combos() {
+        for (notation, count) in [("QQ", 6), ("AKs", 4), ("T9o", 12)] {
+            let combos = hand_combos(notation).unwrap();
+            assert_eq!(combos.len(), count);
+            assert!(combos.iter().all(|hole| hole.notation() == notation));
+        }
+        assert!(hand_combos("AKss").is_err());
+    }
+}
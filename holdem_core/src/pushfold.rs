@@ -0,0 +1,204 @@
+//! # Push/Fold Module
+//!
+//! Approximate Nash equilibrium for heads-up all-in-or-fold play, the
+//! standard model for short-stacked tournament endgames. The small blind
+//! (0.5 big blinds) either moves all in or folds; facing the shove, the big
+//! blind (1 big blind) calls or folds.
+//!
+//! Equities between the 169 canonical hands come from an exact table
+//! generated by `examples/preflop_matchups.rs`. Both players' strategies
+//! are found by fictitious play: each round every hand best-responds to the
+//! opponent's average strategy, and the averages converge toward the
+//! equilibrium.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::pushfold::nash_pushfold_chart;
+//!
+//! let (push, call) = nash_pushfold_chart(10.0);
+//! assert!(push.contains_hand("A2o"));
+//! assert!(push.len() > call.len());
+//! ```
+
+use crate::equity::card_mask;
+use crate::preflop::{hand_combos, CANONICAL_HAND_COUNT};
+use crate::range::WeightedRange;
+use std::sync::OnceLock;
+
+mod matchups;
+
+/// Hands the small blind moves all in with
+pub type PushRange = WeightedRange;
+
+/// Hands the big blind calls an all-in with
+pub type CallRange = WeightedRange;
+
+/// Rounds of fictitious play
+const ITERATIONS: usize = 200;
+
+/// Approximate Nash push and call ranges for an effective stack in big blinds
+///
+/// A canonical hand is in a range, with every combo at full weight, when
+/// the averaged strategy plays it at least half the time. Card removal
+/// between the two hands is accounted for. With stacks of a big blind or
+/// less every hand is pushed and called; as stacks grow both ranges tighten,
+/// the calling range faster than the pushing range.
+///
+/// The equity matrix is expanded from the exact table on first use and
+/// shared by later calls.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn nash_pushfold_chart(effective_stack_bb: f64) -> (PushRange, CallRange) {
+    let table = equity_table();
+    let stack = effective_stack_bb.max(1.0);
+    let n = CANONICAL_HAND_COUNT;
+
+    let mut push = vec![1.0; n];
+    let mut call = vec![1.0; n];
+    for round in 1..=ITERATIONS {
+        let step = 1.0 / (round + 1) as f64;
+
+        // Big blind: calling risks the rest of the stack to win the pot
+        for (hand, frequency) in call.iter_mut().enumerate() {
+            let (mut weight, mut net) = (0.0, 0.0);
+            for (villain, push_frequency) in push.iter().enumerate() {
+                let combos = table.combos[hand * n + villain] * push_frequency;
+                weight += combos;
+                net += combos * (2.0 * table.equity[hand * n + villain] - 1.0) * stack;
+            }
+            // Folding gives up the big blind already posted
+            let best = if weight > 0.0 && net / weight > -1.0 {
+                1.0
+            } else {
+                0.0
+            };
+            *frequency += (best - *frequency) * step;
+        }
+
+        // Small blind: pushing wins the big blind uncalled, folding loses 0.5
+        for (hand, frequency) in push.iter_mut().enumerate() {
+            let (mut weight, mut net) = (0.0, 0.0);
+            for (villain, call_frequency) in call.iter().enumerate() {
+                let combos = table.combos[hand * n + villain];
+                let showdown = (2.0 * table.equity[hand * n + villain] - 1.0) * stack;
+                weight += combos;
+                net += combos * (call_frequency * showdown + (1.0 - call_frequency));
+            }
+            let best = if net / weight > -0.5 { 1.0 } else { 0.0 };
+            *frequency += (best - *frequency) * step;
+        }
+    }
+
+    (chart(&push), chart(&call))
+}
+
+/// Hands played at least half the time, every combo at full weight
+fn chart(frequencies: &[f64]) -> WeightedRange {
+    let mut range = WeightedRange::new();
+    for (notation, frequency) in matchups::HANDS.iter().zip(frequencies) {
+        if *frequency >= 0.5 {
+            range
+                .add_hand(notation, 1.0)
+                .expect("canonical hands are valid");
+        }
+    }
+    range
+}
+
+/// Equity and combo counts between every pair of canonical hands
+struct EquityTable {
+    /// `equity[i * 169 + j]`: all-in equity of hand `i` against hand `j`
+    equity: Vec<f64>,
+    /// `combos[i * 169 + j]`: combos of `j` left by a combo of `i`, averaged
+    combos: Vec<f64>,
+}
+
+/// The shared equity table, in the order of `matchups::HANDS`
+fn equity_table() -> &'static EquityTable {
+    static TABLE: OnceLock<EquityTable> = OnceLock::new();
+    TABLE.get_or_init(build_equity_table)
+}
+
+fn build_equity_table() -> EquityTable {
+    let n = CANONICAL_HAND_COUNT;
+    let classes: Vec<Vec<u64>> = matchups::HANDS
+        .iter()
+        .map(|notation| {
+            hand_combos(notation)
+                .expect("canonical hands are valid")
+                .iter()
+                .map(|hole| card_mask(&hole.cards))
+                .collect()
+        })
+        .collect();
+
+    let mut combos = vec![0.0; n * n];
+    for (i, hands) in classes.iter().enumerate() {
+        for (j, villains) in classes.iter().enumerate() {
+            let disjoint = hands
+                .iter()
+                .flat_map(|hand| villains.iter().filter(move |villain| hand & *villain == 0))
+                .count();
+            combos[i * n + j] = disjoint as f64 / hands.len() as f64;
+        }
+    }
+
+    let mut equity = vec![0.5; n * n];
+    let mut upper = matchups::EQUITY.iter();
+    for i in 0..n {
+        for j in i + 1..n {
+            let share = *upper.next().expect("one entry per matchup");
+            equity[i * n + j] = share;
+            equity[j * n + i] = 1.0 - share;
+        }
+    }
+    EquityTable { equity, combos }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_stacks_push_nearly_everything() {
+        let (push, call) = nash_pushfold_chart(1.5);
+        assert!(push.len() >= 1200, "pushed {} combos", push.len());
+        assert!(push.contains_hand("72o"));
+        assert!(call.contains_hand("AA"));
+
+        let (deep_push, deep_call) = nash_pushfold_chart(20.0);
+        assert!(deep_push.len() < push.len());
+        assert!(deep_call.len() < call.len());
+        assert!(deep_call.len() < deep_push.len());
+        assert!(deep_call.contains_hand("AA"));
+        assert!(deep_call.contains_hand("AKo"));
+        assert!(!deep_call.contains_hand("72o"));
+        assert!(!deep_push.contains_hand("72o"));
+    }
+
+    #[test]
+    fn test_equity_table_is_consistent() {
+        let table = equity_table();
+        let n = CANONICAL_HAND_COUNT;
+        let index = |notation: &str| {
+            matchups::HANDS
+                .iter()
+                .position(|hand| *hand == notation)
+                .unwrap()
+        };
+
+        let (aces, kings) = (index("AA"), index("KK"));
+        let equity = table.equity[aces * n + kings];
+        assert!((equity - 0.82).abs() < 0.05, "AA vs KK equity {}", equity);
+        assert!(
+            (table.equity[aces * n + kings] + table.equity[kings * n + aces] - 1.0).abs() < 1e-9
+        );
+
+        // A combo of aces leaves one combo of aces and all six of kings
+        assert_eq!(table.combos[aces * n + aces], 1.0);
+        assert_eq!(table.combos[aces * n + kings], 6.0);
+    }
+}
@@ -0,0 +1,1288 @@
+//! Exact all-in equities between the 169 canonical hands
+//!
+//! Generated by `examples/preflop_matchups.rs`; do not edit by hand.
+
+/// The canonical hands, in the order the equity table is indexed
+#[rustfmt::skip]
+pub(super) const HANDS: [&str; 169] = [
+    "AA", "AKs", "AKo", "AQs", "AQo", "AJs", "AJo", "ATs", "ATo", "A9s", "A9o", "A8s", "A8o",
+    "A7s", "A7o", "A6s", "A6o", "A5s", "A5o", "A4s", "A4o", "A3s", "A3o", "A2s", "A2o", "KK",
+    "KQs", "KQo", "KJs", "KJo", "KTs", "KTo", "K9s", "K9o", "K8s", "K8o", "K7s", "K7o", "K6s",
+    "K6o", "K5s", "K5o", "K4s", "K4o", "K3s", "K3o", "K2s", "K2o", "QQ", "QJs", "QJo", "QTs",
+    "QTo", "Q9s", "Q9o", "Q8s", "Q8o", "Q7s", "Q7o", "Q6s", "Q6o", "Q5s", "Q5o", "Q4s", "Q4o",
+    "Q3s", "Q3o", "Q2s", "Q2o", "JJ", "JTs", "JTo", "J9s", "J9o", "J8s", "J8o", "J7s", "J7o",
+    "J6s", "J6o", "J5s", "J5o", "J4s", "J4o", "J3s", "J3o", "J2s", "J2o", "TT", "T9s", "T9o",
+    "T8s", "T8o", "T7s", "T7o", "T6s", "T6o", "T5s", "T5o", "T4s", "T4o", "T3s", "T3o", "T2s",
+    "T2o", "99", "98s", "98o", "97s", "97o", "96s", "96o", "95s", "95o", "94s", "94o", "93s",
+    "93o", "92s", "92o", "88", "87s", "87o", "86s", "86o", "85s", "85o", "84s", "84o", "83s",
+    "83o", "82s", "82o", "77", "76s", "76o", "75s", "75o", "74s", "74o", "73s", "73o", "72s",
+    "72o", "66", "65s", "65o", "64s", "64o", "63s", "63o", "62s", "62o", "55", "54s", "54o",
+    "53s", "53o", "52s", "52o", "44", "43s", "43o", "42s", "42o", "33", "32s", "32o", "22",
+];
+
+/// Equity of `HANDS[i]` against `HANDS[j]` for every `i < j`, row by
+/// row, rounded to four decimals; the reverse matchup is one minus it
+#[rustfmt::skip]
+#[allow(clippy::approx_constant)]
+pub(super) static EQUITY: [f64; 169 * 168 / 2] = [
+    0.8786, 0.9317, 0.8746, 0.9274, 0.8706, 0.9230, 0.8666, 0.9186, 0.8843, 0.9378, 0.8803, 0.9335,
+    0.8803, 0.9335, 0.8823, 0.9356, 0.8669, 0.9190, 0.8709, 0.9234, 0.8749, 0.9278, 0.8789, 0.9322,
+    0.8195, 0.8294, 0.8712, 0.8253, 0.8668, 0.8213, 0.8624, 0.8273, 0.8687, 0.8392, 0.8814, 0.8351,
+    0.8771, 0.8350, 0.8770, 0.8376, 0.8797, 0.8416, 0.8841, 0.8457, 0.8886, 0.8498, 0.8930, 0.8155,
+    0.8089, 0.8493, 0.8048, 0.8449, 0.8108, 0.8512, 0.8227, 0.8639, 0.8351, 0.8771, 0.8310, 0.8726,
+    0.8335, 0.8754, 0.8376, 0.8798, 0.8417, 0.8842, 0.8457, 0.8886, 0.8115, 0.7884, 0.8274, 0.7944,
+    0.8336, 0.8063, 0.8464, 0.8187, 0.8595, 0.8310, 0.8726, 0.8295, 0.8710, 0.8336, 0.8754, 0.8376,
+    0.8798, 0.8417, 0.8842, 0.8075, 0.7780, 0.8161, 0.7899, 0.8288, 0.8023, 0.8420, 0.8145, 0.8550,
+    0.8295, 0.8710, 0.8295, 0.8710, 0.8336, 0.8754, 0.8376, 0.8799, 0.8086, 0.7794, 0.8178, 0.7918,
+    0.8310, 0.8041, 0.8440, 0.8190, 0.8599, 0.8355, 0.8775, 0.8355, 0.8776, 0.8396, 0.8820, 0.8046,
+    0.7754, 0.8134, 0.7876, 0.8265, 0.8026, 0.8424, 0.8191, 0.8600, 0.8355, 0.8776, 0.8355, 0.8776,
+    0.8048, 0.7753, 0.8133, 0.7902, 0.8293, 0.8067, 0.8468, 0.8231, 0.8644, 0.8396, 0.8820, 0.8046,
+    0.7750, 0.8131, 0.7915, 0.8306, 0.8079, 0.8482, 0.8244, 0.8658, 0.8089, 0.7891, 0.8281, 0.8055,
+    0.8457, 0.8220, 0.8633, 0.8133, 0.8096, 0.8501, 0.8260, 0.8677, 0.8177, 0.8301, 0.8721, 0.8222,
+    0.5249, 0.7128, 0.7545, 0.7101, 0.7515, 0.7074, 0.7485, 0.7119, 0.7539, 0.7094, 0.7510, 0.7070,
+    0.7483, 0.7093, 0.7508, 0.6982, 0.7386, 0.7024, 0.7432, 0.7067, 0.7479, 0.7110, 0.7526, 0.3411,
+    0.7143, 0.7559, 0.7116, 0.7529, 0.7089, 0.7499, 0.7273, 0.7703, 0.7248, 0.7676, 0.7225, 0.7649,
+    0.7231, 0.7655, 0.7273, 0.7700, 0.7318, 0.7748, 0.7363, 0.7797, 0.7409, 0.7847, 0.4605, 0.6353,
+    0.6644, 0.6322, 0.6612, 0.6445, 0.6741, 0.6528, 0.6829, 0.6619, 0.6924, 0.6583, 0.6886, 0.6612,
+    0.6915, 0.6646, 0.6952, 0.6680, 0.6989, 0.6716, 0.7027, 0.4596, 0.6201, 0.6484, 0.6324, 0.6613,
+    0.6407, 0.6702, 0.6497, 0.6796, 0.6583, 0.6886, 0.6581, 0.6883, 0.6615, 0.6919, 0.6650, 0.6957,
+    0.6685, 0.6995, 0.4594, 0.6202, 0.6485, 0.6285, 0.6574, 0.6376, 0.6668, 0.6461, 0.6758, 0.6581,
+    0.6883, 0.6585, 0.6887, 0.6619, 0.6924, 0.6655, 0.6962, 0.4742, 0.6288, 0.6581, 0.6260, 0.6550,
+    0.6345, 0.6640, 0.6465, 0.6765, 0.6590, 0.6897, 0.6594, 0.6901, 0.6629, 0.6939, 0.4752, 0.6140,
+    0.6424, 0.6225, 0.6514, 0.6345, 0.6639, 0.6470, 0.6770, 0.6596, 0.6903, 0.6601, 0.6909, 0.4769,
+    0.6106, 0.6388, 0.6226, 0.6513, 0.6351, 0.6645, 0.6477, 0.6778, 0.6603, 0.6911, 0.4769, 0.6109,
+    0.6392, 0.6235, 0.6524, 0.6360, 0.6656, 0.6487, 0.6790, 0.4803, 0.6134, 0.6416, 0.6260, 0.6548,
+    0.6386, 0.6682, 0.4859, 0.6295, 0.6586, 0.6421, 0.6719, 0.4921, 0.6457, 0.6757, 0.4989,
+    0.7012, 0.7439, 0.6983, 0.7407, 0.6953, 0.7374, 0.6996, 0.7424, 0.6968, 0.7393, 0.6941, 0.7363,
+    0.6966, 0.7389, 0.6846, 0.7258, 0.6892, 0.7308, 0.6938, 0.7358, 0.6984, 0.7408, 0.3012, 0.7042,
+    0.7477, 0.7013, 0.7445, 0.6983, 0.7412, 0.7175, 0.7624, 0.7149, 0.7594, 0.7123, 0.7565, 0.7129,
+    0.7571, 0.7176, 0.7620, 0.7224, 0.7672, 0.7273, 0.7724, 0.7323, 0.7777, 0.4324, 0.6107, 0.6483,
+    0.6075, 0.6448, 0.6200, 0.6581, 0.6284, 0.6671, 0.6379, 0.6773, 0.6341, 0.6731, 0.6373, 0.6765,
+    0.6409, 0.6804, 0.6446, 0.6844, 0.6483, 0.6885, 0.4315, 0.5949, 0.6312, 0.6074, 0.6445, 0.6158,
+    0.6535, 0.6253, 0.6637, 0.6341, 0.6731, 0.6340, 0.6730, 0.6377, 0.6769, 0.6413, 0.6809, 0.6451,
+    0.6850, 0.4312, 0.5948, 0.6309, 0.6032, 0.6399, 0.6127, 0.6501, 0.6214, 0.6595, 0.6341, 0.6730,
+    0.6344, 0.6734, 0.6381, 0.6774, 0.6418, 0.6815, 0.4473, 0.6033, 0.6406, 0.6003, 0.6373, 0.6091,
+    0.6467, 0.6217, 0.6602, 0.6347, 0.6742, 0.6351, 0.6746, 0.6389, 0.6787, 0.4484, 0.5879, 0.6239,
+    0.5967, 0.6333, 0.6093, 0.6468, 0.6223, 0.6608, 0.6353, 0.6748, 0.6358, 0.6754, 0.4502, 0.5843,
+    0.6200, 0.5969, 0.6334, 0.6099, 0.6474, 0.6230, 0.6615, 0.6361, 0.6757, 0.4501, 0.5847, 0.6203,
+    0.5977, 0.6343, 0.6107, 0.6484, 0.6239, 0.6626, 0.4538, 0.5876, 0.6235, 0.6006, 0.6376, 0.6138,
+    0.6517, 0.4597, 0.6043, 0.6415, 0.6175, 0.6557, 0.4662, 0.6212, 0.6598, 0.4735,
+    0.5249, 0.7081, 0.7496, 0.7055, 0.7466, 0.7066, 0.7483, 0.7041, 0.7454, 0.7053, 0.7468, 0.7042,
+    0.7456, 0.6931, 0.7334, 0.6973, 0.7380, 0.7016, 0.7426, 0.7058, 0.7473, 0.3185, 0.7142, 0.7558,
+    0.6177, 0.6467, 0.6146, 0.6434, 0.6265, 0.6560, 0.6348, 0.6648, 0.6331, 0.6630, 0.6295, 0.6592,
+    0.6324, 0.6621, 0.6358, 0.6658, 0.6392, 0.6695, 0.6428, 0.6733, 0.3427, 0.6997, 0.7400, 0.6970,
+    0.7370, 0.7154, 0.7573, 0.7129, 0.7546, 0.7244, 0.7670, 0.7216, 0.7639, 0.7259, 0.7684, 0.7304,
+    0.7732, 0.7349, 0.7781, 0.7395, 0.7831, 0.4588, 0.6220, 0.6503, 0.6340, 0.6629, 0.6423, 0.6717,
+    0.6528, 0.6828, 0.6611, 0.6915, 0.6609, 0.6912, 0.6644, 0.6948, 0.6678, 0.6986, 0.6714, 0.7024,
+    0.4586, 0.6218, 0.6501, 0.6302, 0.6589, 0.6406, 0.6700, 0.6489, 0.6787, 0.6610, 0.6912, 0.6613,
+    0.6916, 0.6648, 0.6953, 0.6683, 0.6991, 0.4730, 0.6185, 0.6471, 0.6290, 0.6582, 0.6342, 0.6636,
+    0.6462, 0.6761, 0.6587, 0.6893, 0.6591, 0.6897, 0.6627, 0.6935, 0.4740, 0.6170, 0.6456, 0.6222,
+    0.6510, 0.6342, 0.6635, 0.6468, 0.6766, 0.6593, 0.6899, 0.6598, 0.6905, 0.4811, 0.6145, 0.6429,
+    0.6265, 0.6554, 0.6390, 0.6686, 0.6515, 0.6819, 0.6642, 0.6952, 0.4802, 0.6118, 0.6400, 0.6243,
+    0.6532, 0.6369, 0.6665, 0.6495, 0.6798, 0.4836, 0.6142, 0.6424, 0.6268, 0.6557, 0.6394, 0.6690,
+    0.4891, 0.6303, 0.6594, 0.6429, 0.6727, 0.4953, 0.6465, 0.6765, 0.5021,
+    0.6966, 0.7391, 0.6937, 0.7358, 0.6942, 0.7368, 0.6914, 0.7337, 0.6926, 0.7350, 0.6914, 0.7336,
+    0.6794, 0.7205, 0.6839, 0.7254, 0.6885, 0.7304, 0.6931, 0.7354, 0.2821, 0.7041, 0.7452, 0.5918,
+    0.6288, 0.5886, 0.6253, 0.6007, 0.6382, 0.6092, 0.6472, 0.6073, 0.6453, 0.6035, 0.6411, 0.6067,
+    0.6445, 0.6103, 0.6484, 0.6140, 0.6524, 0.6177, 0.6565, 0.3030, 0.6885, 0.7306, 0.6855, 0.7273,
+    0.7047, 0.7485, 0.7021, 0.7455, 0.7143, 0.7587, 0.7113, 0.7553, 0.7160, 0.7603, 0.7209, 0.7654,
+    0.7257, 0.7707, 0.7307, 0.7760, 0.4308, 0.5973, 0.6338, 0.6095, 0.6468, 0.6179, 0.6558, 0.6286,
+    0.6671, 0.6372, 0.6764, 0.6372, 0.6763, 0.6408, 0.6802, 0.6445, 0.6842, 0.6482, 0.6883, 0.4305,
+    0.5968, 0.6332, 0.6053, 0.6422, 0.6160, 0.6535, 0.6246, 0.6628, 0.6372, 0.6763, 0.6376, 0.6767,
+    0.6413, 0.6807, 0.6450, 0.6848, 0.4461, 0.5929, 0.6294, 0.6036, 0.6407, 0.6089, 0.6464, 0.6215,
+    0.6599, 0.6346, 0.6739, 0.6350, 0.6744, 0.6387, 0.6785, 0.4472, 0.5912, 0.6273, 0.5965, 0.6330,
+    0.6091, 0.6465, 0.6221, 0.6605, 0.6352, 0.6746, 0.6357, 0.6751, 0.4547, 0.5884, 0.6244, 0.6010,
+    0.6379, 0.6140, 0.6519, 0.6271, 0.6659, 0.6402, 0.6801, 0.4536, 0.5856, 0.6213, 0.5986, 0.6353,
+    0.6116, 0.6494, 0.6248, 0.6635, 0.4573, 0.5885, 0.6244, 0.6015, 0.6385, 0.6147, 0.6526, 0.4632,
+    0.6052, 0.6425, 0.6184, 0.6566, 0.4698, 0.6221, 0.6607, 0.4770,
+    0.5249, 0.6987, 0.7394, 0.7006, 0.7420, 0.6980, 0.7391, 0.6963, 0.7372, 0.6989, 0.7401, 0.6844,
+    0.7242, 0.6886, 0.7288, 0.6928, 0.7334, 0.6970, 0.7380, 0.3221, 0.5897, 0.6181, 0.7127, 0.7542,
+    0.6178, 0.6467, 0.6297, 0.6592, 0.6380, 0.6680, 0.6331, 0.6629, 0.6333, 0.6633, 0.6332, 0.6630,
+    0.6366, 0.6666, 0.6401, 0.6703, 0.6436, 0.6742, 0.3197, 0.6990, 0.7392, 0.6065, 0.6347, 0.6184,
+    0.6472, 0.6267, 0.6560, 0.6351, 0.6649, 0.6326, 0.6624, 0.6325, 0.6621, 0.6359, 0.6657, 0.6393,
+    0.6695, 0.6429, 0.6733, 0.3451, 0.6851, 0.7240, 0.7035, 0.7444, 0.7010, 0.7417, 0.7125, 0.7540,
+    0.7235, 0.7660, 0.7244, 0.7668, 0.7289, 0.7716, 0.7334, 0.7765, 0.7380, 0.7815, 0.4578, 0.6238,
+    0.6520, 0.6321, 0.6609, 0.6423, 0.6716, 0.6520, 0.6818, 0.6638, 0.6941, 0.6641, 0.6945, 0.6676,
+    0.6982, 0.6711, 0.7020, 0.4723, 0.6174, 0.6457, 0.6276, 0.6565, 0.6373, 0.6667, 0.6486, 0.6785,
+    0.6611, 0.6917, 0.6615, 0.6921, 0.6650, 0.6959, 0.4733, 0.6156, 0.6439, 0.6253, 0.6541, 0.6366,
+    0.6659, 0.6491, 0.6790, 0.6617, 0.6923, 0.6622, 0.6929, 0.4799, 0.6175, 0.6461, 0.6262, 0.6550,
+    0.6387, 0.6682, 0.6513, 0.6815, 0.6639, 0.6948, 0.4843, 0.6156, 0.6441, 0.6281, 0.6573, 0.6407,
+    0.6706, 0.6534, 0.6839, 0.4868, 0.6150, 0.6433, 0.6276, 0.6565, 0.6402, 0.6699, 0.4923, 0.6311,
+    0.6602, 0.6437, 0.6736, 0.4985, 0.6473, 0.6774, 0.5054,
+    0.6868, 0.7285, 0.6881, 0.7304, 0.6853, 0.7272, 0.6833, 0.7250, 0.6860, 0.7280, 0.6703, 0.7109,
+    0.6748, 0.7158, 0.6793, 0.7207, 0.6839, 0.7257, 0.2861, 0.5632, 0.5976, 0.7025, 0.7435, 0.5921,
+    0.6289, 0.6042, 0.6418, 0.6126, 0.6508, 0.6075, 0.6453, 0.6076, 0.6455, 0.6076, 0.6454, 0.6112,
+    0.6493, 0.6148, 0.6533, 0.6186, 0.6574, 0.2835, 0.6877, 0.7274, 0.5805, 0.6165, 0.5926, 0.6294,
+    0.6010, 0.6384, 0.6096, 0.6475, 0.6069, 0.6447, 0.6069, 0.6446, 0.6105, 0.6485, 0.6142, 0.6525,
+    0.6179, 0.6566, 0.3055, 0.6727, 0.7135, 0.6919, 0.7347, 0.6892, 0.7317, 0.7015, 0.7448, 0.7133,
+    0.7576, 0.7145, 0.7585, 0.7193, 0.7637, 0.7242, 0.7689, 0.7291, 0.7743, 0.4298, 0.5992, 0.6359,
+    0.6077, 0.6448, 0.6180, 0.6558, 0.6279, 0.6662, 0.6404, 0.6796, 0.6407, 0.6800, 0.6444, 0.6840,
+    0.6481, 0.6881, 0.4454, 0.5920, 0.6284, 0.6024, 0.6394, 0.6122, 0.6498, 0.6242, 0.6626, 0.6372,
+    0.6766, 0.6376, 0.6771, 0.6414, 0.6812, 0.4465, 0.5899, 0.6260, 0.5998, 0.6364, 0.6118, 0.6492,
+    0.6248, 0.6632, 0.6378, 0.6773, 0.6383, 0.6779, 0.4535, 0.5917, 0.6278, 0.6009, 0.6376, 0.6139,
+    0.6516, 0.6269, 0.6657, 0.6401, 0.6798, 0.4581, 0.5897, 0.6257, 0.6027, 0.6397, 0.6157, 0.6538,
+    0.6289, 0.6680, 0.4608, 0.5893, 0.6253, 0.6024, 0.6394, 0.6155, 0.6536, 0.4667, 0.6061, 0.6434,
+    0.6192, 0.6576, 0.4733, 0.6230, 0.6616, 0.4805,
+    0.5249, 0.6915, 0.7322, 0.6889, 0.7293, 0.6868, 0.7271, 0.6865, 0.7268, 0.6756, 0.7150, 0.6765,
+    0.7159, 0.6806, 0.7204, 0.6848, 0.7250, 0.3257, 0.5905, 0.6189, 0.5901, 0.6184, 0.7113, 0.7526,
+    0.6328, 0.6624, 0.6411, 0.6712, 0.6358, 0.6656, 0.6334, 0.6632, 0.6370, 0.6671, 0.6374, 0.6675,
+    0.6409, 0.6712, 0.6444, 0.6750, 0.3234, 0.5805, 0.6082, 0.6975, 0.7376, 0.6215, 0.6504, 0.6298,
+    0.6592, 0.6383, 0.6682, 0.6326, 0.6623, 0.6363, 0.6662, 0.6367, 0.6666, 0.6402, 0.6703, 0.6437,
+    0.6741, 0.3217, 0.6837, 0.7226, 0.6102, 0.6384, 0.6185, 0.6472, 0.6270, 0.6562, 0.6346, 0.6643,
+    0.6356, 0.6653, 0.6360, 0.6657, 0.6394, 0.6694, 0.6430, 0.6732, 0.3480, 0.6916, 0.7314, 0.6891,
+    0.7287, 0.7006, 0.7410, 0.7116, 0.7530, 0.7263, 0.7689, 0.7275, 0.7700, 0.7320, 0.7750, 0.7366,
+    0.7799, 0.4715, 0.6188, 0.6471, 0.6290, 0.6579, 0.6384, 0.6678, 0.6516, 0.6816, 0.6639, 0.6946,
+    0.6643, 0.6950, 0.6679, 0.6989, 0.4725, 0.6170, 0.6452, 0.6265, 0.6552, 0.6397, 0.6690, 0.6519,
+    0.6820, 0.6645, 0.6952, 0.6650, 0.6958, 0.4791, 0.6161, 0.6443, 0.6293, 0.6582, 0.6411, 0.6706,
+    0.6536, 0.6839, 0.6663, 0.6972, 0.4831, 0.6187, 0.6473, 0.6279, 0.6569, 0.6405, 0.6702, 0.6531,
+    0.6835, 0.4909, 0.6189, 0.6474, 0.6315, 0.6606, 0.6441, 0.6740, 0.4955, 0.6319, 0.6611, 0.6445,
+    0.6744, 0.5017, 0.6481, 0.6782, 0.5086,
+    0.6786, 0.7203, 0.6758, 0.7171, 0.6734, 0.7145, 0.6729, 0.7140, 0.6611, 0.7013, 0.6619, 0.7021,
+    0.6664, 0.7070, 0.6710, 0.7119, 0.2900, 0.5641, 0.5986, 0.5638, 0.5981, 0.7010, 0.7417, 0.6076,
+    0.6455, 0.6161, 0.6545, 0.6104, 0.6484, 0.6077, 0.6456, 0.6117, 0.6498, 0.6121, 0.6502, 0.6157,
+    0.6542, 0.6195, 0.6583, 0.2874, 0.5540, 0.5875, 0.6861, 0.7257, 0.5961, 0.6331, 0.6045, 0.6420,
+    0.6131, 0.6511, 0.6071, 0.6448, 0.6110, 0.6490, 0.6114, 0.6494, 0.6151, 0.6534, 0.6188, 0.6575,
+    0.2856, 0.6712, 0.7096, 0.5845, 0.6206, 0.5929, 0.6296, 0.6015, 0.6387, 0.6092, 0.6470, 0.6104,
+    0.6482, 0.6108, 0.6486, 0.6144, 0.6526, 0.6182, 0.6567, 0.3087, 0.6790, 0.7208, 0.6764, 0.7178,
+    0.6887, 0.7310, 0.7005, 0.7437, 0.7165, 0.7608, 0.7177, 0.7620, 0.7226, 0.7672, 0.7275, 0.7725,
+    0.4447, 0.5939, 0.6305, 0.6042, 0.6415, 0.6138, 0.6516, 0.6274, 0.6660, 0.6403, 0.6800, 0.6408,
+    0.6804, 0.6445, 0.6845, 0.4458, 0.5918, 0.6281, 0.6013, 0.6382, 0.6150, 0.6526, 0.6279, 0.6665,
+    0.6410, 0.6806, 0.6415, 0.6812, 0.4528, 0.5905, 0.6265, 0.6041, 0.6410, 0.6165, 0.6543, 0.6296,
+    0.6684, 0.6427, 0.6826, 0.4570, 0.5929, 0.6291, 0.6025, 0.6394, 0.6156, 0.6535, 0.6287, 0.6677,
+    0.4654, 0.5935, 0.6298, 0.6065, 0.6439, 0.6197, 0.6580, 0.4702, 0.6070, 0.6443, 0.6201, 0.6585,
+    0.4768, 0.6239, 0.6625, 0.4840,
+    0.5249, 0.6670, 0.7060, 0.6652, 0.7041, 0.6646, 0.7034, 0.6508, 0.6885, 0.6553, 0.6934, 0.6561,
+    0.6943, 0.6603, 0.6988, 0.3195, 0.5746, 0.6025, 0.5741, 0.6020, 0.5738, 0.6015, 0.7110, 0.7529,
+    0.6295, 0.6594, 0.6242, 0.6538, 0.6213, 0.6508, 0.6223, 0.6519, 0.6265, 0.6565, 0.6269, 0.6569,
+    0.6305, 0.6607, 0.3172, 0.5646, 0.5918, 0.5642, 0.5913, 0.6973, 0.7379, 0.6182, 0.6474, 0.6266,
+    0.6563, 0.6205, 0.6499, 0.6216, 0.6510, 0.6258, 0.6556, 0.6262, 0.6560, 0.6297, 0.6599, 0.3156,
+    0.5547, 0.5811, 0.6836, 0.7229, 0.6070, 0.6354, 0.6154, 0.6443, 0.6231, 0.6525, 0.6209, 0.6502,
+    0.6251, 0.6548, 0.6255, 0.6552, 0.6291, 0.6590, 0.3146, 0.6699, 0.7081, 0.5958, 0.6235, 0.6042,
+    0.6325, 0.6119, 0.6406, 0.6230, 0.6523, 0.6245, 0.6540, 0.6249, 0.6545, 0.6285, 0.6583, 0.3360,
+    0.6759, 0.7144, 0.6873, 0.7267, 0.6984, 0.7387, 0.7131, 0.7546, 0.7280, 0.7708, 0.7292, 0.7720,
+    0.7338, 0.7770, 0.4570, 0.6137, 0.6418, 0.6231, 0.6517, 0.6360, 0.6652, 0.6497, 0.6797, 0.6621,
+    0.6927, 0.6626, 0.6933, 0.4636, 0.6122, 0.6403, 0.6251, 0.6538, 0.6389, 0.6683, 0.6512, 0.6814,
+    0.6638, 0.6947, 0.4676, 0.6120, 0.6401, 0.6257, 0.6547, 0.6375, 0.6672, 0.6502, 0.6805, 0.4750,
+    0.6167, 0.6451, 0.6259, 0.6548, 0.6386, 0.6682, 0.4849, 0.6305, 0.6598, 0.6431, 0.6731, 0.4902,
+    0.6436, 0.6736, 0.4970,
+    0.6525, 0.6923, 0.6504, 0.6900, 0.6495, 0.6892, 0.6345, 0.6729, 0.6393, 0.6782, 0.6401, 0.6790,
+    0.6446, 0.6839, 0.2836, 0.5473, 0.5811, 0.5470, 0.5806, 0.5467, 0.5802, 0.7001, 0.7414, 0.6039,
+    0.6419, 0.5982, 0.6358, 0.5950, 0.6324, 0.5962, 0.6336, 0.6005, 0.6384, 0.6009, 0.6389, 0.6047,
+    0.6430, 0.2810, 0.5372, 0.5700, 0.5369, 0.5696, 0.6853, 0.7253, 0.5923, 0.6294, 0.6008, 0.6385,
+    0.5943, 0.6316, 0.5955, 0.6328, 0.5998, 0.6376, 0.6003, 0.6381, 0.6040, 0.6422, 0.2792, 0.5272,
+    0.5591, 0.6705, 0.7093, 0.5808, 0.6171, 0.5893, 0.6262, 0.5970, 0.6344, 0.5949, 0.6321, 0.5992,
+    0.6369, 0.5997, 0.6374, 0.6034, 0.6414, 0.2781, 0.6557, 0.6933, 0.5693, 0.6048, 0.5779, 0.6139,
+    0.5856, 0.6221, 0.5972, 0.6344, 0.5987, 0.6362, 0.5992, 0.6367, 0.6029, 0.6407, 0.2955, 0.6620,
+    0.7024, 0.6743, 0.7156, 0.6862, 0.7283, 0.7021, 0.7454, 0.7182, 0.7627, 0.7195, 0.7640, 0.7244,
+    0.7693, 0.4290, 0.5885, 0.6248, 0.5981, 0.6349, 0.6114, 0.6490, 0.6255, 0.6641, 0.6385, 0.6781,
+    0.6390, 0.6786, 0.4361, 0.5867, 0.6227, 0.6000, 0.6368, 0.6141, 0.6519, 0.6271, 0.6659, 0.6402,
+    0.6800, 0.4402, 0.5861, 0.6219, 0.6001, 0.6370, 0.6126, 0.6504, 0.6257, 0.6646, 0.4482, 0.5911,
+    0.6273, 0.6007, 0.6377, 0.6138, 0.6519, 0.4587, 0.6054, 0.6429, 0.6186, 0.6571, 0.4643, 0.6191,
+    0.6576, 0.4715,
+    0.5249, 0.6442, 0.6815, 0.6441, 0.6814, 0.6300, 0.6661, 0.6315, 0.6678, 0.6360, 0.6728, 0.6368,
+    0.6737, 0.3193, 0.5691, 0.5970, 0.5690, 0.5968, 0.5687, 0.5963, 0.5852, 0.6137, 0.7234, 0.7663,
+    0.6265, 0.6561, 0.6236, 0.6531, 0.6242, 0.6537, 0.6257, 0.6555, 0.6300, 0.6602, 0.6305, 0.6607,
+    0.3204, 0.5619, 0.5891, 0.5616, 0.5886, 0.5781, 0.6060, 0.7097, 0.7513, 0.6290, 0.6587, 0.6232,
+    0.6527, 0.6238, 0.6532, 0.6254, 0.6550, 0.6296, 0.6597, 0.6301, 0.6602, 0.3193, 0.5525, 0.5789,
+    0.5690, 0.5962, 0.6960, 0.7364, 0.6181, 0.6471, 0.6258, 0.6553, 0.6231, 0.6524, 0.6247, 0.6542,
+    0.6290, 0.6589, 0.6294, 0.6594, 0.3183, 0.5595, 0.5861, 0.6823, 0.7215, 0.6069, 0.6352, 0.6146,
+    0.6434, 0.6257, 0.6551, 0.6241, 0.6535, 0.6284, 0.6581, 0.6288, 0.6587, 0.3241, 0.6729, 0.7114,
+    0.6001, 0.6281, 0.6078, 0.6363, 0.6189, 0.6480, 0.6306, 0.6604, 0.6321, 0.6621, 0.6326, 0.6627,
+    0.3405, 0.6754, 0.7138, 0.6865, 0.7258, 0.7012, 0.7416, 0.7161, 0.7578, 0.7311, 0.7741, 0.7323,
+    0.7754, 0.4628, 0.6142, 0.6422, 0.6271, 0.6557, 0.6405, 0.6699, 0.6543, 0.6845, 0.6667, 0.6976,
+    0.4669, 0.6134, 0.6415, 0.6268, 0.6557, 0.6406, 0.6703, 0.6530, 0.6834, 0.4742, 0.6152, 0.6434,
+    0.6290, 0.6580, 0.6409, 0.6706, 0.4837, 0.6336, 0.6629, 0.6429, 0.6727, 0.4943, 0.6475, 0.6777,
+    0.5002,
+    0.6280, 0.6661, 0.6278, 0.6658, 0.6124, 0.6492, 0.6139, 0.6510, 0.6187, 0.6562, 0.6195, 0.6571,
+    0.2833, 0.5412, 0.5745, 0.5413, 0.5745, 0.5410, 0.5740, 0.5580, 0.5921, 0.7136, 0.7559, 0.6007,
+    0.6384, 0.5975, 0.6350, 0.5981, 0.6356, 0.5997, 0.6374, 0.6040, 0.6423, 0.6046, 0.6428, 0.2845,
+    0.5341, 0.5666, 0.5339, 0.5662, 0.5508, 0.5843, 0.6987, 0.7398, 0.6033, 0.6411, 0.5972, 0.6346,
+    0.5979, 0.6352, 0.5994, 0.6370, 0.6038, 0.6419, 0.6043, 0.6425, 0.2833, 0.5245, 0.5561, 0.5415,
+    0.5742, 0.6839, 0.7238, 0.5922, 0.6292, 0.5999, 0.6374, 0.5973, 0.6345, 0.5988, 0.6363, 0.6032,
+    0.6412, 0.6037, 0.6417, 0.2822, 0.5319, 0.5638, 0.6692, 0.7078, 0.5807, 0.6168, 0.5885, 0.6251,
+    0.6001, 0.6374, 0.5983, 0.6356, 0.6027, 0.6405, 0.6032, 0.6410, 0.2882, 0.6589, 0.6969, 0.5738,
+    0.6096, 0.5815, 0.6178, 0.5931, 0.6301, 0.6051, 0.6429, 0.6067, 0.6448, 0.6072, 0.6454, 0.3003,
+    0.6615, 0.7017, 0.6733, 0.7144, 0.6893, 0.7315, 0.7054, 0.7488, 0.7215, 0.7662, 0.7228, 0.7676,
+    0.4353, 0.5890, 0.6253, 0.6024, 0.6395, 0.6162, 0.6542, 0.6303, 0.6693, 0.6433, 0.6833, 0.4395,
+    0.5879, 0.6240, 0.6017, 0.6387, 0.6158, 0.6538, 0.6288, 0.6679, 0.4475, 0.5898, 0.6260, 0.6040,
+    0.6411, 0.6165, 0.6546, 0.4576, 0.6087, 0.6463, 0.6184, 0.6568, 0.4688, 0.6232, 0.6620, 0.4750,
+    0.5249, 0.6153, 0.6504, 0.6019, 0.6359, 0.6032, 0.6373, 0.6047, 0.6391, 0.6091, 0.6441, 0.3229,
+    0.5699, 0.5978, 0.5698, 0.5976, 0.5695, 0.5971, 0.5860, 0.6145, 0.5974, 0.6264, 0.7220, 0.7647,
+    0.6267, 0.6564, 0.6273, 0.6570, 0.6284, 0.6582, 0.6300, 0.6601, 0.6343, 0.6648, 0.3188, 0.5544,
+    0.5815, 0.5545, 0.5814, 0.5710, 0.5988, 0.5824, 0.6106, 0.7201, 0.7627, 0.6229, 0.6523, 0.6234,
+    0.6529, 0.6245, 0.6541, 0.6261, 0.6560, 0.6305, 0.6607, 0.3206, 0.5474, 0.5738, 0.5640, 0.5912,
+    0.5753, 0.6030, 0.7064, 0.7477, 0.6254, 0.6549, 0.6231, 0.6524, 0.6242, 0.6537, 0.6259, 0.6556,
+    0.6302, 0.6603, 0.3202, 0.5549, 0.5815, 0.5663, 0.5933, 0.6928, 0.7328, 0.6146, 0.6434, 0.6257,
+    0.6551, 0.6236, 0.6529, 0.6253, 0.6548, 0.6296, 0.6595, 0.3260, 0.5612, 0.5880, 0.6833, 0.7227,
+    0.6078, 0.6363, 0.6189, 0.6480, 0.6306, 0.6604, 0.6290, 0.6588, 0.6333, 0.6636, 0.3264, 0.6698,
+    0.7080, 0.5967, 0.6245, 0.6079, 0.6362, 0.6195, 0.6486, 0.6313, 0.6611, 0.6329, 0.6629, 0.3442,
+    0.6760, 0.7144, 0.6907, 0.7302, 0.7056, 0.7464, 0.7206, 0.7627, 0.7356, 0.7791, 0.4629, 0.6145,
+    0.6426, 0.6280, 0.6568, 0.6414, 0.6711, 0.6553, 0.6857, 0.4702, 0.6158, 0.6439, 0.6293, 0.6582,
+    0.6432, 0.6729, 0.4798, 0.6313, 0.6604, 0.6451, 0.6750, 0.4899, 0.6498, 0.6801, 0.5012,
+    0.5970, 0.6328, 0.5824, 0.6171, 0.5836, 0.6184, 0.5851, 0.6201, 0.5899, 0.6254, 0.2873, 0.5421,
+    0.5755, 0.5422, 0.5754, 0.5419, 0.5750, 0.5589, 0.5930, 0.5709, 0.6059, 0.7120, 0.7542, 0.6009,
+    0.6386, 0.6016, 0.6393, 0.6026, 0.6405, 0.6042, 0.6423, 0.6087, 0.6473, 0.2828, 0.5260, 0.5581,
+    0.5261, 0.5581, 0.5431, 0.5761, 0.5551, 0.5890, 0.7100, 0.7519, 0.5968, 0.6342, 0.5974, 0.6348,
+    0.5985, 0.6360, 0.6001, 0.6379, 0.6045, 0.6428, 0.2847, 0.5191, 0.5503, 0.5361, 0.5684, 0.5481,
+    0.5813, 0.6952, 0.7359, 0.5995, 0.6370, 0.5973, 0.6345, 0.5983, 0.6357, 0.5999, 0.6376, 0.6044,
+    0.6425, 0.2842, 0.5268, 0.5584, 0.5388, 0.5712, 0.6804, 0.7200, 0.5885, 0.6251, 0.6000, 0.6374,
+    0.5978, 0.6350, 0.5994, 0.6369, 0.6039, 0.6418, 0.2903, 0.5337, 0.5658, 0.6702, 0.7090, 0.5815,
+    0.6178, 0.5931, 0.6301, 0.6051, 0.6429, 0.6034, 0.6412, 0.6078, 0.6462, 0.2907, 0.6556, 0.6932,
+    0.5702, 0.6056, 0.5818, 0.6179, 0.5937, 0.6307, 0.6058, 0.6436, 0.6074, 0.6456, 0.3042, 0.6621,
+    0.7023, 0.6780, 0.7193, 0.6941, 0.7367, 0.7102, 0.7540, 0.7264, 0.7715, 0.4353, 0.5894, 0.6257,
+    0.6032, 0.6404, 0.6170, 0.6552, 0.6312, 0.6703, 0.4432, 0.5908, 0.6271, 0.6047, 0.6419, 0.6189,
+    0.6571, 0.4533, 0.6066, 0.6441, 0.6208, 0.6592, 0.4641, 0.6256, 0.6645, 0.4760,
+    0.5249, 0.5674, 0.5987, 0.5696, 0.6012, 0.5713, 0.6031, 0.5757, 0.6080, 0.3193, 0.5699, 0.5978,
+    0.5660, 0.5937, 0.5660, 0.5936, 0.5825, 0.6109, 0.5939, 0.6228, 0.5937, 0.6225, 0.7216, 0.7643,
+    0.6272, 0.6569, 0.6283, 0.6582, 0.6294, 0.6595, 0.6310, 0.6613, 0.3188, 0.5544, 0.5815, 0.5545,
+    0.5814, 0.5710, 0.5988, 0.5824, 0.6106, 0.5937, 0.6225, 0.7182, 0.7606, 0.6242, 0.6536, 0.6252,
+    0.6549, 0.6264, 0.6562, 0.6280, 0.6581, 0.3154, 0.5391, 0.5654, 0.5560, 0.5831, 0.5674, 0.5950,
+    0.5788, 0.6068, 0.7164, 0.7586, 0.6204, 0.6496, 0.6214, 0.6508, 0.6226, 0.6522, 0.6242, 0.6540,
+    0.3179, 0.5491, 0.5756, 0.5605, 0.5874, 0.5718, 0.5993, 0.7028, 0.7438, 0.6229, 0.6522, 0.6212,
+    0.6505, 0.6224, 0.6518, 0.6239, 0.6537, 0.3242, 0.5558, 0.5825, 0.5672, 0.5944, 0.6934, 0.7336,
+    0.6165, 0.6455, 0.6282, 0.6579, 0.6261, 0.6559, 0.6277, 0.6577, 0.3246, 0.5578, 0.5844, 0.6798,
+    0.7189, 0.6055, 0.6338, 0.6171, 0.6461, 0.6288, 0.6586, 0.6272, 0.6571, 0.3263, 0.6697, 0.7078,
+    0.5975, 0.6253, 0.6092, 0.6377, 0.6209, 0.6502, 0.6330, 0.6630, 0.3450, 0.6769, 0.7153, 0.6918,
+    0.7315, 0.7068, 0.7477, 0.7218, 0.7641, 0.4640, 0.6140, 0.6420, 0.6275, 0.6562, 0.6413, 0.6709,
+    0.4735, 0.6290, 0.6579, 0.6428, 0.6725, 0.4837, 0.6447, 0.6747, 0.4949,
+    0.5452, 0.5770, 0.5475, 0.5795, 0.5492, 0.5814, 0.5539, 0.5866, 0.2833, 0.5421, 0.5755, 0.5380,
+    0.5710, 0.5381, 0.5710, 0.5551, 0.5891, 0.5671, 0.6020, 0.5670, 0.6017, 0.7115, 0.7537, 0.6015,
+    0.6393, 0.6026, 0.6405, 0.6037, 0.6418, 0.6052, 0.6436, 0.2828, 0.5260, 0.5581, 0.5261, 0.5581,
+    0.5431, 0.5761, 0.5551, 0.5890, 0.5670, 0.6017, 0.7079, 0.7497, 0.5983, 0.6358, 0.5994, 0.6370,
+    0.6005, 0.6383, 0.6020, 0.6401, 0.2791, 0.5100, 0.5408, 0.5275, 0.5593, 0.5395, 0.5722, 0.5514,
+    0.5849, 0.7059, 0.7476, 0.5943, 0.6314, 0.5953, 0.6326, 0.5964, 0.6339, 0.5980, 0.6357, 0.2817,
+    0.5204, 0.5516, 0.5325, 0.5645, 0.5443, 0.5772, 0.6912, 0.7316, 0.5971, 0.6343, 0.5952, 0.6324,
+    0.5963, 0.6337, 0.5979, 0.6354, 0.2884, 0.5277, 0.5595, 0.5396, 0.5722, 0.6810, 0.7207, 0.5905,
+    0.6274, 0.6025, 0.6403, 0.6003, 0.6380, 0.6018, 0.6398, 0.2888, 0.5301, 0.5619, 0.6664, 0.7048,
+    0.5792, 0.6152, 0.5911, 0.6281, 0.6032, 0.6410, 0.6014, 0.6392, 0.2905, 0.6554, 0.6930, 0.5711,
+    0.6066, 0.5831, 0.6195, 0.5951, 0.6324, 0.6075, 0.6457, 0.3050, 0.6629, 0.7031, 0.6790, 0.7205,
+    0.6951, 0.7378, 0.7113, 0.7553, 0.4365, 0.5893, 0.6257, 0.6031, 0.6405, 0.6173, 0.6557, 0.4466,
+    0.6045, 0.6421, 0.6187, 0.6573, 0.4574, 0.6206, 0.6594, 0.4693,
+    0.5250, 0.5509, 0.5805, 0.5532, 0.5832, 0.5543, 0.5845, 0.3334, 0.5747, 0.6028, 0.5717, 0.5995,
+    0.5679, 0.5955, 0.5848, 0.6132, 0.5961, 0.6251, 0.5960, 0.6248, 0.5962, 0.6251, 0.7243, 0.7672,
+    0.6400, 0.6701, 0.6412, 0.6715, 0.6423, 0.6728, 0.3329, 0.5601, 0.5873, 0.5563, 0.5833, 0.5732,
+    0.6010, 0.5846, 0.6129, 0.5960, 0.6248, 0.5932, 0.6218, 0.7210, 0.7636, 0.6370, 0.6669, 0.6381,
+    0.6682, 0.6393, 0.6696, 0.3331, 0.5448, 0.5712, 0.5617, 0.5889, 0.5731, 0.6008, 0.5845, 0.6126,
+    0.5933, 0.6219, 0.7178, 0.7600, 0.6340, 0.6637, 0.6351, 0.6650, 0.6363, 0.6664, 0.3304, 0.5465,
+    0.5729, 0.5582, 0.5852, 0.5696, 0.5971, 0.5784, 0.6063, 0.7160, 0.7581, 0.6302, 0.6597, 0.6314,
+    0.6610, 0.6325, 0.6624, 0.3396, 0.5556, 0.5824, 0.5670, 0.5943, 0.5758, 0.6035, 0.7066, 0.7480,
+    0.6372, 0.6671, 0.6355, 0.6655, 0.6367, 0.6668, 0.3406, 0.5581, 0.5847, 0.5669, 0.5939, 0.6931,
+    0.7332, 0.6265, 0.6558, 0.6383, 0.6682, 0.6362, 0.6662, 0.3423, 0.5607, 0.5873, 0.6829, 0.7222,
+    0.6186, 0.6473, 0.6303, 0.6598, 0.6420, 0.6723, 0.3438, 0.6698, 0.7080, 0.6104, 0.6386, 0.6221,
+    0.6510, 0.6338, 0.6635, 0.3647, 0.6920, 0.7312, 0.7069, 0.7474, 0.7218, 0.7636, 0.4800, 0.6447,
+    0.6741, 0.6582, 0.6884, 0.4901, 0.6598, 0.6901, 0.5010,
+    0.5279, 0.5581, 0.5304, 0.5608, 0.5314, 0.5620, 0.2985, 0.5473, 0.5808, 0.5440, 0.5773, 0.5400,
+    0.5729, 0.5574, 0.5914, 0.5694, 0.6043, 0.5693, 0.6041, 0.5697, 0.6045, 0.7146, 0.7569, 0.6151,
+    0.6535, 0.6162, 0.6548, 0.6173, 0.6561, 0.2980, 0.5321, 0.5644, 0.5280, 0.5600, 0.5454, 0.5785,
+    0.5574, 0.5914, 0.5693, 0.6041, 0.5665, 0.6010, 0.7110, 0.7530, 0.6119, 0.6500, 0.6130, 0.6513,
+    0.6141, 0.6526, 0.2982, 0.5161, 0.5471, 0.5335, 0.5656, 0.5455, 0.5785, 0.5574, 0.5912, 0.5666,
+    0.6011, 0.7075, 0.7491, 0.6087, 0.6465, 0.6098, 0.6478, 0.6109, 0.6491, 0.2952, 0.5175, 0.5484,
+    0.5299, 0.5617, 0.5418, 0.5744, 0.5509, 0.5843, 0.7056, 0.7470, 0.6047, 0.6422, 0.6058, 0.6435,
+    0.6069, 0.6448, 0.3050, 0.5274, 0.5590, 0.5393, 0.5717, 0.5484, 0.5816, 0.6953, 0.7361, 0.6120,
+    0.6501, 0.6102, 0.6483, 0.6113, 0.6496, 0.3060, 0.5302, 0.5618, 0.5393, 0.5717, 0.6807, 0.7203,
+    0.6011, 0.6383, 0.6131, 0.6512, 0.6109, 0.6490, 0.3077, 0.5331, 0.5650, 0.6698, 0.7084, 0.5930,
+    0.6297, 0.6051, 0.6426, 0.6171, 0.6556, 0.3093, 0.6555, 0.6931, 0.5847, 0.6208, 0.5967, 0.6337,
+    0.6088, 0.6467, 0.3263, 0.6798, 0.7208, 0.6959, 0.7381, 0.7119, 0.7555, 0.4534, 0.6211, 0.6593,
+    0.6350, 0.6742, 0.4642, 0.6365, 0.6759, 0.4756,
+    0.5250, 0.5276, 0.5555, 0.5302, 0.5585, 0.3297, 0.5739, 0.6019, 0.5709, 0.5987, 0.5679, 0.5955,
+    0.5809, 0.6093, 0.5927, 0.6215, 0.5925, 0.6212, 0.5928, 0.6215, 0.6048, 0.6338, 0.7258, 0.7689,
+    0.6404, 0.6706, 0.6415, 0.6720, 0.3293, 0.5593, 0.5865, 0.5563, 0.5833, 0.5694, 0.5971, 0.5811,
+    0.6094, 0.5925, 0.6212, 0.5897, 0.6183, 0.6018, 0.6305, 0.7225, 0.7652, 0.6373, 0.6674, 0.6385,
+    0.6687, 0.3295, 0.5448, 0.5712, 0.5579, 0.5850, 0.5697, 0.5972, 0.5810, 0.6091, 0.5898, 0.6183,
+    0.5988, 0.6273, 0.7192, 0.7616, 0.6343, 0.6642, 0.6355, 0.6655, 0.3304, 0.5465, 0.5729, 0.5582,
+    0.5852, 0.5696, 0.5970, 0.5784, 0.6063, 0.5989, 0.6275, 0.7160, 0.7581, 0.6314, 0.6610, 0.6325,
+    0.6624, 0.3344, 0.5473, 0.5740, 0.5591, 0.5862, 0.5679, 0.5955, 0.5884, 0.6167, 0.7185, 0.7610,
+    0.6320, 0.6618, 0.6332, 0.6632, 0.3383, 0.5522, 0.5788, 0.5610, 0.5880, 0.5815, 0.6092, 0.7050,
+    0.7462, 0.6347, 0.6646, 0.6331, 0.6629, 0.3405, 0.5552, 0.5818, 0.5757, 0.6030, 0.6948, 0.7351,
+    0.6272, 0.6565, 0.6389, 0.6690, 0.3420, 0.5675, 0.5942, 0.6817, 0.7210, 0.6189, 0.6478, 0.6306,
+    0.6603, 0.3369, 0.6843, 0.7232, 0.6102, 0.6385, 0.6219, 0.6510, 0.3690, 0.7084, 0.7490, 0.7233,
+    0.7653, 0.4869, 0.6590, 0.6893, 0.4978,
+    0.5026, 0.5309, 0.5053, 0.5339, 0.2945, 0.5463, 0.5799, 0.5431, 0.5763, 0.5400, 0.5729, 0.5532,
+    0.5870, 0.5656, 0.6003, 0.5655, 0.6001, 0.5659, 0.6005, 0.5788, 0.6139, 0.7162, 0.7586, 0.6153,
+    0.6538, 0.6164, 0.6551, 0.2940, 0.5311, 0.5634, 0.5280, 0.5599, 0.5412, 0.5741, 0.5536, 0.5874,
+    0.5655, 0.6001, 0.5627, 0.5970, 0.5755, 0.6103, 0.7126, 0.7547, 0.6120, 0.6503, 0.6131, 0.6516,
+    0.2942, 0.5161, 0.5471, 0.5293, 0.5612, 0.5417, 0.5745, 0.5536, 0.5872, 0.5628, 0.5971, 0.5724,
+    0.6069, 0.7090, 0.7508, 0.6089, 0.6468, 0.6100, 0.6481, 0.2952, 0.5175, 0.5484, 0.5299, 0.5617,
+    0.5418, 0.5744, 0.5509, 0.5843, 0.5725, 0.6070, 0.7055, 0.7470, 0.6058, 0.6434, 0.6069, 0.6448,
+    0.2993, 0.5184, 0.5495, 0.5307, 0.5626, 0.5398, 0.5725, 0.5614, 0.5953, 0.7081, 0.7500, 0.6063,
+    0.6442, 0.6074, 0.6455, 0.3035, 0.5238, 0.5551, 0.5329, 0.5649, 0.5545, 0.5877, 0.6935, 0.7341,
+    0.6092, 0.6471, 0.6074, 0.6453, 0.3057, 0.5271, 0.5586, 0.5487, 0.5814, 0.6826, 0.7223, 0.6016,
+    0.6390, 0.6136, 0.6519, 0.3074, 0.5403, 0.5725, 0.6683, 0.7070, 0.5932, 0.6301, 0.6053, 0.6430,
+    0.3015, 0.6717, 0.7101, 0.5844, 0.6205, 0.5965, 0.6335, 0.3308, 0.6975, 0.7399, 0.7135, 0.7572,
+    0.4606, 0.6356, 0.6749, 0.4721,
+    0.5251, 0.5272, 0.5553, 0.3261, 0.5731, 0.6011, 0.5700, 0.5978, 0.5670, 0.5946, 0.5809, 0.6093,
+    0.5889, 0.6176, 0.5891, 0.6177, 0.5893, 0.6180, 0.6014, 0.6303, 0.6048, 0.6340, 0.7272, 0.7705,
+    0.6407, 0.6712, 0.3256, 0.5585, 0.5856, 0.5555, 0.5824, 0.5694, 0.5971, 0.5773, 0.6054, 0.5891,
+    0.6177, 0.5863, 0.6147, 0.5983, 0.6270, 0.6018, 0.6307, 0.7239, 0.7669, 0.6376, 0.6679, 0.3259,
+    0.5440, 0.5703, 0.5579, 0.5850, 0.5658, 0.5933, 0.5776, 0.6056, 0.5864, 0.6148, 0.5953, 0.6238,
+    0.5988, 0.6275, 0.7207, 0.7633, 0.6346, 0.6647, 0.3268, 0.5465, 0.5729, 0.5544, 0.5813, 0.5661,
+    0.5935, 0.5749, 0.6027, 0.5954, 0.6239, 0.5959, 0.6244, 0.7175, 0.7598, 0.6317, 0.6615, 0.3344,
+    0.5473, 0.5740, 0.5591, 0.5862, 0.5679, 0.5954, 0.5884, 0.6166, 0.6004, 0.6293, 0.7185, 0.7610,
+    0.6332, 0.6632, 0.3331, 0.5439, 0.5704, 0.5527, 0.5796, 0.5736, 0.6012, 0.5856, 0.6138, 0.7169,
+    0.7592, 0.6295, 0.6593, 0.3382, 0.5490, 0.5755, 0.5699, 0.5971, 0.5819, 0.6097, 0.7067, 0.7482,
+    0.6353, 0.6654, 0.3397, 0.5617, 0.5883, 0.5736, 0.6009, 0.6936, 0.7340, 0.6271, 0.6567, 0.3351,
+    0.5653, 0.5920, 0.6962, 0.7362, 0.6188, 0.6478, 0.3413, 0.7000, 0.7404, 0.6224, 0.6516, 0.3740,
+    0.7248, 0.7669, 0.4945,
+    0.5021, 0.5305, 0.2905, 0.5454, 0.5789, 0.5422, 0.5754, 0.5390, 0.5719, 0.5531, 0.5870, 0.5614,
+    0.5959, 0.5617, 0.5961, 0.5621, 0.5965, 0.5749, 0.6099, 0.5786, 0.6138, 0.7177, 0.7604, 0.6154,
+    0.6541, 0.2900, 0.5302, 0.5624, 0.5270, 0.5590, 0.5412, 0.5740, 0.5494, 0.5829, 0.5617, 0.5961,
+    0.5589, 0.5930, 0.5717, 0.6063, 0.5754, 0.6103, 0.7141, 0.7564, 0.6122, 0.6506, 0.2902, 0.5151,
+    0.5461, 0.5293, 0.5611, 0.5375, 0.5701, 0.5498, 0.5832, 0.5589, 0.5931, 0.5686, 0.6029, 0.5722,
+    0.6069, 0.7105, 0.7526, 0.6090, 0.6472, 0.2912, 0.5174, 0.5483, 0.5257, 0.5572, 0.5380, 0.5704,
+    0.5471, 0.5803, 0.5687, 0.6030, 0.5691, 0.6035, 0.7070, 0.7488, 0.6060, 0.6438, 0.2993, 0.5183,
+    0.5494, 0.5306, 0.5626, 0.5398, 0.5725, 0.5613, 0.5952, 0.5738, 0.6086, 0.7081, 0.7500, 0.6074,
+    0.6454, 0.2978, 0.5147, 0.5455, 0.5239, 0.5554, 0.5459, 0.5786, 0.5583, 0.5920, 0.7063, 0.7480,
+    0.6035, 0.6412, 0.3032, 0.5203, 0.5514, 0.5423, 0.5746, 0.5547, 0.5880, 0.6954, 0.7362, 0.6097,
+    0.6478, 0.3049, 0.5339, 0.5657, 0.5463, 0.5791, 0.6811, 0.7209, 0.6014, 0.6389, 0.2996, 0.5379,
+    0.5699, 0.6845, 0.7239, 0.5929, 0.6298, 0.3062, 0.6886, 0.7284, 0.5967, 0.6339, 0.3359, 0.7151,
+    0.7589, 0.4685,
+    0.5251, 0.3225, 0.5723, 0.6002, 0.5692, 0.5970, 0.5662, 0.5938, 0.5801, 0.6084, 0.5889, 0.6176,
+    0.5852, 0.6137, 0.5855, 0.6140, 0.5979, 0.6267, 0.6014, 0.6304, 0.6048, 0.6342, 0.7287, 0.7722,
+    0.3220, 0.5577, 0.5848, 0.5547, 0.5816, 0.5685, 0.5963, 0.5773, 0.6054, 0.5852, 0.6137, 0.5825,
+    0.6108, 0.5949, 0.6235, 0.5983, 0.6272, 0.6018, 0.6309, 0.7254, 0.7686, 0.3222, 0.5432, 0.5695,
+    0.5571, 0.5841, 0.5658, 0.5933, 0.5737, 0.6016, 0.5825, 0.6108, 0.5919, 0.6203, 0.5953, 0.6240,
+    0.5988, 0.6277, 0.7221, 0.7650, 0.3231, 0.5456, 0.5721, 0.5544, 0.5813, 0.5623, 0.5896, 0.5711,
+    0.5988, 0.5920, 0.6204, 0.5924, 0.6208, 0.5959, 0.6245, 0.7189, 0.7615, 0.3308, 0.5473, 0.5740,
+    0.5553, 0.5823, 0.5640, 0.5915, 0.5849, 0.6131, 0.5969, 0.6257, 0.5973, 0.6262, 0.7200, 0.7627,
+    0.3331, 0.5439, 0.5704, 0.5527, 0.5796, 0.5736, 0.6012, 0.5856, 0.6138, 0.5976, 0.6264, 0.7169,
+    0.7593, 0.3330, 0.5386, 0.5649, 0.5620, 0.5890, 0.5739, 0.6016, 0.5859, 0.6143, 0.7186, 0.7612,
+    0.3345, 0.5537, 0.5803, 0.5657, 0.5929, 0.5777, 0.6056, 0.7055, 0.7470, 0.3333, 0.5598, 0.5865,
+    0.5718, 0.5992, 0.7081, 0.7493, 0.3395, 0.5754, 0.6030, 0.7119, 0.7534, 0.3465, 0.7157, 0.7576,
+    0.3796,
+    0.2865, 0.5444, 0.5779, 0.5412, 0.5744, 0.5380, 0.5709, 0.5522, 0.5860, 0.5613, 0.5958, 0.5574,
+    0.5916, 0.5578, 0.5921, 0.5711, 0.6058, 0.5747, 0.6098, 0.5784, 0.6138, 0.7192, 0.7621, 0.2860,
+    0.5292, 0.5614, 0.5261, 0.5580, 0.5402, 0.5730, 0.5493, 0.5829, 0.5574, 0.5916, 0.5546, 0.5886,
+    0.5679, 0.6023, 0.5715, 0.6063, 0.5752, 0.6103, 0.7156, 0.7581, 0.2862, 0.5142, 0.5451, 0.5283,
+    0.5601, 0.5374, 0.5700, 0.5455, 0.5788, 0.5547, 0.5886, 0.5647, 0.5989, 0.5684, 0.6029, 0.5720,
+    0.6069, 0.7120, 0.7543, 0.2872, 0.5165, 0.5473, 0.5256, 0.5572, 0.5337, 0.5659, 0.5428, 0.5758,
+    0.5648, 0.5990, 0.5653, 0.5995, 0.5690, 0.6035, 0.7085, 0.7505, 0.2953, 0.5182, 0.5494, 0.5264,
+    0.5581, 0.5355, 0.5680, 0.5575, 0.5912, 0.5699, 0.6046, 0.5704, 0.6051, 0.7095, 0.7517, 0.2977,
+    0.5146, 0.5454, 0.5238, 0.5553, 0.5458, 0.5785, 0.5582, 0.5919, 0.5706, 0.6054, 0.7062, 0.7480,
+    0.2975, 0.5089, 0.5395, 0.5336, 0.5654, 0.5460, 0.5789, 0.5584, 0.5923, 0.7081, 0.7500, 0.2992,
+    0.5253, 0.5565, 0.5377, 0.5700, 0.5501, 0.5834, 0.6938, 0.7348, 0.2976, 0.5318, 0.5636, 0.5443,
+    0.5771, 0.6972, 0.7378, 0.3042, 0.5481, 0.5811, 0.7014, 0.7423, 0.3116, 0.7055, 0.7468, 0.3418,
+    0.8613, 0.9130, 0.8573, 0.9087, 0.8533, 0.9043, 0.8710, 0.9234, 0.8842, 0.9377, 0.8802, 0.9333,
+    0.8802, 0.9334, 0.8800, 0.9331, 0.8840, 0.9375, 0.8880, 0.9419, 0.8920, 0.9463, 0.8193, 0.8230,
+    0.8644, 0.8189, 0.8600, 0.8220, 0.8632, 0.8259, 0.8672, 0.8378, 0.8799, 0.8337, 0.8755, 0.8335,
+    0.8753, 0.8375, 0.8797, 0.8416, 0.8841, 0.8456, 0.8885, 0.8154, 0.8025, 0.8425, 0.8055, 0.8456,
+    0.8094, 0.8496, 0.8213, 0.8624, 0.8337, 0.8755, 0.8294, 0.8709, 0.8335, 0.8753, 0.8375, 0.8797,
+    0.8416, 0.8842, 0.8114, 0.7891, 0.8281, 0.7930, 0.8321, 0.8049, 0.8448, 0.8173, 0.8580, 0.8294,
+    0.8709, 0.8294, 0.8709, 0.8335, 0.8754, 0.8376, 0.8798, 0.8082, 0.7825, 0.8211, 0.7906, 0.8296,
+    0.8030, 0.8428, 0.8151, 0.8557, 0.8316, 0.8733, 0.8316, 0.8733, 0.8356, 0.8778, 0.8087, 0.7793,
+    0.8178, 0.7917, 0.8309, 0.8039, 0.8438, 0.8203, 0.8614, 0.8368, 0.8790, 0.8368, 0.8790, 0.8047,
+    0.7753, 0.8134, 0.7874, 0.8263, 0.8039, 0.8438, 0.8204, 0.8614, 0.8368, 0.8790, 0.8049, 0.7751,
+    0.8131, 0.7915, 0.8307, 0.8080, 0.8483, 0.8244, 0.8659, 0.8048, 0.7748, 0.8129, 0.7913, 0.8305,
+    0.8077, 0.8481, 0.8092, 0.7954, 0.8349, 0.8118, 0.8525, 0.8137, 0.8159, 0.8569, 0.8181,
+    0.5249, 0.7085, 0.7496, 0.7058, 0.7465, 0.7068, 0.7481, 0.7105, 0.7525, 0.7080, 0.7496, 0.7055,
+    0.7469, 0.7061, 0.7475, 0.7103, 0.7521, 0.7146, 0.7567, 0.7189, 0.7614, 0.3534, 0.7084, 0.7492,
+    0.7057, 0.7462, 0.7101, 0.7514, 0.7242, 0.7671, 0.7218, 0.7644, 0.7195, 0.7618, 0.7203, 0.7626,
+    0.7247, 0.7674, 0.7292, 0.7722, 0.7337, 0.7772, 0.4633, 0.6334, 0.6620, 0.6432, 0.6724, 0.6524,
+    0.6820, 0.6608, 0.6909, 0.6699, 0.7004, 0.6669, 0.6972, 0.6703, 0.7009, 0.6738, 0.7046, 0.6773,
+    0.7084, 0.4631, 0.6311, 0.6596, 0.6402, 0.6692, 0.6486, 0.6781, 0.6577, 0.6876, 0.6669, 0.6972,
+    0.6673, 0.6976, 0.6707, 0.7014, 0.6743, 0.7052, 0.4744, 0.6286, 0.6574, 0.6342, 0.6633, 0.6402,
+    0.6696, 0.6494, 0.6791, 0.6619, 0.6923, 0.6623, 0.6928, 0.6658, 0.6966, 0.4872, 0.6327, 0.6620,
+    0.6299, 0.6590, 0.6390, 0.6686, 0.6515, 0.6818, 0.6641, 0.6950, 0.6646, 0.6956, 0.4887, 0.6179,
+    0.6464, 0.6271, 0.6560, 0.6396, 0.6692, 0.6522, 0.6825, 0.6648, 0.6958, 0.4909, 0.6152, 0.6435,
+    0.6278, 0.6567, 0.6403, 0.6700, 0.6530, 0.6833, 0.4948, 0.6163, 0.6446, 0.6288, 0.6579, 0.6415,
+    0.6712, 0.5001, 0.6323, 0.6616, 0.6450, 0.6749, 0.5060, 0.6485, 0.6787, 0.5126,
+    0.6975, 0.7396, 0.6945, 0.7362, 0.6949, 0.7372, 0.6984, 0.7412, 0.6956, 0.7381, 0.6929, 0.7351,
+    0.6935, 0.7357, 0.6981, 0.7406, 0.7026, 0.7455, 0.7072, 0.7505, 0.3146, 0.6987, 0.7414, 0.6956,
+    0.7380, 0.6997, 0.7429, 0.7145, 0.7592, 0.7119, 0.7562, 0.7093, 0.7533, 0.7102, 0.7542, 0.7149,
+    0.7593, 0.7198, 0.7645, 0.7247, 0.7697, 0.4354, 0.6092, 0.6462, 0.6192, 0.6570, 0.6286, 0.6668,
+    0.6371, 0.6759, 0.6466, 0.6861, 0.6435, 0.6827, 0.6471, 0.6866, 0.6507, 0.6906, 0.6545, 0.6947,
+    0.4351, 0.6066, 0.6434, 0.6160, 0.6532, 0.6245, 0.6623, 0.6340, 0.6725, 0.6435, 0.6827, 0.6438,
+    0.6831, 0.6475, 0.6871, 0.6512, 0.6912, 0.4475, 0.6036, 0.6404, 0.6092, 0.6464, 0.6155, 0.6531,
+    0.6249, 0.6632, 0.6379, 0.6772, 0.6384, 0.6777, 0.6421, 0.6817, 0.4613, 0.6074, 0.6449, 0.6045,
+    0.6416, 0.6140, 0.6517, 0.6270, 0.6657, 0.6400, 0.6798, 0.6405, 0.6804, 0.4629, 0.5922, 0.6283,
+    0.6016, 0.6384, 0.6146, 0.6524, 0.6277, 0.6665, 0.6408, 0.6806, 0.4652, 0.5893, 0.6251, 0.6023,
+    0.6391, 0.6154, 0.6532, 0.6285, 0.6674, 0.4695, 0.5904, 0.6263, 0.6035, 0.6403, 0.6166, 0.6545,
+    0.4751, 0.6071, 0.6443, 0.6203, 0.6585, 0.4813, 0.6240, 0.6625, 0.4882,
+    0.5249, 0.6991, 0.7394, 0.7009, 0.7419, 0.7017, 0.7431, 0.6991, 0.7402, 0.7003, 0.7416, 0.6976,
+    0.7385, 0.7018, 0.7430, 0.7060, 0.7476, 0.7102, 0.7523, 0.3208, 0.7076, 0.7485, 0.6159, 0.6443,
+    0.6277, 0.6567, 0.6365, 0.6660, 0.6448, 0.6748, 0.6431, 0.6731, 0.6402, 0.6699, 0.6436, 0.6736,
+    0.6470, 0.6773, 0.6506, 0.6811, 0.3557, 0.6938, 0.7332, 0.6982, 0.7385, 0.7123, 0.7541, 0.7099,
+    0.7514, 0.7213, 0.7638, 0.7188, 0.7610, 0.7232, 0.7658, 0.7277, 0.7706, 0.7323, 0.7756, 0.4623,
+    0.6330, 0.6615, 0.6419, 0.6708, 0.6503, 0.6797, 0.6608, 0.6908, 0.6697, 0.7001, 0.6701, 0.7005,
+    0.6736, 0.7043, 0.6771, 0.7081, 0.4737, 0.6271, 0.6557, 0.6327, 0.6616, 0.6433, 0.6727, 0.6517,
+    0.6815, 0.6642, 0.6947, 0.6647, 0.6952, 0.6682, 0.6990, 0.4860, 0.6224, 0.6510, 0.6329, 0.6621,
+    0.6387, 0.6682, 0.6513, 0.6814, 0.6638, 0.6946, 0.6643, 0.6952, 0.4875, 0.6210, 0.6496, 0.6268,
+    0.6556, 0.6393, 0.6688, 0.6519, 0.6821, 0.6646, 0.6954, 0.4950, 0.6191, 0.6476, 0.6316, 0.6608,
+    0.6442, 0.6741, 0.6568, 0.6874, 0.4980, 0.6171, 0.6454, 0.6297, 0.6587, 0.6423, 0.6720, 0.5033,
+    0.6331, 0.6624, 0.6458, 0.6758, 0.5092, 0.6494, 0.6795, 0.5158,
+    0.6876, 0.7290, 0.6889, 0.7308, 0.6892, 0.7315, 0.6864, 0.7283, 0.6876, 0.7296, 0.6846, 0.7263,
+    0.6891, 0.7311, 0.6936, 0.7360, 0.6982, 0.7410, 0.2846, 0.6979, 0.7381, 0.5904, 0.6268, 0.6025,
+    0.6396, 0.6114, 0.6491, 0.6199, 0.6581, 0.6181, 0.6563, 0.6150, 0.6528, 0.6186, 0.6567, 0.6222,
+    0.6607, 0.6260, 0.6648, 0.3171, 0.6828, 0.7241, 0.6869, 0.7290, 0.7017, 0.7453, 0.6991, 0.7424,
+    0.7114, 0.7555, 0.7086, 0.7524, 0.7134, 0.7575, 0.7182, 0.7627, 0.7231, 0.7680, 0.4344, 0.6090,
+    0.6460, 0.6180, 0.6555, 0.6266, 0.6646, 0.6373, 0.6760, 0.6466, 0.6860, 0.6470, 0.6864, 0.6507,
+    0.6904, 0.6544, 0.6945, 0.4468, 0.6024, 0.6391, 0.6080, 0.6451, 0.6188, 0.6565, 0.6276, 0.6659,
+    0.6406, 0.6799, 0.6410, 0.6804, 0.6447, 0.6845, 0.4601, 0.5971, 0.6337, 0.6078, 0.6450, 0.6138,
+    0.6515, 0.6268, 0.6655, 0.6399, 0.6796, 0.6404, 0.6801, 0.4618, 0.5954, 0.6317, 0.6015, 0.6381,
+    0.6145, 0.6521, 0.6275, 0.6662, 0.6407, 0.6804, 0.4697, 0.5934, 0.6296, 0.6064, 0.6436, 0.6195,
+    0.6577, 0.6326, 0.6718, 0.4730, 0.5913, 0.6272, 0.6043, 0.6413, 0.6175, 0.6554, 0.4786, 0.6080,
+    0.6452, 0.6212, 0.6594, 0.4848, 0.6249, 0.6635, 0.4918,
+    0.5249, 0.6919, 0.7323, 0.6933, 0.7341, 0.6907, 0.7312, 0.6889, 0.7293, 0.6898, 0.7304, 0.6907,
+    0.7312, 0.6948, 0.7358, 0.6990, 0.7404, 0.3245, 0.5879, 0.6157, 0.7062, 0.7469, 0.6308, 0.6600,
+    0.6396, 0.6692, 0.6480, 0.6781, 0.6432, 0.6730, 0.6440, 0.6740, 0.6444, 0.6744, 0.6479, 0.6781,
+    0.6514, 0.6820, 0.3228, 0.6924, 0.7319, 0.6195, 0.6480, 0.6283, 0.6572, 0.6367, 0.6661, 0.6452,
+    0.6751, 0.6433, 0.6732, 0.6437, 0.6735, 0.6471, 0.6773, 0.6507, 0.6811, 0.3586, 0.6863, 0.7255,
+    0.7004, 0.7412, 0.6980, 0.7385, 0.7094, 0.7508, 0.7207, 0.7630, 0.7218, 0.7642, 0.7263, 0.7690,
+    0.7308, 0.7740, 0.4729, 0.6286, 0.6571, 0.6342, 0.6630, 0.6444, 0.6738, 0.6548, 0.6847, 0.6671,
+    0.6976, 0.6675, 0.6981, 0.6710, 0.7019, 0.4852, 0.6212, 0.6496, 0.6315, 0.6604, 0.6418, 0.6713,
+    0.6536, 0.6838, 0.6662, 0.6970, 0.6667, 0.6976, 0.4868, 0.6195, 0.6479, 0.6299, 0.6588, 0.6417,
+    0.6712, 0.6543, 0.6845, 0.6669, 0.6978, 0.4938, 0.6222, 0.6508, 0.6314, 0.6604, 0.6439, 0.6737,
+    0.6566, 0.6870, 0.5022, 0.6209, 0.6495, 0.6335, 0.6628, 0.6462, 0.6761, 0.5065, 0.6340, 0.6632,
+    0.6466, 0.6766, 0.5125, 0.6502, 0.6804, 0.5190,
+    0.6795, 0.7208, 0.6805, 0.7222, 0.6776, 0.7190, 0.6756, 0.7168, 0.6764, 0.7178, 0.6773, 0.7186,
+    0.6818, 0.7235, 0.6863, 0.7284, 0.2885, 0.5618, 0.5956, 0.6963, 0.7364, 0.6059, 0.6433, 0.6149,
+    0.6527, 0.6234, 0.6618, 0.6183, 0.6563, 0.6191, 0.6573, 0.6195, 0.6577, 0.6231, 0.6617, 0.6269,
+    0.6658, 0.2867, 0.6814, 0.7203, 0.5943, 0.6308, 0.6033, 0.6403, 0.6118, 0.6493, 0.6204, 0.6585,
+    0.6184, 0.6565, 0.6188, 0.6569, 0.6225, 0.6609, 0.6262, 0.6649, 0.3202, 0.6741, 0.7152, 0.6889,
+    0.7315, 0.6863, 0.7285, 0.6985, 0.7417, 0.7106, 0.7546, 0.7118, 0.7558, 0.7166, 0.7610, 0.7215,
+    0.7663, 0.4461, 0.6042, 0.6412, 0.6099, 0.6471, 0.6203, 0.6582, 0.6308, 0.6693, 0.6437, 0.6832,
+    0.6442, 0.6837, 0.6479, 0.6878, 0.4594, 0.5962, 0.6327, 0.6066, 0.6437, 0.6171, 0.6549, 0.6295,
+    0.6682, 0.6425, 0.6823, 0.6430, 0.6829, 0.4611, 0.5942, 0.6304, 0.6047, 0.6415, 0.6171, 0.6549,
+    0.6302, 0.6690, 0.6433, 0.6831, 0.4686, 0.5967, 0.6330, 0.6063, 0.6433, 0.6194, 0.6574, 0.6325,
+    0.6715, 0.4775, 0.5954, 0.6316, 0.6085, 0.6457, 0.6216, 0.6599, 0.4821, 0.6089, 0.6462, 0.6220,
+    0.6603, 0.4883, 0.6258, 0.6644, 0.4953,
+    0.5249, 0.6733, 0.7128, 0.6707, 0.7099, 0.6686, 0.7076, 0.6666, 0.7055, 0.6711, 0.7105, 0.6719,
+    0.7113, 0.6761, 0.7159, 0.3183, 0.5719, 0.5993, 0.5716, 0.5988, 0.7060, 0.7471, 0.6280, 0.6573,
+    0.6364, 0.6662, 0.6311, 0.6607, 0.6293, 0.6588, 0.6335, 0.6634, 0.6339, 0.6639, 0.6374, 0.6677,
+    0.3167, 0.5621, 0.5886, 0.6923, 0.7322, 0.6167, 0.6454, 0.6251, 0.6543, 0.6336, 0.6632, 0.6286,
+    0.6580, 0.6328, 0.6626, 0.6332, 0.6631, 0.6368, 0.6669, 0.3157, 0.6786, 0.7173, 0.6055, 0.6334,
+    0.6139, 0.6423, 0.6224, 0.6513, 0.6307, 0.6601, 0.6322, 0.6618, 0.6326, 0.6622, 0.6361, 0.6661,
+    0.3463, 0.6870, 0.7267, 0.6846, 0.7240, 0.6961, 0.7364, 0.7073, 0.7486, 0.7222, 0.7647, 0.7233,
+    0.7660, 0.7279, 0.7709, 0.4697, 0.6174, 0.6456, 0.6276, 0.6564, 0.6377, 0.6670, 0.6514, 0.6815,
+    0.6637, 0.6945, 0.6642, 0.6951, 0.4712, 0.6157, 0.6438, 0.6257, 0.6544, 0.6395, 0.6689, 0.6518,
+    0.6819, 0.6644, 0.6953, 0.4783, 0.6154, 0.6436, 0.6291, 0.6581, 0.6410, 0.6706, 0.6536, 0.6840,
+    0.4862, 0.6182, 0.6467, 0.6275, 0.6565, 0.6401, 0.6698, 0.4959, 0.6320, 0.6614, 0.6447, 0.6747,
+    0.5009, 0.6452, 0.6753, 0.5074,
+    0.6592, 0.6996, 0.6563, 0.6964, 0.6539, 0.6938, 0.6516, 0.6913, 0.6563, 0.6965, 0.6572, 0.6974,
+    0.6617, 0.7022, 0.2821, 0.5450, 0.5781, 0.5447, 0.5777, 0.6954, 0.7360, 0.6026, 0.6401, 0.6112,
+    0.6492, 0.6055, 0.6432, 0.6036, 0.6411, 0.6079, 0.6459, 0.6083, 0.6464, 0.6121, 0.6504, 0.2803,
+    0.5350, 0.5672, 0.6806, 0.7200, 0.5911, 0.6278, 0.5996, 0.6368, 0.6083, 0.6460, 0.6030, 0.6403,
+    0.6073, 0.6451, 0.6077, 0.6456, 0.6115, 0.6497, 0.2792, 0.6659, 0.7040, 0.5796, 0.6154, 0.5881,
+    0.6244, 0.5967, 0.6336, 0.6052, 0.6426, 0.6067, 0.6444, 0.6072, 0.6449, 0.6109, 0.6489, 0.3067,
+    0.6743, 0.7159, 0.6717, 0.7129, 0.6840, 0.7261, 0.6961, 0.7391, 0.7121, 0.7564, 0.7133, 0.7576,
+    0.7182, 0.7629, 0.4427, 0.5923, 0.6288, 0.6027, 0.6399, 0.6130, 0.6507, 0.6270, 0.6657, 0.6400,
+    0.6797, 0.6405, 0.6803, 0.4443, 0.5904, 0.6265, 0.6006, 0.6373, 0.6147, 0.6524, 0.6276, 0.6664,
+    0.6408, 0.6805, 0.4518, 0.5898, 0.6258, 0.6039, 0.6408, 0.6163, 0.6542, 0.6294, 0.6684, 0.4603,
+    0.5924, 0.6285, 0.6021, 0.6389, 0.6152, 0.6531, 0.4705, 0.6068, 0.6441, 0.6199, 0.6583, 0.4758,
+    0.6205, 0.6588, 0.4827,
+    0.5249, 0.6438, 0.6810, 0.6422, 0.6794, 0.6399, 0.6769, 0.6414, 0.6786, 0.6459, 0.6836, 0.6467,
+    0.6845, 0.3142, 0.5594, 0.5864, 0.5591, 0.5859, 0.5756, 0.6033, 0.7056, 0.7471, 0.6279, 0.6576,
+    0.6226, 0.6520, 0.6203, 0.6497, 0.6219, 0.6515, 0.6262, 0.6561, 0.6266, 0.6567, 0.3126, 0.5496,
+    0.5758, 0.5661, 0.5931, 0.6919, 0.7322, 0.6167, 0.6456, 0.6251, 0.6546, 0.6197, 0.6489, 0.6213,
+    0.6506, 0.6255, 0.6553, 0.6260, 0.6558, 0.3116, 0.5566, 0.5830, 0.6782, 0.7173, 0.6055, 0.6337,
+    0.6140, 0.6427, 0.6223, 0.6515, 0.6207, 0.6499, 0.6249, 0.6545, 0.6254, 0.6551, 0.3173, 0.6688,
+    0.7072, 0.5955, 0.6232, 0.6040, 0.6322, 0.6123, 0.6410, 0.6239, 0.6534, 0.6255, 0.6552, 0.6259,
+    0.6557, 0.3387, 0.6716, 0.7099, 0.6830, 0.7223, 0.6943, 0.7345, 0.7091, 0.7506, 0.7241, 0.7669,
+    0.7253, 0.7682, 0.4594, 0.6131, 0.6411, 0.6232, 0.6517, 0.6366, 0.6659, 0.6504, 0.6805, 0.6628,
+    0.6936, 0.4664, 0.6124, 0.6404, 0.6258, 0.6546, 0.6395, 0.6692, 0.6519, 0.6823, 0.4744, 0.6122,
+    0.6404, 0.6260, 0.6550, 0.6379, 0.6676, 0.4837, 0.6306, 0.6599, 0.6399, 0.6697, 0.4940, 0.6445,
+    0.6748, 0.4996,
+    0.6276, 0.6656, 0.6257, 0.6636, 0.6230, 0.6607, 0.6245, 0.6625, 0.6293, 0.6677, 0.6301, 0.6686,
+    0.2780, 0.5319, 0.5645, 0.5316, 0.5640, 0.5486, 0.5821, 0.6946, 0.7355, 0.6023, 0.6401, 0.5967,
+    0.6341, 0.5942, 0.6314, 0.5957, 0.6332, 0.6001, 0.6381, 0.6006, 0.6386, 0.2762, 0.5219, 0.5535,
+    0.5389, 0.5716, 0.6798, 0.7195, 0.5908, 0.6277, 0.5994, 0.6369, 0.5936, 0.6306, 0.5951, 0.6324,
+    0.5995, 0.6373, 0.6000, 0.6379, 0.2750, 0.5292, 0.5612, 0.6650, 0.7035, 0.5793, 0.6154, 0.5879,
+    0.6246, 0.5964, 0.6335, 0.5946, 0.6317, 0.5990, 0.6366, 0.5995, 0.6372, 0.2810, 0.6548, 0.6926,
+    0.5689, 0.6044, 0.5776, 0.6135, 0.5860, 0.6225, 0.5980, 0.6353, 0.5996, 0.6372, 0.6001, 0.6378,
+    0.2984, 0.6576, 0.6978, 0.6699, 0.7109, 0.6819, 0.7239, 0.6980, 0.7412, 0.7141, 0.7585, 0.7153,
+    0.7598, 0.4316, 0.5879, 0.6241, 0.5981, 0.6349, 0.6119, 0.6496, 0.6261, 0.6647, 0.6391, 0.6788,
+    0.4391, 0.5868, 0.6228, 0.6006, 0.6375, 0.6147, 0.6526, 0.6277, 0.6667, 0.4475, 0.5864, 0.6222,
+    0.6005, 0.6373, 0.6130, 0.6508, 0.4574, 0.6052, 0.6425, 0.6150, 0.6530, 0.4683, 0.6197, 0.6583,
+    0.4742,
+    0.5249, 0.6153, 0.6504, 0.6137, 0.6487, 0.6149, 0.6502, 0.6165, 0.6519, 0.6209, 0.6569, 0.3140,
+    0.5539, 0.5809, 0.5539, 0.5808, 0.5704, 0.5981, 0.5836, 0.6118, 0.7180, 0.7605, 0.6249, 0.6544,
+    0.6227, 0.6520, 0.6238, 0.6533, 0.6254, 0.6552, 0.6297, 0.6599, 0.3158, 0.5469, 0.5731, 0.5634,
+    0.5905, 0.5765, 0.6042, 0.7043, 0.7456, 0.6275, 0.6570, 0.6224, 0.6516, 0.6235, 0.6529, 0.6251,
+    0.6548, 0.6294, 0.6595, 0.3153, 0.5544, 0.5808, 0.5675, 0.5945, 0.6906, 0.7307, 0.6167, 0.6455,
+    0.6250, 0.6543, 0.6229, 0.6521, 0.6245, 0.6540, 0.6288, 0.6587, 0.3210, 0.5624, 0.5892, 0.6778,
+    0.7169, 0.6068, 0.6351, 0.6151, 0.6439, 0.6267, 0.6563, 0.6252, 0.6547, 0.6295, 0.6595, 0.3267,
+    0.6680, 0.7063, 0.5995, 0.6275, 0.6078, 0.6363, 0.6195, 0.6487, 0.6312, 0.6612, 0.6328, 0.6630,
+    0.3438, 0.6711, 0.7093, 0.6824, 0.7215, 0.6972, 0.7377, 0.7122, 0.7539, 0.7272, 0.7702, 0.4657,
+    0.6143, 0.6423, 0.6277, 0.6565, 0.6412, 0.6708, 0.6550, 0.6854, 0.4736, 0.6137, 0.6418, 0.6272,
+    0.6561, 0.6410, 0.6707, 0.4829, 0.6291, 0.6582, 0.6430, 0.6729, 0.4928, 0.6476, 0.6779, 0.5037,
+    0.5970, 0.6328, 0.5951, 0.6308, 0.5963, 0.6321, 0.5978, 0.6339, 0.6025, 0.6391, 0.2777, 0.5258,
+    0.5579, 0.5259, 0.5579, 0.5429, 0.5760, 0.5564, 0.5903, 0.7080, 0.7500, 0.5991, 0.6366, 0.5966,
+    0.6340, 0.5977, 0.6352, 0.5993, 0.6370, 0.6037, 0.6420, 0.2796, 0.5188, 0.5502, 0.5358, 0.5682,
+    0.5493, 0.5826, 0.6932, 0.7340, 0.6019, 0.6394, 0.5965, 0.6336, 0.5975, 0.6348, 0.5991, 0.6367,
+    0.6036, 0.6417, 0.2791, 0.5266, 0.5582, 0.5401, 0.5725, 0.6785, 0.7180, 0.5908, 0.6275, 0.5992,
+    0.6365, 0.5970, 0.6342, 0.5986, 0.6360, 0.6030, 0.6410, 0.2850, 0.5349, 0.5671, 0.6646, 0.7031,
+    0.5806, 0.6167, 0.5890, 0.6256, 0.6010, 0.6385, 0.5993, 0.6368, 0.6037, 0.6417, 0.2910, 0.6539,
+    0.6916, 0.5732, 0.6089, 0.5816, 0.6178, 0.5936, 0.6307, 0.6056, 0.6436, 0.6073, 0.6456, 0.3038,
+    0.6570, 0.6971, 0.6691, 0.7100, 0.6851, 0.7273, 0.7012, 0.7446, 0.7174, 0.7621, 0.4384, 0.5892,
+    0.6254, 0.6030, 0.6401, 0.6168, 0.6549, 0.6310, 0.6701, 0.4468, 0.5882, 0.6243, 0.6021, 0.6391,
+    0.6163, 0.6542, 0.4567, 0.6040, 0.6412, 0.6182, 0.6564, 0.4671, 0.6230, 0.6617, 0.4787,
+    0.5249, 0.5811, 0.6136, 0.5833, 0.6160, 0.5845, 0.6175, 0.5861, 0.6192, 0.3176, 0.5547, 0.5817,
+    0.5548, 0.5816, 0.5713, 0.5989, 0.5844, 0.6127, 0.5958, 0.6246, 0.7165, 0.7589, 0.6258, 0.6553,
+    0.6269, 0.6565, 0.6280, 0.6579, 0.6297, 0.6598, 0.3142, 0.5394, 0.5655, 0.5563, 0.5833, 0.5694,
+    0.5970, 0.5809, 0.6090, 0.7147, 0.7570, 0.6220, 0.6512, 0.6231, 0.6525, 0.6242, 0.6538, 0.6259,
+    0.6558, 0.3166, 0.5494, 0.5757, 0.5625, 0.5895, 0.5739, 0.6014, 0.7011, 0.7421, 0.6246, 0.6539,
+    0.6229, 0.6521, 0.6240, 0.6535, 0.6257, 0.6554, 0.3229, 0.5578, 0.5846, 0.5661, 0.5932, 0.6883,
+    0.7283, 0.6151, 0.6439, 0.6267, 0.6563, 0.6247, 0.6542, 0.6264, 0.6561, 0.3286, 0.5607, 0.5874,
+    0.6785, 0.7177, 0.6078, 0.6363, 0.6195, 0.6487, 0.6312, 0.6611, 0.6297, 0.6597, 0.3296, 0.6650,
+    0.7030, 0.5968, 0.6246, 0.6085, 0.6370, 0.6202, 0.6494, 0.6320, 0.6620, 0.3480, 0.6719, 0.7102,
+    0.6868, 0.7263, 0.7017, 0.7425, 0.7167, 0.7589, 0.4696, 0.6148, 0.6429, 0.6283, 0.6571, 0.6418,
+    0.6715, 0.4789, 0.6298, 0.6588, 0.6433, 0.6731, 0.4888, 0.6453, 0.6753, 0.4993,
+    0.5601, 0.5931, 0.5624, 0.5956, 0.5636, 0.5970, 0.5651, 0.5987, 0.2817, 0.5266, 0.5588, 0.5268,
+    0.5589, 0.5438, 0.5769, 0.5573, 0.5912, 0.5694, 0.6042, 0.7065, 0.7483, 0.6001, 0.6376, 0.6011,
+    0.6388, 0.6022, 0.6401, 0.6039, 0.6420, 0.2779, 0.5107, 0.5416, 0.5281, 0.5601, 0.5416, 0.5744,
+    0.5537, 0.5873, 0.7045, 0.7461, 0.5960, 0.6332, 0.5971, 0.6344, 0.5982, 0.6357, 0.5999, 0.6376,
+    0.2805, 0.5211, 0.5524, 0.5346, 0.5667, 0.5467, 0.5796, 0.6897, 0.7302, 0.5988, 0.6361, 0.5970,
+    0.6342, 0.5981, 0.6354, 0.5997, 0.6374, 0.2871, 0.5299, 0.5617, 0.5386, 0.5711, 0.6758, 0.7152,
+    0.5890, 0.6256, 0.6009, 0.6385, 0.5988, 0.6362, 0.6004, 0.6381, 0.2930, 0.5330, 0.5651, 0.6651,
+    0.7038, 0.5816, 0.6178, 0.5936, 0.6307, 0.6056, 0.6436, 0.6040, 0.6420, 0.2940, 0.6506, 0.6880,
+    0.5703, 0.6057, 0.5823, 0.6186, 0.5943, 0.6315, 0.6064, 0.6445, 0.3083, 0.6579, 0.6979, 0.6739,
+    0.7152, 0.6900, 0.7325, 0.7061, 0.7499, 0.4426, 0.5897, 0.6260, 0.6036, 0.6407, 0.6175, 0.6556,
+    0.4524, 0.6050, 0.6423, 0.6189, 0.6572, 0.4629, 0.6209, 0.6594, 0.4741,
+    0.5250, 0.5520, 0.5821, 0.5544, 0.5848, 0.5556, 0.5863, 0.3179, 0.5553, 0.5823, 0.5515, 0.5782,
+    0.5684, 0.5960, 0.5815, 0.6097, 0.5929, 0.6216, 0.5928, 0.6214, 0.7163, 0.7587, 0.6295, 0.6592,
+    0.6307, 0.6606, 0.6319, 0.6620, 0.3181, 0.5400, 0.5661, 0.5569, 0.5839, 0.5700, 0.5976, 0.5814,
+    0.6095, 0.5929, 0.6214, 0.7130, 0.7551, 0.6265, 0.6560, 0.6277, 0.6574, 0.6289, 0.6588, 0.3153,
+    0.5416, 0.5679, 0.5551, 0.5820, 0.5666, 0.5939, 0.5780, 0.6059, 0.7113, 0.7532, 0.6228, 0.6520,
+    0.6239, 0.6534, 0.6251, 0.6548, 0.3245, 0.5525, 0.5792, 0.5609, 0.5879, 0.5723, 0.5998, 0.6985,
+    0.7394, 0.6266, 0.6562, 0.6250, 0.6545, 0.6262, 0.6559, 0.3307, 0.5558, 0.5825, 0.5672, 0.5944,
+    0.6887, 0.7288, 0.6198, 0.6490, 0.6315, 0.6614, 0.6295, 0.6595, 0.3317, 0.5579, 0.5845, 0.6752,
+    0.7141, 0.6088, 0.6373, 0.6205, 0.6497, 0.6323, 0.6623, 0.3339, 0.6651, 0.7031, 0.6009, 0.6289,
+    0.6126, 0.6414, 0.6244, 0.6539, 0.3532, 0.6730, 0.7112, 0.6879, 0.7274, 0.7029, 0.7438, 0.4746,
+    0.6312, 0.6602, 0.6448, 0.6745, 0.4845, 0.6463, 0.6762, 0.4951,
+    0.5287, 0.5592, 0.5312, 0.5620, 0.5323, 0.5634, 0.2819, 0.5273, 0.5595, 0.5232, 0.5551, 0.5406,
+    0.5736, 0.5541, 0.5879, 0.5662, 0.6009, 0.5662, 0.6007, 0.7062, 0.7480, 0.6040, 0.6418, 0.6051,
+    0.6431, 0.6063, 0.6445, 0.2822, 0.5113, 0.5422, 0.5287, 0.5607, 0.5422, 0.5750, 0.5543, 0.5880,
+    0.5663, 0.6008, 0.7026, 0.7441, 0.6009, 0.6384, 0.6019, 0.6397, 0.6031, 0.6410, 0.2791, 0.5127,
+    0.5435, 0.5266, 0.5582, 0.5387, 0.5712, 0.5507, 0.5840, 0.7007, 0.7420, 0.5969, 0.6341, 0.5980,
+    0.6354, 0.5991, 0.6367, 0.2888, 0.5241, 0.5556, 0.5329, 0.5649, 0.5448, 0.5777, 0.6868, 0.7271,
+    0.6009, 0.6384, 0.5991, 0.6365, 0.6002, 0.6379, 0.2953, 0.5277, 0.5594, 0.5397, 0.5722, 0.6761,
+    0.7156, 0.5939, 0.6310, 0.6059, 0.6439, 0.6038, 0.6417, 0.2963, 0.5302, 0.5620, 0.6616, 0.6999,
+    0.5826, 0.6189, 0.5946, 0.6318, 0.6067, 0.6448, 0.2985, 0.6507, 0.6881, 0.5746, 0.6104, 0.5867,
+    0.6233, 0.5988, 0.6363, 0.3137, 0.6589, 0.6989, 0.6750, 0.7163, 0.6911, 0.7337, 0.4479, 0.6070,
+    0.6446, 0.6208, 0.6594, 0.4584, 0.6223, 0.6611, 0.4695,
+    0.5250, 0.5287, 0.5571, 0.5314, 0.5601, 0.3142, 0.5545, 0.5814, 0.5515, 0.5782, 0.5645, 0.5920,
+    0.5780, 0.6062, 0.5895, 0.6181, 0.5894, 0.6178, 0.5925, 0.6211, 0.7177, 0.7603, 0.6298, 0.6597,
+    0.6310, 0.6612, 0.3145, 0.5400, 0.5661, 0.5531, 0.5799, 0.5665, 0.5941, 0.5780, 0.6060, 0.5894,
+    0.6179, 0.5895, 0.6179, 0.7145, 0.7568, 0.6268, 0.6565, 0.6280, 0.6579, 0.3153, 0.5416, 0.5679,
+    0.5551, 0.5820, 0.5666, 0.5939, 0.5780, 0.6058, 0.5897, 0.6180, 0.7113, 0.7532, 0.6239, 0.6534,
+    0.6251, 0.6548, 0.3193, 0.5442, 0.5708, 0.5530, 0.5798, 0.5644, 0.5917, 0.5761, 0.6039, 0.7104,
+    0.7524, 0.6214, 0.6509, 0.6226, 0.6523, 0.3284, 0.5499, 0.5766, 0.5614, 0.5885, 0.5731, 0.6006,
+    0.7006, 0.7418, 0.6280, 0.6578, 0.6264, 0.6562, 0.3299, 0.5525, 0.5790, 0.5642, 0.5911, 0.6871,
+    0.7271, 0.6174, 0.6465, 0.6291, 0.6591, 0.3321, 0.5580, 0.5846, 0.6770, 0.7161, 0.6095, 0.6381,
+    0.6213, 0.6507, 0.3349, 0.6655, 0.7035, 0.5987, 0.6266, 0.6104, 0.6392, 0.3572, 0.6894, 0.7291,
+    0.7044, 0.7454, 0.4813, 0.6455, 0.6754, 0.4919,
+    0.5034, 0.5321, 0.5061, 0.5352, 0.2779, 0.5263, 0.5585, 0.5232, 0.5551, 0.5364, 0.5692, 0.5503,
+    0.5839, 0.5624, 0.5969, 0.5624, 0.5967, 0.5659, 0.6004, 0.7077, 0.7497, 0.6042, 0.6422, 0.6053,
+    0.6435, 0.2782, 0.5113, 0.5422, 0.5245, 0.5563, 0.5384, 0.5710, 0.5505, 0.5840, 0.5625, 0.5968,
+    0.5627, 0.5969, 0.7042, 0.7459, 0.6010, 0.6387, 0.6022, 0.6401, 0.2791, 0.5127, 0.5435, 0.5266,
+    0.5582, 0.5387, 0.5712, 0.5506, 0.5840, 0.5628, 0.5970, 0.7007, 0.7421, 0.5979, 0.6353, 0.5991,
+    0.6367, 0.2831, 0.5151, 0.5460, 0.5243, 0.5558, 0.5362, 0.5686, 0.5484, 0.5817, 0.6996, 0.7410,
+    0.5952, 0.6324, 0.5963, 0.6338, 0.2928, 0.5213, 0.5527, 0.5333, 0.5654, 0.5455, 0.5785, 0.6889,
+    0.7295, 0.6020, 0.6398, 0.6003, 0.6381, 0.2944, 0.5242, 0.5556, 0.5364, 0.5687, 0.6744, 0.7138,
+    0.5911, 0.6281, 0.6032, 0.6411, 0.2966, 0.5302, 0.5620, 0.6635, 0.7020, 0.5832, 0.6196, 0.5953,
+    0.6326, 0.2995, 0.6510, 0.6884, 0.5722, 0.6078, 0.5843, 0.6208, 0.3179, 0.6765, 0.7180, 0.6927,
+    0.7354, 0.4548, 0.6214, 0.6601, 0.4660,
+    0.5251, 0.5283, 0.5568, 0.3106, 0.5537, 0.5806, 0.5507, 0.5774, 0.5645, 0.5920, 0.5742, 0.6022,
+    0.5860, 0.6145, 0.5859, 0.6143, 0.5891, 0.6176, 0.5926, 0.6213, 0.7192, 0.7620, 0.6302, 0.6603,
+    0.3108, 0.5392, 0.5653, 0.5531, 0.5799, 0.5627, 0.5901, 0.5745, 0.6024, 0.5860, 0.6144, 0.5861,
+    0.6144, 0.5896, 0.6181, 0.7159, 0.7584, 0.6272, 0.6571, 0.3117, 0.5416, 0.5679, 0.5513, 0.5781,
+    0.5631, 0.5904, 0.5745, 0.6023, 0.5862, 0.6145, 0.5866, 0.6149, 0.7127, 0.7549, 0.6243, 0.6540,
+    0.3192, 0.5442, 0.5708, 0.5530, 0.5798, 0.5644, 0.5917, 0.5761, 0.6039, 0.5880, 0.6165, 0.7104,
+    0.7524, 0.6226, 0.6523, 0.3232, 0.5416, 0.5681, 0.5535, 0.5804, 0.5651, 0.5926, 0.5771, 0.6052,
+    0.7125, 0.7548, 0.6228, 0.6526, 0.3276, 0.5467, 0.5731, 0.5583, 0.5852, 0.5703, 0.5978, 0.6990,
+    0.7401, 0.6256, 0.6554, 0.3303, 0.5526, 0.5790, 0.5646, 0.5917, 0.6889, 0.7291, 0.6181, 0.6475,
+    0.3331, 0.5558, 0.5822, 0.6774, 0.7165, 0.6073, 0.6359, 0.3391, 0.6812, 0.7206, 0.6108, 0.6397,
+    0.3619, 0.7059, 0.7470, 0.4886,
+    0.5028, 0.5317, 0.2740, 0.5254, 0.5575, 0.5222, 0.5541, 0.5364, 0.5691, 0.5461, 0.5795, 0.5586,
+    0.5929, 0.5586, 0.5927, 0.5620, 0.5964, 0.5657, 0.6003, 0.7092, 0.7514, 0.6044, 0.6426, 0.2742,
+    0.5103, 0.5412, 0.5245, 0.5563, 0.5342, 0.5666, 0.5467, 0.5800, 0.5586, 0.5928, 0.5589, 0.5929,
+    0.5626, 0.5969, 0.7057, 0.7476, 0.6012, 0.6391, 0.2751, 0.5126, 0.5434, 0.5224, 0.5538, 0.5349,
+    0.5672, 0.5468, 0.5800, 0.5590, 0.5930, 0.5595, 0.5935, 0.7022, 0.7438, 0.5982, 0.6357, 0.2831,
+    0.5150, 0.5460, 0.5242, 0.5558, 0.5362, 0.5686, 0.5484, 0.5816, 0.5608, 0.5951, 0.6995, 0.7410,
+    0.5963, 0.6338, 0.2871, 0.5123, 0.5431, 0.5246, 0.5563, 0.5368, 0.5694, 0.5493, 0.5828, 0.7017,
+    0.7434, 0.5963, 0.6340, 0.2918, 0.5178, 0.5488, 0.5300, 0.5619, 0.5424, 0.5753, 0.6871, 0.7276,
+    0.5993, 0.6370, 0.2947, 0.5242, 0.5556, 0.5367, 0.5691, 0.6763, 0.7159, 0.5917, 0.6289, 0.2976,
+    0.5278, 0.5594, 0.6638, 0.7023, 0.5808, 0.6171, 0.3039, 0.6680, 0.7068, 0.5845, 0.6212, 0.3229,
+    0.6943, 0.7371, 0.4624,
+    0.5251, 0.3070, 0.5528, 0.5797, 0.5498, 0.5765, 0.5637, 0.5912, 0.5742, 0.6022, 0.5822, 0.6106,
+    0.5825, 0.6108, 0.5856, 0.6140, 0.5891, 0.6177, 0.5926, 0.6215, 0.7206, 0.7637, 0.3072, 0.5383,
+    0.5644, 0.5522, 0.5791, 0.5627, 0.5901, 0.5707, 0.5985, 0.5825, 0.6108, 0.5826, 0.6108, 0.5861,
+    0.6145, 0.5896, 0.6183, 0.7174, 0.7601, 0.3081, 0.5408, 0.5670, 0.5513, 0.5781, 0.5593, 0.5864,
+    0.5711, 0.5988, 0.5828, 0.6109, 0.5832, 0.6114, 0.5867, 0.6152, 0.7142, 0.7566, 0.3156, 0.5442,
+    0.5708, 0.5491, 0.5759, 0.5609, 0.5882, 0.5726, 0.6003, 0.5846, 0.6130, 0.5851, 0.6135, 0.7118,
+    0.7541, 0.3232, 0.5416, 0.5681, 0.5535, 0.5804, 0.5651, 0.5926, 0.5771, 0.6052, 0.5891, 0.6179,
+    0.7125, 0.7549, 0.3224, 0.5384, 0.5646, 0.5504, 0.5772, 0.5624, 0.5898, 0.5744, 0.6025, 0.7109,
+    0.7531, 0.3280, 0.5468, 0.5731, 0.5587, 0.5858, 0.5708, 0.5985, 0.7008, 0.7422, 0.3313, 0.5503,
+    0.5767, 0.5624, 0.5894, 0.6893, 0.7295, 0.3373, 0.5659, 0.5932, 0.6931, 0.7337, 0.3440, 0.6970,
+    0.7379, 0.3674,
+    0.2699, 0.5244, 0.5566, 0.5213, 0.5531, 0.5354, 0.5681, 0.5460, 0.5794, 0.5543, 0.5884, 0.5547,
+    0.5887, 0.5582, 0.5923, 0.5619, 0.5963, 0.5656, 0.6004, 0.7107, 0.7532, 0.2702, 0.5094, 0.5402,
+    0.5235, 0.5553, 0.5341, 0.5666, 0.5424, 0.5756, 0.5548, 0.5888, 0.5550, 0.5889, 0.5587, 0.5929,
+    0.5624, 0.5970, 0.7072, 0.7493, 0.2711, 0.5116, 0.5424, 0.5223, 0.5537, 0.5306, 0.5627, 0.5430,
+    0.5759, 0.5552, 0.5890, 0.5556, 0.5895, 0.5594, 0.5936, 0.7037, 0.7455, 0.2791, 0.5149, 0.5459,
+    0.5200, 0.5513, 0.5323, 0.5646, 0.5445, 0.5776, 0.5570, 0.5911, 0.5575, 0.5916, 0.7010, 0.7427,
+    0.2871, 0.5122, 0.5430, 0.5245, 0.5563, 0.5368, 0.5693, 0.5492, 0.5828, 0.5617, 0.5963, 0.7016,
+    0.7434, 0.2862, 0.5087, 0.5392, 0.5213, 0.5527, 0.5338, 0.5662, 0.5462, 0.5797, 0.6999, 0.7415,
+    0.2921, 0.5178, 0.5488, 0.5302, 0.5622, 0.5427, 0.5757, 0.6890, 0.7297, 0.2956, 0.5218, 0.5531,
+    0.5342, 0.5666, 0.6765, 0.7162, 0.3019, 0.5380, 0.5706, 0.6807, 0.7207, 0.3089, 0.6849, 0.7252,
+    0.3285,
+    0.8417, 0.8918, 0.8377, 0.8874, 0.8554, 0.9065, 0.8686, 0.9208, 0.8817, 0.9350, 0.8778, 0.9307,
+    0.8775, 0.9304, 0.8815, 0.9348, 0.8855, 0.9392, 0.8895, 0.9436, 0.8197, 0.8167, 0.8577, 0.8193,
+    0.8604, 0.8207, 0.8618, 0.8246, 0.8658, 0.8365, 0.8785, 0.8322, 0.8739, 0.8362, 0.8783, 0.8403,
+    0.8827, 0.8443, 0.8871, 0.8157, 0.8029, 0.8429, 0.8042, 0.8442, 0.8081, 0.8482, 0.8200, 0.8610,
+    0.8322, 0.8739, 0.8322, 0.8739, 0.8362, 0.8783, 0.8403, 0.8828, 0.8119, 0.7899, 0.8291, 0.7938,
+    0.8330, 0.8052, 0.8452, 0.8174, 0.8581, 0.8338, 0.8757, 0.8338, 0.8757, 0.8379, 0.8802, 0.8087,
+    0.7826, 0.8211, 0.7907, 0.8297, 0.8028, 0.8426, 0.8192, 0.8602, 0.8357, 0.8778, 0.8357, 0.8778,
+    0.8092, 0.7794, 0.8178, 0.7916, 0.8307, 0.8080, 0.8483, 0.8245, 0.8659, 0.8409, 0.8835, 0.8053,
+    0.7751, 0.8132, 0.7916, 0.8308, 0.8080, 0.8483, 0.8245, 0.8659, 0.8052, 0.7749, 0.8130, 0.7913,
+    0.8306, 0.8078, 0.8481, 0.8096, 0.7954, 0.8350, 0.8119, 0.8526, 0.8140, 0.8159, 0.8570, 0.8185,
+    0.5249, 0.6980, 0.7379, 0.6997, 0.7402, 0.7004, 0.7413, 0.7041, 0.7457, 0.7016, 0.7428, 0.6988,
+    0.7398, 0.7030, 0.7443, 0.7072, 0.7489, 0.7115, 0.7535, 0.3699, 0.7030, 0.7431, 0.7040, 0.7446,
+    0.7076, 0.7489, 0.7218, 0.7646, 0.7194, 0.7619, 0.7168, 0.7590, 0.7212, 0.7638, 0.7257, 0.7686,
+    0.7302, 0.7735, 0.4699, 0.6447, 0.6735, 0.6518, 0.6810, 0.6610, 0.6907, 0.6695, 0.6996, 0.6784,
+    0.7089, 0.6787, 0.7093, 0.6822, 0.7131, 0.6857, 0.7169, 0.4809, 0.6343, 0.6629, 0.6435, 0.6726,
+    0.6516, 0.6812, 0.6600, 0.6900, 0.6726, 0.7032, 0.6730, 0.7036, 0.6765, 0.7074, 0.4905, 0.6332,
+    0.6620, 0.6388, 0.6680, 0.6447, 0.6741, 0.6572, 0.6872, 0.6697, 0.7005, 0.6702, 0.7011, 0.5038,
+    0.6374, 0.6668, 0.6344, 0.6636, 0.6469, 0.6767, 0.6594, 0.6900, 0.6721, 0.7034, 0.5057, 0.6225,
+    0.6511, 0.6350, 0.6643, 0.6476, 0.6775, 0.6602, 0.6909, 0.5085, 0.6205, 0.6489, 0.6330, 0.6621,
+    0.6457, 0.6755, 0.5136, 0.6365, 0.6658, 0.6492, 0.6792, 0.5192, 0.6527, 0.6830, 0.5255,
+    0.6869, 0.7278, 0.6881, 0.7296, 0.6883, 0.7301, 0.6918, 0.7342, 0.6890, 0.7310, 0.6860, 0.7277,
+    0.6905, 0.7326, 0.6950, 0.7375, 0.6996, 0.7424, 0.3327, 0.6936, 0.7356, 0.6940, 0.7364, 0.6974,
+    0.7404, 0.7122, 0.7567, 0.7096, 0.7538, 0.7068, 0.7506, 0.7115, 0.7557, 0.7163, 0.7609, 0.7212,
+    0.7661, 0.4424, 0.6214, 0.6588, 0.6285, 0.6664, 0.6380, 0.6764, 0.6465, 0.6855, 0.6559, 0.6955,
+    0.6563, 0.6959, 0.6599, 0.6999, 0.6637, 0.7040, 0.4545, 0.6101, 0.6470, 0.6195, 0.6569, 0.6277,
+    0.6656, 0.6365, 0.6751, 0.6495, 0.6891, 0.6499, 0.6896, 0.6537, 0.6937, 0.4650, 0.6085, 0.6455,
+    0.6142, 0.6515, 0.6203, 0.6580, 0.6333, 0.6720, 0.6464, 0.6861, 0.6469, 0.6866, 0.4793, 0.6125,
+    0.6501, 0.6094, 0.6466, 0.6224, 0.6606, 0.6355, 0.6747, 0.6486, 0.6888, 0.4814, 0.5971, 0.6333,
+    0.6101, 0.6473, 0.6232, 0.6614, 0.6363, 0.6756, 0.4844, 0.5949, 0.6309, 0.6080, 0.6450, 0.6211,
+    0.6592, 0.4897, 0.6117, 0.6490, 0.6248, 0.6632, 0.4957, 0.6286, 0.6672, 0.5023,
+    0.5249, 0.6908, 0.7307, 0.6921, 0.7324, 0.6931, 0.7338, 0.6905, 0.7309, 0.6914, 0.7320, 0.6922,
+    0.7329, 0.6964, 0.7374, 0.7006, 0.7420, 0.3275, 0.7017, 0.7417, 0.6296, 0.6583, 0.6383, 0.6674,
+    0.6471, 0.6767, 0.6556, 0.6857, 0.6537, 0.6837, 0.6541, 0.6841, 0.6575, 0.6879, 0.6611, 0.6917,
+    0.3728, 0.6921, 0.7317, 0.6957, 0.7359, 0.7099, 0.7516, 0.7075, 0.7489, 0.7187, 0.7611, 0.7198,
+    0.7622, 0.7242, 0.7670, 0.7287, 0.7719, 0.4801, 0.6358, 0.6643, 0.6447, 0.6737, 0.6528, 0.6822,
+    0.6631, 0.6931, 0.6754, 0.7061, 0.6758, 0.7065, 0.6793, 0.7104, 0.4898, 0.6317, 0.6603, 0.6374,
+    0.6663, 0.6477, 0.6772, 0.6595, 0.6896, 0.6721, 0.7029, 0.6726, 0.7035, 0.5026, 0.6271, 0.6558,
+    0.6374, 0.6667, 0.6466, 0.6764, 0.6592, 0.6896, 0.6718, 0.7030, 0.5045, 0.6256, 0.6542, 0.6348,
+    0.6639, 0.6473, 0.6771, 0.6600, 0.6905, 0.5127, 0.6243, 0.6530, 0.6369, 0.6662, 0.6495, 0.6796,
+    0.5168, 0.6374, 0.6667, 0.6500, 0.6800, 0.5225, 0.6536, 0.6838, 0.5287,
+    0.6788, 0.7196, 0.6796, 0.7209, 0.6802, 0.7217, 0.6773, 0.7186, 0.6782, 0.7196, 0.6791, 0.7204,
+    0.6836, 0.7253, 0.6881, 0.7302, 0.2917, 0.6922, 0.7317, 0.6050, 0.6419, 0.6139, 0.6513, 0.6229,
+    0.6608, 0.6315, 0.6699, 0.6295, 0.6679, 0.6299, 0.6683, 0.6335, 0.6723, 0.6373, 0.6763, 0.3357,
+    0.6812, 0.7226, 0.6846, 0.7265, 0.6994, 0.7429, 0.6968, 0.7399, 0.7088, 0.7528, 0.7099, 0.7540,
+    0.7147, 0.7591, 0.7196, 0.7644, 0.4537, 0.6119, 0.6490, 0.6210, 0.6587, 0.6292, 0.6674, 0.6398,
+    0.6785, 0.6526, 0.6924, 0.6531, 0.6929, 0.6568, 0.6970, 0.4643, 0.6073, 0.6442, 0.6130, 0.6502,
+    0.6236, 0.6614, 0.6359, 0.6747, 0.6490, 0.6888, 0.6495, 0.6894, 0.4781, 0.6021, 0.6389, 0.6127,
+    0.6500, 0.6223, 0.6603, 0.6353, 0.6744, 0.6484, 0.6886, 0.4802, 0.6004, 0.6367, 0.6100, 0.6471,
+    0.6230, 0.6611, 0.6361, 0.6753, 0.4889, 0.5990, 0.6354, 0.6121, 0.6495, 0.6252, 0.6636, 0.4932,
+    0.6126, 0.6499, 0.6257, 0.6641, 0.4992, 0.6295, 0.6682, 0.5058,
+    0.5249, 0.6723, 0.7113, 0.6740, 0.7134, 0.6714, 0.7105, 0.6693, 0.7084, 0.6738, 0.7133, 0.6746,
+    0.7142, 0.6788, 0.7188, 0.3213, 0.5701, 0.5969, 0.7015, 0.7420, 0.6267, 0.6556, 0.6356, 0.6649,
+    0.6440, 0.6738, 0.6390, 0.6686, 0.6432, 0.6732, 0.6436, 0.6737, 0.6472, 0.6775, 0.3203, 0.6878,
+    0.7271, 0.6155, 0.6437, 0.6243, 0.6529, 0.6328, 0.6619, 0.6411, 0.6707, 0.6426, 0.6724, 0.6430,
+    0.6728, 0.6465, 0.6767, 0.3603, 0.6822, 0.7213, 0.6964, 0.7370, 0.6940, 0.7343, 0.7052, 0.7465,
+    0.7201, 0.7626, 0.7212, 0.7638, 0.7257, 0.7687, 0.4742, 0.6278, 0.6562, 0.6335, 0.6622, 0.6435,
+    0.6728, 0.6572, 0.6873, 0.6696, 0.7003, 0.6700, 0.7009, 0.4870, 0.6205, 0.6489, 0.6306, 0.6595,
+    0.6443, 0.6740, 0.6562, 0.6865, 0.6688, 0.6999, 0.4889, 0.6187, 0.6470, 0.6325, 0.6615, 0.6443,
+    0.6740, 0.6570, 0.6874, 0.4967, 0.6215, 0.6501, 0.6308, 0.6598, 0.6434, 0.6732, 0.5062, 0.6354,
+    0.6648, 0.6480, 0.6781, 0.5108, 0.6485, 0.6786, 0.5171,
+    0.6585, 0.6984, 0.6598, 0.7001, 0.6570, 0.6969, 0.6546, 0.6944, 0.6594, 0.6996, 0.6602, 0.7005,
+    0.6647, 0.7054, 0.2853, 0.5436, 0.5761, 0.6914, 0.7314, 0.6017, 0.6387, 0.6107, 0.6483, 0.6193,
+    0.6574, 0.6140, 0.6517, 0.6184, 0.6565, 0.6188, 0.6570, 0.6225, 0.6611, 0.2841, 0.6766, 0.7154,
+    0.5902, 0.6264, 0.5992, 0.6359, 0.6078, 0.6450, 0.6162, 0.6540, 0.6178, 0.6558, 0.6182, 0.6563,
+    0.6219, 0.6603, 0.3221, 0.6699, 0.7109, 0.6847, 0.7272, 0.6821, 0.7242, 0.6942, 0.7371, 0.7102,
+    0.7544, 0.7113, 0.7556, 0.7162, 0.7608, 0.4474, 0.6034, 0.6403, 0.6091, 0.6463, 0.6194, 0.6571,
+    0.6334, 0.6722, 0.6464, 0.6861, 0.6469, 0.6867, 0.4613, 0.5955, 0.6319, 0.6057, 0.6427, 0.6198,
+    0.6578, 0.6322, 0.6712, 0.6453, 0.6854, 0.4634, 0.5934, 0.6295, 0.6075, 0.6445, 0.6199, 0.6579,
+    0.6330, 0.6721, 0.4716, 0.5960, 0.6322, 0.6057, 0.6427, 0.6188, 0.6568, 0.4817, 0.6104, 0.6478,
+    0.6235, 0.6620, 0.4866, 0.6241, 0.6625, 0.4932,
+    0.5249, 0.6498, 0.6876, 0.6472, 0.6846, 0.6448, 0.6821, 0.6464, 0.6839, 0.6508, 0.6889, 0.6516,
+    0.6898, 0.3173, 0.5576, 0.5840, 0.5741, 0.6013, 0.7011, 0.7420, 0.6271, 0.6563, 0.6355, 0.6652,
+    0.6301, 0.6595, 0.6317, 0.6612, 0.6359, 0.6659, 0.6364, 0.6664, 0.3163, 0.5646, 0.5913, 0.6875,
+    0.7271, 0.6159, 0.6444, 0.6244, 0.6533, 0.6327, 0.6621, 0.6310, 0.6605, 0.6353, 0.6651, 0.6358,
+    0.6657, 0.3219, 0.6746, 0.7132, 0.6058, 0.6338, 0.6139, 0.6423, 0.6222, 0.6511, 0.6339, 0.6635,
+    0.6354, 0.6653, 0.6359, 0.6658, 0.3524, 0.6832, 0.7228, 0.6808, 0.7201, 0.6920, 0.7322, 0.7069,
+    0.7483, 0.7218, 0.7645, 0.7229, 0.7658, 0.4751, 0.6175, 0.6456, 0.6275, 0.6562, 0.6409, 0.6704,
+    0.6547, 0.6850, 0.6671, 0.6981, 0.4771, 0.6157, 0.6437, 0.6291, 0.6579, 0.6428, 0.6725, 0.6552,
+    0.6856, 0.4848, 0.6155, 0.6437, 0.6293, 0.6583, 0.6412, 0.6709, 0.4939, 0.6339, 0.6633, 0.6432,
+    0.6731, 0.5039, 0.6478, 0.6781, 0.5092,
+    0.6340, 0.6726, 0.6311, 0.6694, 0.6284, 0.6665, 0.6300, 0.6682, 0.6347, 0.6734, 0.6355, 0.6743,
+    0.2812, 0.5305, 0.5624, 0.5475, 0.5805, 0.6906, 0.7308, 0.6019, 0.6392, 0.6105, 0.6483, 0.6046,
+    0.6421, 0.6062, 0.6438, 0.6106, 0.6487, 0.6111, 0.6493, 0.2800, 0.5378, 0.5700, 0.6758, 0.7149,
+    0.5904, 0.6268, 0.5990, 0.6360, 0.6074, 0.6449, 0.6057, 0.6431, 0.6101, 0.6480, 0.6106, 0.6486,
+    0.2859, 0.6618, 0.6999, 0.5800, 0.6158, 0.5882, 0.6245, 0.5966, 0.6334, 0.6086, 0.6462, 0.6102,
+    0.6481, 0.6107, 0.6487, 0.3134, 0.6704, 0.7118, 0.6678, 0.7089, 0.6799, 0.7218, 0.6959, 0.7390,
+    0.7119, 0.7563, 0.7131, 0.7576, 0.4485, 0.5925, 0.6289, 0.6027, 0.6397, 0.6164, 0.6544, 0.6306,
+    0.6695, 0.6436, 0.6836, 0.4506, 0.5904, 0.6265, 0.6041, 0.6412, 0.6183, 0.6563, 0.6313, 0.6703,
+    0.4589, 0.5899, 0.6259, 0.6041, 0.6410, 0.6166, 0.6545, 0.4685, 0.6088, 0.6462, 0.6185, 0.6567,
+    0.4791, 0.6233, 0.6619, 0.4847,
+    0.5249, 0.6134, 0.6484, 0.6119, 0.6467, 0.6131, 0.6481, 0.6146, 0.6499, 0.6191, 0.6549, 0.3132,
+    0.5451, 0.5712, 0.5616, 0.5885, 0.5747, 0.6022, 0.7007, 0.7420, 0.6271, 0.6566, 0.6216, 0.6508,
+    0.6227, 0.6521, 0.6243, 0.6539, 0.6286, 0.6587, 0.3122, 0.5521, 0.5784, 0.5652, 0.5921, 0.6871,
+    0.7271, 0.6159, 0.6447, 0.6242, 0.6535, 0.6221, 0.6513, 0.6237, 0.6532, 0.6280, 0.6579, 0.3178,
+    0.5570, 0.5834, 0.6743, 0.7133, 0.6055, 0.6337, 0.6138, 0.6425, 0.6255, 0.6549, 0.6239, 0.6534,
+    0.6282, 0.6581, 0.3234, 0.6645, 0.7027, 0.5956, 0.6233, 0.6039, 0.6321, 0.6155, 0.6444, 0.6272,
+    0.6569, 0.6288, 0.6588, 0.3454, 0.6678, 0.7060, 0.6790, 0.7181, 0.6938, 0.7342, 0.7087, 0.7504,
+    0.7237, 0.7667, 0.4652, 0.6131, 0.6410, 0.6265, 0.6552, 0.6400, 0.6695, 0.6538, 0.6842, 0.4730,
+    0.6125, 0.6405, 0.6260, 0.6548, 0.6398, 0.6695, 0.4820, 0.6279, 0.6569, 0.6417, 0.6716, 0.4916,
+    0.6464, 0.6766, 0.5023,
+    0.5949, 0.6306, 0.5931, 0.6286, 0.5942, 0.6299, 0.5958, 0.6317, 0.6005, 0.6369, 0.2770, 0.5174,
+    0.5488, 0.5343, 0.5668, 0.5478, 0.5811, 0.6897, 0.7303, 0.6016, 0.6392, 0.5958, 0.6329, 0.5968,
+    0.6342, 0.5984, 0.6360, 0.6029, 0.6410, 0.2758, 0.5247, 0.5564, 0.5382, 0.5707, 0.6749, 0.7143,
+    0.5901, 0.6269, 0.5986, 0.6358, 0.5963, 0.6335, 0.5979, 0.6353, 0.6024, 0.6403, 0.2817, 0.5296,
+    0.5615, 0.6610, 0.6994, 0.5794, 0.6154, 0.5878, 0.6244, 0.5998, 0.6372, 0.5981, 0.6355, 0.6025,
+    0.6405, 0.2875, 0.6503, 0.6879, 0.5691, 0.6045, 0.5775, 0.6134, 0.5895, 0.6262, 0.6015, 0.6392,
+    0.6032, 0.6411, 0.3056, 0.6537, 0.6937, 0.6657, 0.7066, 0.6817, 0.7238, 0.6978, 0.7411, 0.7139,
+    0.7585, 0.4379, 0.5879, 0.6241, 0.6017, 0.6388, 0.6155, 0.6535, 0.6297, 0.6687, 0.4461, 0.5870,
+    0.6229, 0.6008, 0.6377, 0.6150, 0.6529, 0.4557, 0.6027, 0.6399, 0.6169, 0.6551, 0.4659, 0.6217,
+    0.6603, 0.4772,
+    0.5249, 0.5811, 0.6135, 0.5833, 0.6160, 0.5845, 0.6174, 0.5861, 0.6192, 0.3130, 0.5396, 0.5656,
+    0.5565, 0.5833, 0.5696, 0.5970, 0.5827, 0.6108, 0.7131, 0.7554, 0.6239, 0.6532, 0.6250, 0.6544,
+    0.6262, 0.6558, 0.6279, 0.6577, 0.3154, 0.5495, 0.5757, 0.5626, 0.5895, 0.5758, 0.6033, 0.6995,
+    0.7405, 0.6265, 0.6558, 0.6248, 0.6541, 0.6259, 0.6554, 0.6276, 0.6574, 0.3215, 0.5548, 0.5813,
+    0.5680, 0.5951, 0.6862, 0.7262, 0.6165, 0.6453, 0.6282, 0.6577, 0.6261, 0.6556, 0.6278, 0.6576,
+    0.3271, 0.5625, 0.5893, 0.6735, 0.7124, 0.6067, 0.6349, 0.6183, 0.6473, 0.6300, 0.6598, 0.6285,
+    0.6583, 0.3333, 0.6637, 0.7019, 0.5995, 0.6274, 0.6112, 0.6398, 0.6229, 0.6523, 0.6347, 0.6648,
+    0.3509, 0.6671, 0.7052, 0.6819, 0.7213, 0.6968, 0.7375, 0.7118, 0.7537, 0.4722, 0.6144, 0.6424,
+    0.6279, 0.6567, 0.6414, 0.6710, 0.4812, 0.6294, 0.6583, 0.6429, 0.6727, 0.4909, 0.6449, 0.6749,
+    0.5011,
+    0.5601, 0.5931, 0.5624, 0.5956, 0.5636, 0.5970, 0.5651, 0.5987, 0.2767, 0.5112, 0.5422, 0.5286,
+    0.5607, 0.5421, 0.5750, 0.5557, 0.5894, 0.7031, 0.7448, 0.5982, 0.6355, 0.5993, 0.6367, 0.6004,
+    0.6380, 0.6021, 0.6399, 0.2793, 0.5216, 0.5530, 0.5351, 0.5673, 0.5487, 0.5817, 0.6884, 0.7289,
+    0.6010, 0.6384, 0.5992, 0.6364, 0.6003, 0.6377, 0.6019, 0.6397, 0.2857, 0.5271, 0.5587, 0.5406,
+    0.5731, 0.6740, 0.7133, 0.5907, 0.6273, 0.6026, 0.6402, 0.6004, 0.6379, 0.6021, 0.6399, 0.2916,
+    0.5350, 0.5672, 0.6601, 0.6984, 0.5805, 0.6165, 0.5925, 0.6294, 0.6045, 0.6423, 0.6029, 0.6407,
+    0.2980, 0.6495, 0.6871, 0.5732, 0.6088, 0.5851, 0.6216, 0.5972, 0.6346, 0.6093, 0.6475, 0.3115,
+    0.6529, 0.6927, 0.6689, 0.7100, 0.6849, 0.7273, 0.7010, 0.7446, 0.4454, 0.5893, 0.6255, 0.6032,
+    0.6403, 0.6171, 0.6552, 0.4550, 0.6046, 0.6419, 0.6185, 0.6568, 0.4652, 0.6205, 0.6590, 0.4760,
+    0.5250, 0.5520, 0.5821, 0.5544, 0.5848, 0.5556, 0.5863, 0.3169, 0.5402, 0.5662, 0.5570, 0.5839,
+    0.5701, 0.5976, 0.5833, 0.6114, 0.5948, 0.6234, 0.7114, 0.7536, 0.6285, 0.6580, 0.6296, 0.6593,
+    0.6308, 0.6607, 0.3141, 0.5418, 0.5679, 0.5553, 0.5820, 0.5684, 0.5958, 0.5799, 0.6078, 0.7097,
+    0.7516, 0.6247, 0.6540, 0.6259, 0.6553, 0.6271, 0.6568, 0.3231, 0.5496, 0.5759, 0.5627, 0.5897,
+    0.5738, 0.6012, 0.6964, 0.7373, 0.6281, 0.6576, 0.6264, 0.6559, 0.6276, 0.6574, 0.3292, 0.5576,
+    0.5843, 0.5660, 0.5930, 0.6837, 0.7235, 0.6186, 0.6476, 0.6303, 0.6601, 0.6283, 0.6581, 0.3354,
+    0.5606, 0.5873, 0.6739, 0.7130, 0.6115, 0.6401, 0.6232, 0.6526, 0.6350, 0.6651, 0.3369, 0.6605,
+    0.6984, 0.6005, 0.6285, 0.6122, 0.6409, 0.6240, 0.6535, 0.3559, 0.6681, 0.7062, 0.6830, 0.7224,
+    0.6979, 0.7386, 0.4770, 0.6308, 0.6597, 0.6444, 0.6741, 0.4866, 0.6459, 0.6758, 0.4968,
+    0.5287, 0.5592, 0.5312, 0.5620, 0.5323, 0.5634, 0.2810, 0.5118, 0.5429, 0.5292, 0.5613, 0.5427,
+    0.5757, 0.5563, 0.5900, 0.5685, 0.6031, 0.7013, 0.7428, 0.6030, 0.6407, 0.6041, 0.6420, 0.6053,
+    0.6433, 0.2779, 0.5132, 0.5441, 0.5271, 0.5589, 0.5407, 0.5733, 0.5528, 0.5863, 0.6994, 0.7407,
+    0.5991, 0.6364, 0.6002, 0.6376, 0.6013, 0.6390, 0.2875, 0.5213, 0.5526, 0.5349, 0.5670, 0.5465,
+    0.5794, 0.6849, 0.7252, 0.6025, 0.6401, 0.6007, 0.6383, 0.6019, 0.6396, 0.2939, 0.5297, 0.5615,
+    0.5386, 0.5709, 0.6711, 0.7103, 0.5928, 0.6297, 0.6048, 0.6426, 0.6027, 0.6404, 0.3004, 0.5330,
+    0.5651, 0.6605, 0.6989, 0.5854, 0.6220, 0.5975, 0.6349, 0.6096, 0.6479, 0.3018, 0.6460, 0.6832,
+    0.5742, 0.6099, 0.5863, 0.6229, 0.5984, 0.6358, 0.3167, 0.6539, 0.6938, 0.6699, 0.7111, 0.6860,
+    0.7284, 0.4505, 0.6066, 0.6441, 0.6205, 0.6590, 0.4607, 0.6220, 0.6607, 0.4715,
+    0.5250, 0.5287, 0.5571, 0.5314, 0.5601, 0.3132, 0.5402, 0.5662, 0.5532, 0.5799, 0.5667, 0.5941,
+    0.5799, 0.6079, 0.5914, 0.6199, 0.5915, 0.6199, 0.7129, 0.7552, 0.6288, 0.6585, 0.6300, 0.6599,
+    0.3141, 0.5418, 0.5679, 0.5553, 0.5820, 0.5684, 0.5958, 0.5799, 0.6078, 0.5916, 0.6200, 0.7097,
+    0.7517, 0.6258, 0.6553, 0.6271, 0.6568, 0.3179, 0.5413, 0.5675, 0.5548, 0.5817, 0.5658, 0.5932,
+    0.5775, 0.6053, 0.7083, 0.7503, 0.6229, 0.6523, 0.6241, 0.6537, 0.3269, 0.5518, 0.5784, 0.5602,
+    0.5871, 0.5719, 0.5993, 0.6956, 0.7365, 0.6268, 0.6565, 0.6252, 0.6549, 0.3336, 0.5552, 0.5818,
+    0.5668, 0.5940, 0.6858, 0.7260, 0.6200, 0.6493, 0.6318, 0.6619, 0.3351, 0.5577, 0.5841, 0.6724,
+    0.7113, 0.6091, 0.6377, 0.6209, 0.6503, 0.3377, 0.6609, 0.6987, 0.5983, 0.6262, 0.6100, 0.6387,
+    0.3596, 0.6845, 0.7240, 0.6994, 0.7403, 0.4834, 0.6451, 0.6750, 0.4936,
+    0.5034, 0.5321, 0.5061, 0.5352, 0.2770, 0.5118, 0.5428, 0.5250, 0.5569, 0.5389, 0.5717, 0.5525,
+    0.5861, 0.5647, 0.5991, 0.5649, 0.5992, 0.7028, 0.7445, 0.6032, 0.6410, 0.6044, 0.6424, 0.2779,
+    0.5132, 0.5441, 0.5271, 0.5588, 0.5407, 0.5732, 0.5528, 0.5862, 0.5650, 0.5993, 0.6993, 0.7407,
+    0.6001, 0.6376, 0.6013, 0.6390, 0.2818, 0.5123, 0.5431, 0.5263, 0.5579, 0.5379, 0.5703, 0.5501,
+    0.5834, 0.6977, 0.7391, 0.5968, 0.6342, 0.5980, 0.6355, 0.2914, 0.5233, 0.5547, 0.5322, 0.5641,
+    0.5444, 0.5772, 0.6839, 0.7242, 0.6009, 0.6385, 0.5992, 0.6368, 0.2984, 0.5270, 0.5587, 0.5393,
+    0.5718, 0.6733, 0.7128, 0.5940, 0.6312, 0.6061, 0.6442, 0.2999, 0.5299, 0.5616, 0.6588, 0.6971,
+    0.5828, 0.6192, 0.5949, 0.6322, 0.3025, 0.6463, 0.6836, 0.5718, 0.6074, 0.5839, 0.6204, 0.3206,
+    0.6715, 0.7128, 0.6876, 0.7301, 0.4571, 0.6210, 0.6597, 0.4679,
+    0.5251, 0.5283, 0.5568, 0.3096, 0.5393, 0.5653, 0.5532, 0.5799, 0.5628, 0.5901, 0.5764, 0.6043,
+    0.5879, 0.6163, 0.5880, 0.6163, 0.5915, 0.6200, 0.7143, 0.7568, 0.6292, 0.6591, 0.3104, 0.5418,
+    0.5679, 0.5514, 0.5781, 0.5650, 0.5923, 0.5765, 0.6043, 0.5882, 0.6164, 0.5886, 0.6169, 0.7111,
+    0.7533, 0.6262, 0.6559, 0.3179, 0.5413, 0.5675, 0.5548, 0.5817, 0.5658, 0.5932, 0.5775, 0.6053,
+    0.5895, 0.6179, 0.7083, 0.7503, 0.6241, 0.6537, 0.3217, 0.5435, 0.5700, 0.5523, 0.5791, 0.5640,
+    0.5913, 0.5759, 0.6039, 0.7075, 0.7495, 0.6217, 0.6513, 0.3313, 0.5493, 0.5759, 0.5610, 0.5881,
+    0.5730, 0.6007, 0.6977, 0.7390, 0.6283, 0.6583, 0.3333, 0.5522, 0.5786, 0.5642, 0.5912, 0.6843,
+    0.7244, 0.6177, 0.6470, 0.3359, 0.5554, 0.5818, 0.6728, 0.7117, 0.6069, 0.6355, 0.3416, 0.6766,
+    0.7159, 0.6104, 0.6393, 0.3641, 0.7009, 0.7419, 0.4904,
+    0.5028, 0.5317, 0.2730, 0.5109, 0.5419, 0.5250, 0.5569, 0.5347, 0.5672, 0.5487, 0.5821, 0.5608,
+    0.5951, 0.5611, 0.5952, 0.5648, 0.5992, 0.7043, 0.7463, 0.6034, 0.6414, 0.2739, 0.5131, 0.5441,
+    0.5229, 0.5544, 0.5369, 0.5692, 0.5490, 0.5823, 0.5612, 0.5953, 0.5617, 0.5958, 0.7009, 0.7425,
+    0.6003, 0.6380, 0.2818, 0.5122, 0.5430, 0.5262, 0.5578, 0.5378, 0.5703, 0.5501, 0.5834, 0.5625,
+    0.5968, 0.6977, 0.7391, 0.5979, 0.6355, 0.2857, 0.5143, 0.5451, 0.5235, 0.5550, 0.5357, 0.5681,
+    0.5482, 0.5815, 0.6966, 0.7381, 0.5952, 0.6327, 0.2959, 0.5206, 0.5519, 0.5329, 0.5650, 0.5453,
+    0.5784, 0.6860, 0.7267, 0.6022, 0.6401, 0.2979, 0.5239, 0.5552, 0.5363, 0.5686, 0.6716, 0.7110,
+    0.5914, 0.6285, 0.3006, 0.5274, 0.5590, 0.6591, 0.6974, 0.5804, 0.6167, 0.3066, 0.6632, 0.7019,
+    0.5841, 0.6207, 0.3252, 0.6892, 0.7318, 0.4643,
+    0.5251, 0.3060, 0.5385, 0.5645, 0.5524, 0.5791, 0.5628, 0.5901, 0.5726, 0.6004, 0.5845, 0.6128,
+    0.5846, 0.6128, 0.5880, 0.6165, 0.5916, 0.6203, 0.7158, 0.7585, 0.3068, 0.5409, 0.5670, 0.5514,
+    0.5781, 0.5611, 0.5883, 0.5730, 0.6007, 0.5847, 0.6129, 0.5851, 0.6133, 0.5886, 0.6171, 0.7126,
+    0.7550, 0.3143, 0.5413, 0.5675, 0.5510, 0.5778, 0.5624, 0.5896, 0.5741, 0.6018, 0.5860, 0.6144,
+    0.5865, 0.6149, 0.7098, 0.7520, 0.3217, 0.5435, 0.5700, 0.5523, 0.5791, 0.5640, 0.5912, 0.5759,
+    0.6039, 0.5880, 0.6166, 0.7075, 0.7496, 0.3261, 0.5410, 0.5675, 0.5531, 0.5800, 0.5651, 0.5926,
+    0.5771, 0.6053, 0.7096, 0.7520, 0.3310, 0.5464, 0.5727, 0.5583, 0.5853, 0.5704, 0.5980, 0.6962,
+    0.7374, 0.3341, 0.5500, 0.5763, 0.5620, 0.5890, 0.6847, 0.7248, 0.3398, 0.5655, 0.5928, 0.6885,
+    0.7289, 0.3461, 0.6924, 0.7332, 0.3692,
+    0.2690, 0.5099, 0.5409, 0.5240, 0.5559, 0.5346, 0.5672, 0.5444, 0.5776, 0.5570, 0.5911, 0.5572,
+    0.5912, 0.5609, 0.5952, 0.5646, 0.5992, 0.7058, 0.7480, 0.2699, 0.5121, 0.5431, 0.5228, 0.5544,
+    0.5326, 0.5648, 0.5452, 0.5782, 0.5574, 0.5913, 0.5578, 0.5918, 0.5615, 0.5959, 0.7023, 0.7442,
+    0.2778, 0.5122, 0.5430, 0.5220, 0.5534, 0.5340, 0.5663, 0.5462, 0.5794, 0.5586, 0.5928, 0.5591,
+    0.5933, 0.6992, 0.7408, 0.2856, 0.5142, 0.5451, 0.5234, 0.5550, 0.5357, 0.5680, 0.5481, 0.5815,
+    0.5606, 0.5950, 0.6966, 0.7381, 0.2902, 0.5115, 0.5423, 0.5242, 0.5558, 0.5366, 0.5693, 0.5491,
+    0.5828, 0.6988, 0.7406, 0.2954, 0.5174, 0.5484, 0.5298, 0.5618, 0.5423, 0.5753, 0.6843, 0.7249,
+    0.2986, 0.5214, 0.5526, 0.5339, 0.5661, 0.6718, 0.7113, 0.3046, 0.5376, 0.5702, 0.6760, 0.7158,
+    0.3113, 0.6802, 0.7204, 0.3305,
+    0.8218, 0.8703, 0.8394, 0.8893, 0.8525, 0.9035, 0.8657, 0.9177, 0.8789, 0.9320, 0.8746, 0.9273,
+    0.8786, 0.9317, 0.8826, 0.9361, 0.8866, 0.9405, 0.8199, 0.8170, 0.8580, 0.8179, 0.8589, 0.8193,
+    0.8603, 0.8232, 0.8643, 0.8348, 0.8767, 0.8348, 0.8768, 0.8389, 0.8812, 0.8429, 0.8856, 0.8161,
+    0.8031, 0.8432, 0.8045, 0.8445, 0.8084, 0.8485, 0.8200, 0.8609, 0.8364, 0.8785, 0.8365, 0.8786,
+    0.8405, 0.8830, 0.8123, 0.7899, 0.8290, 0.7938, 0.8330, 0.8049, 0.8449, 0.8214, 0.8625, 0.8378,
+    0.8800, 0.8378, 0.8801, 0.8091, 0.7825, 0.8211, 0.7903, 0.8294, 0.8068, 0.8470, 0.8233, 0.8646,
+    0.8397, 0.8821, 0.8097, 0.7791, 0.8175, 0.7956, 0.8351, 0.8120, 0.8527, 0.8285, 0.8703, 0.8054,
+    0.7748, 0.8129, 0.7913, 0.8305, 0.8077, 0.8481, 0.8098, 0.7953, 0.8349, 0.8118, 0.8525, 0.8142,
+    0.8159, 0.8569, 0.8187,
+    0.5249, 0.6897, 0.7291, 0.6909, 0.7308, 0.6918, 0.7320, 0.6955, 0.7364, 0.6927, 0.7333, 0.6935,
+    0.7341, 0.6977, 0.7387, 0.7019, 0.7433, 0.3869, 0.7011, 0.7412, 0.7018, 0.7422, 0.7054, 0.7465,
+    0.7196, 0.7622, 0.7170, 0.7593, 0.7180, 0.7604, 0.7224, 0.7652, 0.7268, 0.7700, 0.4877, 0.6470,
+    0.6758, 0.6541, 0.6833, 0.6634, 0.6931, 0.6717, 0.7018, 0.6840, 0.7148, 0.6844, 0.7153, 0.6879,
+    0.7191, 0.4969, 0.6388, 0.6675, 0.6481, 0.6772, 0.6560, 0.6856, 0.6678, 0.6980, 0.6803, 0.7113,
+    0.6808, 0.7118, 0.5070, 0.6378, 0.6667, 0.6433, 0.6725, 0.6524, 0.6821, 0.6650, 0.6954, 0.6776,
+    0.7088, 0.5206, 0.6418, 0.6713, 0.6422, 0.6717, 0.6548, 0.6850, 0.6674, 0.6983, 0.5232, 0.6277,
+    0.6563, 0.6402, 0.6696, 0.6529, 0.6829, 0.5271, 0.6407, 0.6700, 0.6533, 0.6834, 0.5325, 0.6569,
+    0.6872, 0.5384,
+    0.6781, 0.7184, 0.6789, 0.7196, 0.6793, 0.7203, 0.6827, 0.7244, 0.6797, 0.7210, 0.6805, 0.7219,
+    0.6850, 0.7267, 0.6895, 0.7316, 0.3512, 0.6916, 0.7336, 0.6918, 0.7341, 0.6953, 0.7381, 0.7101,
+    0.7545, 0.7073, 0.7513, 0.7083, 0.7524, 0.7131, 0.7575, 0.7179, 0.7627, 0.4618, 0.6239, 0.6614,
+    0.6311, 0.6690, 0.6406, 0.6790, 0.6489, 0.6880, 0.6618, 0.7019, 0.6623, 0.7024, 0.6660, 0.7064,
+    0.4718, 0.6149, 0.6520, 0.6244, 0.6620, 0.6324, 0.6705, 0.6448, 0.6838, 0.6578, 0.6979, 0.6583,
+    0.6985, 0.4828, 0.6135, 0.6506, 0.6190, 0.6564, 0.6286, 0.6668, 0.6417, 0.6808, 0.6548, 0.6950,
+    0.4975, 0.6173, 0.6551, 0.6178, 0.6555, 0.6308, 0.6695, 0.6440, 0.6837, 0.5003, 0.6026, 0.6391,
+    0.6157, 0.6531, 0.6288, 0.6673, 0.5044, 0.6162, 0.6536, 0.6293, 0.6678, 0.5101, 0.6331, 0.6718,
+    0.5163,
+    0.5249, 0.6712, 0.7097, 0.6728, 0.7117, 0.6739, 0.7133, 0.6711, 0.7102, 0.6756, 0.7152, 0.6764,
+    0.7160, 0.6806, 0.7206, 0.3249, 0.6956, 0.7354, 0.6248, 0.6532, 0.6336, 0.6624, 0.6425, 0.6718,
+    0.6507, 0.6805, 0.6522, 0.6822, 0.6526, 0.6827, 0.6562, 0.6865, 0.3744, 0.6868, 0.7262, 0.6905,
+    0.7304, 0.7046, 0.7461, 0.7020, 0.7432, 0.7168, 0.7593, 0.7179, 0.7604, 0.7224, 0.7653, 0.4813,
+    0.6343, 0.6628, 0.6433, 0.6722, 0.6512, 0.6806, 0.6649, 0.6951, 0.6773, 0.7081, 0.6777, 0.7087,
+    0.4914, 0.6304, 0.6589, 0.6359, 0.6647, 0.6496, 0.6792, 0.6615, 0.6917, 0.6741, 0.7051, 0.5046,
+    0.6256, 0.6543, 0.6394, 0.6688, 0.6486, 0.6785, 0.6612, 0.6918, 0.5072, 0.6243, 0.6529, 0.6336,
+    0.6626, 0.6462, 0.6759, 0.5164, 0.6381, 0.6675, 0.6508, 0.6809, 0.5208, 0.6513, 0.6814, 0.5267,
+    0.6578, 0.6972, 0.6590, 0.6988, 0.6597, 0.6999, 0.6566, 0.6964, 0.6614, 0.7016, 0.6622, 0.7025,
+    0.6667, 0.7074, 0.2891, 0.6859, 0.7252, 0.6001, 0.6366, 0.6090, 0.6460, 0.6181, 0.6556, 0.6265,
+    0.6645, 0.6281, 0.6664, 0.6285, 0.6668, 0.6322, 0.6709, 0.3375, 0.6757, 0.7168, 0.6791, 0.7208,
+    0.6939, 0.7372, 0.6911, 0.7340, 0.7071, 0.7512, 0.7082, 0.7523, 0.7130, 0.7575, 0.4550, 0.6105,
+    0.6474, 0.6196, 0.6571, 0.6276, 0.6656, 0.6417, 0.6806, 0.6546, 0.6946, 0.6551, 0.6952, 0.4660,
+    0.6059, 0.6427, 0.6115, 0.6485, 0.6255, 0.6636, 0.6380, 0.6770, 0.6511, 0.6911, 0.4802, 0.6006,
+    0.6372, 0.6147, 0.6523, 0.6244, 0.6627, 0.6375, 0.6768, 0.4830, 0.5990, 0.6353, 0.6087, 0.6457,
+    0.6218, 0.6599, 0.4928, 0.6134, 0.6509, 0.6265, 0.6650, 0.4974, 0.6271, 0.6656, 0.5037,
+    0.5249, 0.6487, 0.6860, 0.6510, 0.6888, 0.6481, 0.6856, 0.6497, 0.6874, 0.6541, 0.6923, 0.6549,
+    0.6932, 0.3209, 0.5720, 0.5987, 0.6952, 0.7354, 0.6252, 0.6538, 0.6341, 0.6632, 0.6423, 0.6719,
+    0.6407, 0.6703, 0.6450, 0.6750, 0.6454, 0.6755, 0.3264, 0.6822, 0.7213, 0.6148, 0.6430, 0.6237,
+    0.6523, 0.6320, 0.6611, 0.6436, 0.6734, 0.6452, 0.6752, 0.6457, 0.6758, 0.3663, 0.6771, 0.7160,
+    0.6913, 0.7317, 0.6887, 0.7288, 0.7035, 0.7449, 0.7184, 0.7611, 0.7195, 0.7623, 0.4794, 0.6272,
+    0.6556, 0.6327, 0.6614, 0.6461, 0.6756, 0.6599, 0.6902, 0.6723, 0.7033, 0.4927, 0.6199, 0.6482,
+    0.6333, 0.6624, 0.6471, 0.6769, 0.6590, 0.6895, 0.4953, 0.6182, 0.6465, 0.6320, 0.6610, 0.6439,
+    0.6736, 0.5041, 0.6366, 0.6660, 0.6459, 0.6758, 0.5138, 0.6505, 0.6808, 0.5188,
+    0.6333, 0.6714, 0.6352, 0.6737, 0.6321, 0.6702, 0.6336, 0.6720, 0.6383, 0.6772, 0.6392, 0.6781,
+    0.2849, 0.5456, 0.5781, 0.6851, 0.7246, 0.6002, 0.6370, 0.6093, 0.6466, 0.6177, 0.6555, 0.6160,
+    0.6537, 0.6204, 0.6586, 0.6209, 0.6592, 0.2907, 0.6708, 0.7093, 0.5895, 0.6256, 0.5986, 0.6352,
+    0.6070, 0.6441, 0.6189, 0.6569, 0.6206, 0.6588, 0.6211, 0.6594, 0.3286, 0.6647, 0.7054, 0.6795,
+    0.7217, 0.6767, 0.7185, 0.6926, 0.7357, 0.7086, 0.7529, 0.7098, 0.7542, 0.4532, 0.6029, 0.6396,
+    0.6084, 0.6455, 0.6222, 0.6602, 0.6363, 0.6753, 0.6493, 0.6893, 0.4674, 0.5948, 0.6312, 0.6086,
+    0.6459, 0.6227, 0.6610, 0.6352, 0.6745, 0.4702, 0.5929, 0.6289, 0.6070, 0.6440, 0.6195, 0.6575,
+    0.4795, 0.6117, 0.6492, 0.6215, 0.6597, 0.4899, 0.6263, 0.6649, 0.4951,
+    0.5249, 0.6204, 0.6560, 0.6175, 0.6527, 0.6187, 0.6542, 0.6203, 0.6559, 0.6247, 0.6609, 0.3168,
+    0.5595, 0.5859, 0.5725, 0.5996, 0.6949, 0.7354, 0.6256, 0.6545, 0.6339, 0.6633, 0.6318, 0.6612,
+    0.6334, 0.6630, 0.6377, 0.6678, 0.3224, 0.5640, 0.5905, 0.6818, 0.7213, 0.6153, 0.6437, 0.6236,
+    0.6525, 0.6352, 0.6649, 0.6337, 0.6633, 0.6380, 0.6681, 0.3278, 0.6691, 0.7075, 0.6053, 0.6332,
+    0.6132, 0.6415, 0.6248, 0.6539, 0.6366, 0.6664, 0.6382, 0.6682, 0.3589, 0.6781, 0.7175, 0.6755,
+    0.7146, 0.6903, 0.7306, 0.7052, 0.7468, 0.7201, 0.7630, 0.4808, 0.6168, 0.6449, 0.6302, 0.6591,
+    0.6437, 0.6734, 0.6575, 0.6880, 0.4834, 0.6152, 0.6432, 0.6286, 0.6575, 0.6425, 0.6721, 0.4922,
+    0.6306, 0.6596, 0.6444, 0.6743, 0.5015, 0.6491, 0.6793, 0.5119,
+    0.6024, 0.6387, 0.5993, 0.6351, 0.6005, 0.6365, 0.6020, 0.6382, 0.6067, 0.6435, 0.2808, 0.5325,
+    0.5645, 0.5460, 0.5788, 0.6842, 0.7241, 0.6005, 0.6375, 0.6088, 0.6464, 0.6066, 0.6440, 0.6082,
+    0.6459, 0.6127, 0.6509, 0.2865, 0.5371, 0.5693, 0.6700, 0.7088, 0.5898, 0.6262, 0.5982, 0.6351,
+    0.6101, 0.6479, 0.6085, 0.6462, 0.6129, 0.6511, 0.2922, 0.6561, 0.6939, 0.5794, 0.6151, 0.5874,
+    0.6236, 0.5994, 0.6365, 0.6114, 0.6494, 0.6131, 0.6513, 0.3204, 0.6652, 0.7064, 0.6624, 0.7032,
+    0.6783, 0.7203, 0.6943, 0.7376, 0.7104, 0.7549, 0.4547, 0.5918, 0.6282, 0.6056, 0.6428, 0.6194,
+    0.6576, 0.6336, 0.6728, 0.4574, 0.5899, 0.6259, 0.6037, 0.6407, 0.6179, 0.6558, 0.4668, 0.6056,
+    0.6428, 0.6199, 0.6580, 0.4767, 0.6246, 0.6633, 0.4876,
+    0.5249, 0.5793, 0.6115, 0.5815, 0.6140, 0.5827, 0.6154, 0.5842, 0.6172, 0.3127, 0.5470, 0.5730,
+    0.5600, 0.5867, 0.5732, 0.6005, 0.6945, 0.7354, 0.6254, 0.6547, 0.6233, 0.6525, 0.6244, 0.6539,
+    0.6261, 0.6558, 0.3183, 0.5515, 0.5777, 0.5646, 0.5915, 0.6815, 0.7213, 0.6151, 0.6438, 0.6268,
+    0.6562, 0.6247, 0.6542, 0.6264, 0.6561, 0.3237, 0.5564, 0.5828, 0.6687, 0.7075, 0.6048, 0.6329,
+    0.6164, 0.6453, 0.6282, 0.6578, 0.6267, 0.6563, 0.3298, 0.6590, 0.6970, 0.5949, 0.6225, 0.6065,
+    0.6349, 0.6183, 0.6474, 0.6301, 0.6599, 0.3523, 0.6625, 0.7005, 0.6773, 0.7165, 0.6921, 0.7327,
+    0.7070, 0.7489, 0.4716, 0.6126, 0.6405, 0.6261, 0.6548, 0.6396, 0.6691, 0.4803, 0.6275, 0.6564,
+    0.6411, 0.6707, 0.4897, 0.6431, 0.6730, 0.4996,
+    0.5581, 0.5909, 0.5603, 0.5934, 0.5615, 0.5948, 0.5630, 0.5965, 0.2766, 0.5194, 0.5508, 0.5328,
+    0.5651, 0.5464, 0.5795, 0.6833, 0.7236, 0.6000, 0.6373, 0.5977, 0.6349, 0.5988, 0.6362, 0.6005,
+    0.6382, 0.2824, 0.5240, 0.5556, 0.5376, 0.5700, 0.6691, 0.7083, 0.5893, 0.6259, 0.6013, 0.6388,
+    0.5991, 0.6365, 0.6008, 0.6384, 0.2881, 0.5291, 0.5609, 0.6553, 0.6934, 0.5786, 0.6146, 0.5906,
+    0.6274, 0.6026, 0.6403, 0.6010, 0.6387, 0.2944, 0.6446, 0.6820, 0.5684, 0.6037, 0.5804, 0.6165,
+    0.5924, 0.6294, 0.6045, 0.6424, 0.3130, 0.6482, 0.6880, 0.6642, 0.7052, 0.6802, 0.7224, 0.6962,
+    0.7397, 0.4447, 0.5874, 0.6235, 0.6012, 0.6383, 0.6151, 0.6531, 0.4540, 0.6027, 0.6399, 0.6166,
+    0.6547, 0.4639, 0.6186, 0.6569, 0.4744,
+    0.5250, 0.5520, 0.5821, 0.5544, 0.5848, 0.5556, 0.5863, 0.3128, 0.5412, 0.5672, 0.5547, 0.5813,
+    0.5678, 0.5951, 0.5811, 0.6089, 0.7066, 0.7485, 0.6259, 0.6552, 0.6271, 0.6565, 0.6283, 0.6580,
+    0.3217, 0.5486, 0.5748, 0.5618, 0.5886, 0.5750, 0.6024, 0.6936, 0.7344, 0.6294, 0.6589, 0.6277,
+    0.6572, 0.6289, 0.6586, 0.3277, 0.5540, 0.5804, 0.5673, 0.5942, 0.6804, 0.7201, 0.6194, 0.6484,
+    0.6312, 0.6609, 0.6292, 0.6589, 0.3337, 0.5618, 0.5885, 0.6677, 0.7064, 0.6097, 0.6381, 0.6214,
+    0.6506, 0.6332, 0.6631, 0.3404, 0.6580, 0.6960, 0.6025, 0.6306, 0.6143, 0.6431, 0.6261, 0.6557,
+    0.3585, 0.6620, 0.6999, 0.6768, 0.7160, 0.6917, 0.7322, 0.4793, 0.6298, 0.6587, 0.6433, 0.6730,
+    0.4886, 0.6449, 0.6747, 0.4985,
+    0.5287, 0.5592, 0.5312, 0.5620, 0.5323, 0.5634, 0.2766, 0.5130, 0.5440, 0.5269, 0.5587, 0.5404,
+    0.5731, 0.5541, 0.5875, 0.6965, 0.7378, 0.6005, 0.6378, 0.6016, 0.6391, 0.6028, 0.6405, 0.2861,
+    0.5206, 0.5520, 0.5342, 0.5663, 0.5479, 0.5808, 0.6823, 0.7225, 0.6041, 0.6417, 0.6023, 0.6398,
+    0.6034, 0.6412, 0.2924, 0.5263, 0.5578, 0.5399, 0.5723, 0.6679, 0.7071, 0.5938, 0.6307, 0.6058,
+    0.6436, 0.6037, 0.6415, 0.2987, 0.5344, 0.5664, 0.6541, 0.6922, 0.5837, 0.6200, 0.5957, 0.6329,
+    0.6078, 0.6459, 0.3056, 0.6436, 0.6809, 0.5764, 0.6123, 0.5884, 0.6252, 0.6005, 0.6382, 0.3197,
+    0.6476, 0.6872, 0.6636, 0.7045, 0.6797, 0.7218, 0.4530, 0.6055, 0.6430, 0.6194, 0.6579, 0.4629,
+    0.6209, 0.6595, 0.4734,
+    0.5250, 0.5287, 0.5571, 0.5314, 0.5601, 0.3128, 0.5412, 0.5672, 0.5547, 0.5813, 0.5678, 0.5951,
+    0.5811, 0.6089, 0.5928, 0.6212, 0.7066, 0.7486, 0.6271, 0.6565, 0.6283, 0.6580, 0.3165, 0.5403,
+    0.5663, 0.5538, 0.5805, 0.5671, 0.5944, 0.5788, 0.6066, 0.7055, 0.7474, 0.6242, 0.6536, 0.6254,
+    0.6550, 0.3254, 0.5482, 0.5745, 0.5614, 0.5883, 0.5727, 0.6001, 0.6923, 0.7331, 0.6276, 0.6572,
+    0.6260, 0.6557, 0.3320, 0.5564, 0.5830, 0.5650, 0.5920, 0.6796, 0.7194, 0.6182, 0.6473, 0.6300,
+    0.6599, 0.3386, 0.5597, 0.5863, 0.6699, 0.7090, 0.6111, 0.6399, 0.6229, 0.6524, 0.3404, 0.6551,
+    0.6927, 0.5972, 0.6251, 0.6090, 0.6376, 0.3620, 0.6783, 0.7176, 0.6932, 0.7339, 0.4854, 0.6441,
+    0.6739, 0.4953,
+    0.5034, 0.5321, 0.5062, 0.5352, 0.2766, 0.5129, 0.5439, 0.5268, 0.5587, 0.5404, 0.5730, 0.5541,
+    0.5875, 0.5665, 0.6008, 0.6965, 0.7378, 0.6016, 0.6391, 0.6027, 0.6405, 0.2805, 0.5116, 0.5424,
+    0.5256, 0.5572, 0.5392, 0.5717, 0.5517, 0.5850, 0.6951, 0.7364, 0.5984, 0.6357, 0.5995, 0.6371,
+    0.2899, 0.5199, 0.5510, 0.5335, 0.5655, 0.5454, 0.5782, 0.6807, 0.7209, 0.6019, 0.6395, 0.6002,
+    0.6378, 0.2968, 0.5284, 0.5600, 0.5375, 0.5698, 0.6669, 0.7061, 0.5922, 0.6292, 0.6043, 0.6422,
+    0.3037, 0.5320, 0.5640, 0.6564, 0.6948, 0.5850, 0.6216, 0.5971, 0.6346, 0.3055, 0.6403, 0.6773,
+    0.5707, 0.6063, 0.5828, 0.6192, 0.3233, 0.6652, 0.7062, 0.6812, 0.7235, 0.4594, 0.6200, 0.6586,
+    0.4698,
+    0.5251, 0.5283, 0.5568, 0.3091, 0.5412, 0.5672, 0.5508, 0.5773, 0.5644, 0.5915, 0.5776, 0.6054,
+    0.5894, 0.6176, 0.5898, 0.6181, 0.7081, 0.7502, 0.6274, 0.6571, 0.3165, 0.5403, 0.5663, 0.5538,
+    0.5805, 0.5671, 0.5944, 0.5788, 0.6066, 0.5908, 0.6192, 0.7055, 0.7475, 0.6254, 0.6550, 0.3202,
+    0.5399, 0.5661, 0.5535, 0.5803, 0.5648, 0.5920, 0.5768, 0.6047, 0.7042, 0.7461, 0.6225, 0.6520,
+    0.3297, 0.5506, 0.5771, 0.5592, 0.5860, 0.5712, 0.5987, 0.6915, 0.7324, 0.6265, 0.6563, 0.3368,
+    0.5542, 0.5808, 0.5662, 0.5934, 0.6818, 0.7220, 0.6198, 0.6492, 0.3386, 0.5544, 0.5807, 0.6670,
+    0.7057, 0.6059, 0.6344, 0.3440, 0.6708, 0.7099, 0.6094, 0.6382, 0.3662, 0.6947, 0.7355, 0.4921,
+    0.5028, 0.5317, 0.2726, 0.5129, 0.5439, 0.5226, 0.5542, 0.5366, 0.5690, 0.5502, 0.5835, 0.5627,
+    0.5968, 0.5631, 0.5973, 0.6980, 0.7395, 0.6018, 0.6395, 0.2804, 0.5115, 0.5424, 0.5255, 0.5572,
+    0.5392, 0.5716, 0.5516, 0.5849, 0.5640, 0.5984, 0.6950, 0.7364, 0.5995, 0.6371, 0.2842, 0.5108,
+    0.5415, 0.5249, 0.5564, 0.5368, 0.5691, 0.5492, 0.5825, 0.6935, 0.7348, 0.5963, 0.6337, 0.2943,
+    0.5220, 0.5532, 0.5311, 0.5630, 0.5435, 0.5764, 0.6797, 0.7200, 0.6004, 0.6381, 0.3017, 0.5260,
+    0.5576, 0.5385, 0.5710, 0.6692, 0.7087, 0.5935, 0.6309, 0.3036, 0.5264, 0.5579, 0.6531, 0.6912,
+    0.5793, 0.6156, 0.3093, 0.6572, 0.6957, 0.5831, 0.6196, 0.3275, 0.6828, 0.7252, 0.4663,
+    0.5251, 0.3055, 0.5404, 0.5663, 0.5508, 0.5773, 0.5605, 0.5876, 0.5742, 0.6018, 0.5859, 0.6141,
+    0.5863, 0.6145, 0.5899, 0.6183, 0.7095, 0.7519, 0.3129, 0.5403, 0.5663, 0.5500, 0.5766, 0.5636,
+    0.5908, 0.5754, 0.6031, 0.5873, 0.6157, 0.5878, 0.6162, 0.7070, 0.7492, 0.3202, 0.5399, 0.5660,
+    0.5535, 0.5803, 0.5648, 0.5920, 0.5767, 0.6046, 0.5888, 0.6173, 0.7042, 0.7462, 0.3245, 0.5423,
+    0.5687, 0.5513, 0.5780, 0.5633, 0.5906, 0.5753, 0.6033, 0.7034, 0.7455, 0.3345, 0.5484, 0.5749,
+    0.5604, 0.5875, 0.5724, 0.6002, 0.6937, 0.7350, 0.3368, 0.5489, 0.5752, 0.5610, 0.5879, 0.6789,
+    0.7187, 0.3422, 0.5645, 0.5917, 0.6827, 0.7229, 0.3482, 0.6866, 0.7271, 0.3709,
+    0.2686, 0.5119, 0.5429, 0.5225, 0.5542, 0.5323, 0.5646, 0.5464, 0.5795, 0.5588, 0.5928, 0.5593,
+    0.5933, 0.5630, 0.5973, 0.6995, 0.7412, 0.2764, 0.5115, 0.5423, 0.5213, 0.5527, 0.5353, 0.5676,
+    0.5478, 0.5809, 0.5602, 0.5944, 0.5607, 0.5949, 0.6965, 0.7381, 0.2842, 0.5107, 0.5414, 0.5248,
+    0.5563, 0.5367, 0.5690, 0.5491, 0.5825, 0.5616, 0.5960, 0.6934, 0.7348, 0.2886, 0.5129, 0.5437,
+    0.5224, 0.5538, 0.5348, 0.5672, 0.5473, 0.5807, 0.6925, 0.7338, 0.2992, 0.5196, 0.5508, 0.5320,
+    0.5642, 0.5445, 0.5777, 0.6819, 0.7225, 0.3016, 0.5203, 0.5515, 0.5328, 0.5650, 0.6658, 0.7051,
+    0.3073, 0.5366, 0.5691, 0.6700, 0.7095, 0.3137, 0.6742, 0.7141, 0.3324,
+    0.8231, 0.8717, 0.8362, 0.8858, 0.8493, 0.9000, 0.8625, 0.9142, 0.8754, 0.9282, 0.8754, 0.9282,
+    0.8794, 0.9326, 0.8834, 0.9370, 0.8200, 0.8170, 0.8580, 0.8179, 0.8589, 0.8193, 0.8603, 0.8229,
+    0.8640, 0.8388, 0.8811, 0.8389, 0.8812, 0.8429, 0.8856, 0.8162, 0.8028, 0.8429, 0.8042, 0.8442,
+    0.8078, 0.8479, 0.8238, 0.8651, 0.8402, 0.8826, 0.8402, 0.8827, 0.8125, 0.7896, 0.8287, 0.7932,
+    0.8324, 0.8087, 0.8490, 0.8251, 0.8666, 0.8416, 0.8842, 0.8093, 0.7820, 0.8205, 0.7941, 0.8335,
+    0.8106, 0.8511, 0.8270, 0.8687, 0.8096, 0.7786, 0.8170, 0.7950, 0.8346, 0.8115, 0.8522, 0.8098,
+    0.7950, 0.8346, 0.8115, 0.8522, 0.8142, 0.8156, 0.8566, 0.8187,
+    0.5249, 0.6701, 0.7081, 0.6716, 0.7101, 0.6727, 0.7116, 0.6761, 0.7156, 0.6769, 0.7165, 0.6777,
+    0.7173, 0.6818, 0.7219, 0.3885, 0.6932, 0.7329, 0.6941, 0.7343, 0.6978, 0.7386, 0.7117, 0.7540,
+    0.7127, 0.7551, 0.7138, 0.7562, 0.7182, 0.7610, 0.4888, 0.6445, 0.6732, 0.6517, 0.6807, 0.6608,
+    0.6903, 0.6724, 0.7026, 0.6847, 0.7157, 0.6852, 0.7162, 0.4984, 0.6365, 0.6650, 0.6456, 0.6746,
+    0.6568, 0.6865, 0.6687, 0.6990, 0.6813, 0.7124, 0.5088, 0.6353, 0.6641, 0.6442, 0.6735, 0.6534,
+    0.6832, 0.6661, 0.6966, 0.5230, 0.6396, 0.6689, 0.6400, 0.6694, 0.6526, 0.6827, 0.5267, 0.6404,
+    0.6698, 0.6531, 0.6832, 0.5308, 0.6536, 0.6837, 0.5364,
+    0.6571, 0.6960, 0.6582, 0.6975, 0.6588, 0.6985, 0.6620, 0.7022, 0.6628, 0.7031, 0.6636, 0.7040,
+    0.6681, 0.7088, 0.3528, 0.6834, 0.7250, 0.6839, 0.7258, 0.6873, 0.7298, 0.7019, 0.7459, 0.7030,
+    0.7469, 0.7041, 0.7481, 0.7089, 0.7532, 0.4629, 0.6212, 0.6585, 0.6285, 0.6662, 0.6378, 0.6760,
+    0.6497, 0.6888, 0.6627, 0.7028, 0.6632, 0.7034, 0.4734, 0.6125, 0.6493, 0.6218, 0.6591, 0.6333,
+    0.6715, 0.6458, 0.6849, 0.6589, 0.6991, 0.4848, 0.6109, 0.6478, 0.6200, 0.6575, 0.6297, 0.6679,
+    0.6428, 0.6821, 0.5001, 0.6150, 0.6525, 0.6155, 0.6530, 0.6286, 0.6671, 0.5039, 0.6159, 0.6534,
+    0.6291, 0.6676, 0.5083, 0.6296, 0.6681, 0.5142,
+    0.5249, 0.6476, 0.6844, 0.6498, 0.6871, 0.6509, 0.6886, 0.6516, 0.6894, 0.6561, 0.6944, 0.6569,
+    0.6952, 0.3309, 0.6877, 0.7271, 0.6232, 0.6514, 0.6320, 0.6607, 0.6407, 0.6699, 0.6523, 0.6822,
+    0.6539, 0.6840, 0.6543, 0.6845, 0.3802, 0.6796, 0.7186, 0.6833, 0.7229, 0.6972, 0.7384, 0.6982,
+    0.7394, 0.7130, 0.7555, 0.7141, 0.7567, 0.4864, 0.6329, 0.6612, 0.6417, 0.6705, 0.6529, 0.6824,
+    0.6667, 0.6970, 0.6791, 0.7101, 0.4969, 0.6288, 0.6572, 0.6377, 0.6666, 0.6514, 0.6812, 0.6634,
+    0.6938, 0.5107, 0.6242, 0.6528, 0.6380, 0.6673, 0.6473, 0.6771, 0.5143, 0.6384, 0.6678, 0.6478,
+    0.6776, 0.5238, 0.6524, 0.6826, 0.5284,
+    0.6326, 0.6702, 0.6344, 0.6724, 0.6350, 0.6734, 0.6358, 0.6742, 0.6405, 0.6795, 0.6414, 0.6803,
+    0.2955, 0.6776, 0.7165, 0.5984, 0.6347, 0.6074, 0.6442, 0.6162, 0.6536, 0.6282, 0.6664, 0.6298,
+    0.6683, 0.6303, 0.6688, 0.3438, 0.6682, 0.7090, 0.6716, 0.7130, 0.6862, 0.7291, 0.6873, 0.7301,
+    0.7033, 0.7473, 0.7044, 0.7485, 0.4605, 0.6090, 0.6458, 0.6179, 0.6552, 0.6295, 0.6676, 0.6436,
+    0.6827, 0.6566, 0.6968, 0.4719, 0.6043, 0.6409, 0.6134, 0.6506, 0.6275, 0.6657, 0.6400, 0.6792,
+    0.4868, 0.5992, 0.6356, 0.6133, 0.6507, 0.6230, 0.6612, 0.4906, 0.6138, 0.6512, 0.6235, 0.6617,
+    0.5007, 0.6283, 0.6669, 0.5056,
+    0.5249, 0.6193, 0.6544, 0.6221, 0.6577, 0.6228, 0.6585, 0.6243, 0.6602, 0.6288, 0.6652, 0.3269,
+    0.5703, 0.5970, 0.6873, 0.7271, 0.6236, 0.6521, 0.6323, 0.6613, 0.6439, 0.6736, 0.6423, 0.6721,
+    0.6467, 0.6768, 0.3322, 0.6743, 0.7130, 0.6133, 0.6413, 0.6220, 0.6504, 0.6336, 0.6628, 0.6453,
+    0.6753, 0.6469, 0.6771, 0.3726, 0.6697, 0.7083, 0.6837, 0.7238, 0.6847, 0.7248, 0.6995, 0.7409,
+    0.7143, 0.7571, 0.4849, 0.6256, 0.6538, 0.6344, 0.6632, 0.6479, 0.6775, 0.6617, 0.6921, 0.4987,
+    0.6184, 0.6466, 0.6318, 0.6608, 0.6457, 0.6755, 0.5024, 0.6323, 0.6613, 0.6461, 0.6759, 0.5114,
+    0.6508, 0.6810, 0.5214,
+    0.6017, 0.6375, 0.6042, 0.6405, 0.6049, 0.6412, 0.6065, 0.6429, 0.6112, 0.6482, 0.2914, 0.5439,
+    0.5762, 0.6768, 0.7160, 0.5986, 0.6352, 0.6074, 0.6445, 0.6194, 0.6573, 0.6177, 0.6556, 0.6221,
+    0.6606, 0.2969, 0.6626, 0.7007, 0.5879, 0.6238, 0.5968, 0.6332, 0.6087, 0.6460, 0.6208, 0.6589,
+    0.6224, 0.6609, 0.3354, 0.6569, 0.6973, 0.6715, 0.7133, 0.6726, 0.7144, 0.6886, 0.7316, 0.7046,
+    0.7489, 0.4591, 0.6011, 0.6377, 0.6102, 0.6474, 0.6241, 0.6622, 0.6383, 0.6774, 0.4740, 0.5932,
+    0.6294, 0.6071, 0.6442, 0.6213, 0.6594, 0.4778, 0.6075, 0.6447, 0.6217, 0.6598, 0.4874, 0.6265,
+    0.6651, 0.4980,
+    0.5249, 0.5869, 0.6199, 0.5876, 0.6206, 0.5888, 0.6220, 0.5903, 0.6237, 0.3228, 0.5578, 0.5841,
+    0.5709, 0.5979, 0.6869, 0.7271, 0.6238, 0.6526, 0.6354, 0.6650, 0.6334, 0.6629, 0.6351, 0.6649,
+    0.3281, 0.5624, 0.5889, 0.6740, 0.7131, 0.6136, 0.6418, 0.6252, 0.6542, 0.6369, 0.6667, 0.6354,
+    0.6652, 0.3340, 0.6612, 0.6993, 0.6036, 0.6314, 0.6149, 0.6433, 0.6266, 0.6558, 0.6384, 0.6683,
+    0.3656, 0.6705, 0.7095, 0.6715, 0.7105, 0.6863, 0.7266, 0.7012, 0.7428, 0.4869, 0.6153, 0.6433,
+    0.6288, 0.6576, 0.6423, 0.6719, 0.4905, 0.6292, 0.6580, 0.6427, 0.6724, 0.4995, 0.6448, 0.6746,
+    0.5091,
+    0.5664, 0.5998, 0.5671, 0.6006, 0.5682, 0.6019, 0.5698, 0.6036, 0.2872, 0.5307, 0.5626, 0.5443,
+    0.5769, 0.6759, 0.7154, 0.5986, 0.6354, 0.6105, 0.6482, 0.6083, 0.6459, 0.6100, 0.6479, 0.2928,
+    0.5355, 0.5675, 0.6618, 0.7002, 0.5880, 0.6242, 0.5999, 0.6369, 0.6119, 0.6499, 0.6103, 0.6482,
+    0.2989, 0.6479, 0.6853, 0.5777, 0.6132, 0.5892, 0.6256, 0.6013, 0.6385, 0.6134, 0.6515, 0.3276,
+    0.6572, 0.6980, 0.6583, 0.6990, 0.6743, 0.7163, 0.6903, 0.7335, 0.4612, 0.5902, 0.6264, 0.6040,
+    0.6412, 0.6179, 0.6560, 0.4650, 0.6045, 0.6417, 0.6184, 0.6565, 0.4747, 0.6204, 0.6587, 0.4848,
+    0.5250, 0.5501, 0.5800, 0.5525, 0.5828, 0.5537, 0.5842, 0.3190, 0.5450, 0.5710, 0.5582, 0.5847,
+    0.5714, 0.5986, 0.6863, 0.7268, 0.6273, 0.6566, 0.6252, 0.6546, 0.6264, 0.6560, 0.3243, 0.5497,
+    0.5757, 0.5629, 0.5896, 0.6733, 0.7128, 0.6170, 0.6459, 0.6288, 0.6583, 0.6268, 0.6564, 0.3302,
+    0.5548, 0.5810, 0.6607, 0.6991, 0.6068, 0.6350, 0.6185, 0.6475, 0.6303, 0.6601, 0.3366, 0.6510,
+    0.6886, 0.5969, 0.6247, 0.6087, 0.6372, 0.6204, 0.6497, 0.3596, 0.6550, 0.6927, 0.6699, 0.7088,
+    0.6847, 0.7250, 0.4784, 0.6270, 0.6557, 0.6405, 0.6700, 0.4874, 0.6420, 0.6717, 0.4970,
+    0.5266, 0.5570, 0.5291, 0.5598, 0.5303, 0.5612, 0.2833, 0.5173, 0.5486, 0.5309, 0.5630, 0.5445,
+    0.5774, 0.6748, 0.7146, 0.6020, 0.6394, 0.5998, 0.6372, 0.6009, 0.6385, 0.2889, 0.5221, 0.5536,
+    0.5357, 0.5680, 0.6606, 0.6994, 0.5914, 0.6282, 0.6034, 0.6411, 0.6013, 0.6389, 0.2950, 0.5273,
+    0.5590, 0.6468, 0.6846, 0.5807, 0.6169, 0.5928, 0.6298, 0.6049, 0.6428, 0.3017, 0.6363, 0.6733,
+    0.5706, 0.6061, 0.5826, 0.6190, 0.5947, 0.6319, 0.3209, 0.6404, 0.6798, 0.6564, 0.6970, 0.6724,
+    0.7143, 0.4520, 0.6025, 0.6398, 0.6164, 0.6546, 0.4616, 0.6179, 0.6563, 0.4718,
+    0.5250, 0.5287, 0.5571, 0.5314, 0.5601, 0.3151, 0.5387, 0.5646, 0.5522, 0.5787, 0.5655, 0.5926,
+    0.5790, 0.6067, 0.7002, 0.7419, 0.6244, 0.6537, 0.6256, 0.6551, 0.3239, 0.5462, 0.5722, 0.5594,
+    0.5861, 0.5729, 0.6002, 0.6872, 0.7278, 0.6279, 0.6575, 0.6263, 0.6559, 0.3303, 0.5518, 0.5780,
+    0.5653, 0.5921, 0.6740, 0.7136, 0.6180, 0.6470, 0.6298, 0.6596, 0.3367, 0.5599, 0.5864, 0.6614,
+    0.6999, 0.6083, 0.6368, 0.6201, 0.6493, 0.3436, 0.6503, 0.6878, 0.5983, 0.6262, 0.6101, 0.6387,
+    0.3644, 0.6699, 0.7088, 0.6848, 0.7250, 0.4874, 0.6420, 0.6717, 0.4970,
+    0.5034, 0.5321, 0.5062, 0.5352, 0.2791, 0.5103, 0.5411, 0.5243, 0.5559, 0.5379, 0.5703, 0.5518,
+    0.5851, 0.6898, 0.7308, 0.5987, 0.6361, 0.5999, 0.6374, 0.2884, 0.5181, 0.5492, 0.5318, 0.5637,
+    0.5457, 0.5784, 0.6757, 0.7156, 0.6024, 0.6400, 0.6007, 0.6382, 0.2951, 0.5239, 0.5552, 0.5378,
+    0.5700, 0.6613, 0.7002, 0.5922, 0.6291, 0.6043, 0.6421, 0.3018, 0.5323, 0.5642, 0.6476, 0.6855,
+    0.5821, 0.6184, 0.5942, 0.6314, 0.3090, 0.6355, 0.6724, 0.5719, 0.6075, 0.5840, 0.6205, 0.3259,
+    0.6564, 0.6970, 0.6724, 0.7143, 0.4616, 0.6179, 0.6563, 0.4717,
+    0.5251, 0.5283, 0.5568, 0.3151, 0.5387, 0.5646, 0.5522, 0.5787, 0.5655, 0.5926, 0.5789, 0.6067,
+    0.5910, 0.6194, 0.7002, 0.7419, 0.6256, 0.6551, 0.3187, 0.5379, 0.5638, 0.5515, 0.5781, 0.5650,
+    0.5922, 0.5771, 0.6049, 0.6991, 0.7408, 0.6228, 0.6523, 0.3280, 0.5460, 0.5721, 0.5594, 0.5862,
+    0.5710, 0.5984, 0.6859, 0.7266, 0.6263, 0.6560, 0.3349, 0.5545, 0.5809, 0.5634, 0.5903, 0.6733,
+    0.7130, 0.6169, 0.6461, 0.3418, 0.5554, 0.5818, 0.6622, 0.7009, 0.6069, 0.6355, 0.3464, 0.6627,
+    0.7014, 0.6074, 0.6360, 0.3682, 0.6863, 0.7267, 0.4938,
+    0.5028, 0.5317, 0.2791, 0.5102, 0.5411, 0.5242, 0.5559, 0.5379, 0.5703, 0.5518, 0.5850, 0.5644,
+    0.5987, 0.6897, 0.7309, 0.5999, 0.6374, 0.2827, 0.5091, 0.5397, 0.5231, 0.5546, 0.5370, 0.5693,
+    0.5496, 0.5829, 0.6884, 0.7295, 0.5967, 0.6341, 0.2926, 0.5175, 0.5484, 0.5314, 0.5632, 0.5435,
+    0.5762, 0.6741, 0.7141, 0.6003, 0.6380, 0.2999, 0.5263, 0.5578, 0.5356, 0.5679, 0.6604, 0.6994,
+    0.5907, 0.6277, 0.3071, 0.5275, 0.5591, 0.6482, 0.6863, 0.5804, 0.6168, 0.3119, 0.6488, 0.6868,
+    0.5810, 0.6173, 0.3298, 0.6740, 0.7160, 0.4682,
+    0.5251, 0.3115, 0.5387, 0.5645, 0.5484, 0.5748, 0.5620, 0.5890, 0.5755, 0.6031, 0.5875, 0.6158,
+    0.5880, 0.6164, 0.7016, 0.7436, 0.3187, 0.5379, 0.5638, 0.5515, 0.5781, 0.5650, 0.5922, 0.5771,
+    0.6049, 0.5891, 0.6176, 0.6991, 0.7409, 0.3228, 0.5377, 0.5637, 0.5515, 0.5782, 0.5631, 0.5903,
+    0.5751, 0.6030, 0.6978, 0.7397, 0.3326, 0.5486, 0.5750, 0.5576, 0.5844, 0.5696, 0.5971, 0.6852,
+    0.7260, 0.3401, 0.5500, 0.5763, 0.5620, 0.5890, 0.6741, 0.7139, 0.3446, 0.5625, 0.5895, 0.6746,
+    0.7144, 0.3503, 0.6785, 0.7186, 0.3726,
+    0.2751, 0.5102, 0.5410, 0.5200, 0.5514, 0.5340, 0.5663, 0.5479, 0.5810, 0.5605, 0.5947, 0.5611,
+    0.5952, 0.6912, 0.7326, 0.2827, 0.5090, 0.5396, 0.5230, 0.5545, 0.5370, 0.5692, 0.5496, 0.5829,
+    0.5620, 0.5964, 0.6883, 0.7295, 0.2869, 0.5084, 0.5389, 0.5227, 0.5540, 0.5348, 0.5671, 0.5473,
+    0.5806, 0.6869, 0.7280, 0.2973, 0.5199, 0.5510, 0.5292, 0.5610, 0.5417, 0.5746, 0.6731, 0.7132,
+    0.3051, 0.5214, 0.5527, 0.5339, 0.5662, 0.6610, 0.7001, 0.3100, 0.5345, 0.5668, 0.6615, 0.7007,
+    0.3160, 0.6657, 0.7052, 0.3344,
+    0.8217, 0.8702, 0.8348, 0.8843, 0.8479, 0.8985, 0.8608, 0.9125, 0.8780, 0.9311, 0.8780, 0.9312,
+    0.8820, 0.9356, 0.8194, 0.8162, 0.8572, 0.8171, 0.8581, 0.8182, 0.8592, 0.8262, 0.8676, 0.8421,
+    0.8847, 0.8421, 0.8848, 0.8156, 0.8021, 0.8421, 0.8032, 0.8431, 0.8111, 0.8515, 0.8270, 0.8687,
+    0.8435, 0.8862, 0.8118, 0.7886, 0.8276, 0.7965, 0.8360, 0.8120, 0.8526, 0.8284, 0.8702, 0.8084,
+    0.7810, 0.8195, 0.7931, 0.8325, 0.8096, 0.8501, 0.8131, 0.7983, 0.8381, 0.8148, 0.8557, 0.8134,
+    0.8148, 0.8558, 0.8178,
+    0.5249, 0.6465, 0.6829, 0.6486, 0.6855, 0.6496, 0.6869, 0.6566, 0.6948, 0.6573, 0.6957, 0.6581,
+    0.6965, 0.3900, 0.6817, 0.7209, 0.6829, 0.7225, 0.6864, 0.7265, 0.7039, 0.7459, 0.7049, 0.7470,
+    0.7059, 0.7481, 0.4900, 0.6402, 0.6686, 0.6471, 0.6759, 0.6596, 0.6891, 0.6713, 0.7015, 0.6837,
+    0.7146, 0.4999, 0.6321, 0.6604, 0.6446, 0.6736, 0.6559, 0.6856, 0.6678, 0.6982, 0.5109, 0.6311,
+    0.6597, 0.6401, 0.6692, 0.6494, 0.6790, 0.5261, 0.6509, 0.6809, 0.6514, 0.6815, 0.5300, 0.6519,
+    0.6820, 0.5343,
+    0.6318, 0.6690, 0.6336, 0.6712, 0.6341, 0.6721, 0.6411, 0.6801, 0.6419, 0.6809, 0.6428, 0.6818,
+    0.3545, 0.6714, 0.7123, 0.6721, 0.7133, 0.6753, 0.7171, 0.6938, 0.7374, 0.6949, 0.7385, 0.6960,
+    0.7397, 0.4642, 0.6166, 0.6535, 0.6237, 0.6611, 0.6366, 0.6748, 0.6486, 0.6876, 0.6616, 0.7017,
+    0.4750, 0.6079, 0.6444, 0.6207, 0.6580, 0.6323, 0.6705, 0.6448, 0.6840, 0.4870, 0.6065, 0.6430,
+    0.6157, 0.6528, 0.6254, 0.6633, 0.5033, 0.6267, 0.6652, 0.6273, 0.6658, 0.5074, 0.6278, 0.6663,
+    0.5120,
+    0.5249, 0.6182, 0.6528, 0.6209, 0.6561, 0.6258, 0.6617, 0.6265, 0.6625, 0.6309, 0.6675, 0.3324,
+    0.6755, 0.7143, 0.6185, 0.6465, 0.6272, 0.6556, 0.6393, 0.6684, 0.6509, 0.6808, 0.6525, 0.6827,
+    0.3822, 0.6681, 0.7065, 0.6716, 0.7106, 0.6891, 0.7300, 0.6901, 0.7310, 0.7049, 0.7472, 0.4880,
+    0.6282, 0.6563, 0.6404, 0.6691, 0.6517, 0.6812, 0.6655, 0.6958, 0.4990, 0.6243, 0.6525, 0.6333,
+    0.6620, 0.6471, 0.6766, 0.5137, 0.6353, 0.6644, 0.6491, 0.6791, 0.5176, 0.6497, 0.6796, 0.5273,
+    0.6010, 0.6363, 0.6034, 0.6392, 0.6081, 0.6446, 0.6088, 0.6454, 0.6136, 0.6506, 0.2971, 0.6649,
+    0.7030, 0.5935, 0.6294, 0.6023, 0.6387, 0.6147, 0.6520, 0.6267, 0.6648, 0.6284, 0.6668, 0.3459,
+    0.6561, 0.6962, 0.6593, 0.6999, 0.6778, 0.7203, 0.6789, 0.7214, 0.6949, 0.7386, 0.4622, 0.6041,
+    0.6405, 0.6166, 0.6538, 0.6282, 0.6662, 0.6424, 0.6814, 0.4741, 0.5996, 0.6358, 0.6087, 0.6456,
+    0.6229, 0.6608, 0.4901, 0.6106, 0.6480, 0.6249, 0.6632, 0.4941, 0.6254, 0.6637, 0.5044,
+    0.5249, 0.5859, 0.6183, 0.5932, 0.6267, 0.5939, 0.6274, 0.5954, 0.6292, 0.3283, 0.5657, 0.5921,
+    0.6752, 0.7143, 0.6188, 0.6470, 0.6308, 0.6598, 0.6425, 0.6722, 0.6410, 0.6708, 0.3341, 0.6622,
+    0.7003, 0.6085, 0.6362, 0.6206, 0.6490, 0.6323, 0.6614, 0.6441, 0.6740, 0.3750, 0.6578, 0.6957,
+    0.6753, 0.7151, 0.6763, 0.7162, 0.6912, 0.7323, 0.4871, 0.6210, 0.6490, 0.6299, 0.6584, 0.6434,
+    0.6728, 0.5018, 0.6294, 0.6581, 0.6429, 0.6725, 0.5057, 0.6434, 0.6730, 0.5149,
+    0.5657, 0.5987, 0.5731, 0.6071, 0.5738, 0.6078, 0.5753, 0.6096, 0.2929, 0.5390, 0.5710, 0.6640,
+    0.7025, 0.5935, 0.6297, 0.6059, 0.6429, 0.6179, 0.6558, 0.6163, 0.6542, 0.2989, 0.6499, 0.6873,
+    0.5829, 0.6184, 0.5953, 0.6317, 0.6073, 0.6445, 0.6194, 0.6575, 0.3378, 0.6444, 0.6840, 0.6629,
+    0.7043, 0.6640, 0.7054, 0.6800, 0.7226, 0.4613, 0.5963, 0.6325, 0.6054, 0.6423, 0.6193, 0.6571,
+    0.4772, 0.6046, 0.6417, 0.6185, 0.6566, 0.4813, 0.6190, 0.6571, 0.4911,
+    0.5250, 0.5582, 0.5889, 0.5589, 0.5896, 0.5601, 0.5910, 0.3245, 0.5529, 0.5789, 0.5661, 0.5928,
+    0.6746, 0.7141, 0.6227, 0.6514, 0.6344, 0.6639, 0.6324, 0.6619, 0.3302, 0.5577, 0.5838, 0.6616,
+    0.7001, 0.6125, 0.6407, 0.6242, 0.6531, 0.6360, 0.6657, 0.3365, 0.6490, 0.6864, 0.6026, 0.6303,
+    0.6139, 0.6423, 0.6257, 0.6549, 0.3686, 0.6588, 0.6971, 0.6598, 0.6982, 0.6746, 0.7143, 0.4897,
+    0.6266, 0.6552, 0.6401, 0.6696, 0.4935, 0.6406, 0.6701, 0.5028,
+    0.5354, 0.5665, 0.5361, 0.5672, 0.5373, 0.5686, 0.2890, 0.5256, 0.5570, 0.5392, 0.5714, 0.6629,
+    0.7017, 0.5974, 0.6342, 0.6094, 0.6470, 0.6072, 0.6448, 0.2950, 0.5305, 0.5621, 0.6488, 0.6865,
+    0.5869, 0.6230, 0.5988, 0.6358, 0.6109, 0.6488, 0.3015, 0.6350, 0.6717, 0.5766, 0.6121, 0.5882,
+    0.6245, 0.6003, 0.6375, 0.3307, 0.6448, 0.6849, 0.6459, 0.6860, 0.6619, 0.7032, 0.4642, 0.6021,
+    0.6392, 0.6160, 0.6541, 0.4682, 0.6165, 0.6546, 0.4780,
+    0.5250, 0.5269, 0.5551, 0.5295, 0.5581, 0.3168, 0.5396, 0.5652, 0.5528, 0.5790, 0.5663, 0.5932,
+    0.6756, 0.7157, 0.6227, 0.6520, 0.6208, 0.6500, 0.3226, 0.5444, 0.5701, 0.5579, 0.5842, 0.6627,
+    0.7017, 0.6126, 0.6413, 0.6243, 0.6538, 0.3288, 0.5498, 0.5757, 0.6501, 0.6880, 0.6024, 0.6305,
+    0.6141, 0.6431, 0.3355, 0.6389, 0.6759, 0.5896, 0.6170, 0.6014, 0.6296, 0.3611, 0.6586, 0.6971,
+    0.6735, 0.7132, 0.4824, 0.6361, 0.6655, 0.4917,
+    0.5013, 0.5299, 0.5041, 0.5330, 0.2809, 0.5116, 0.5424, 0.5252, 0.5568, 0.5391, 0.5715, 0.6635,
+    0.7029, 0.5970, 0.6342, 0.5949, 0.6321, 0.2869, 0.5164, 0.5475, 0.5304, 0.5622, 0.6495, 0.6877,
+    0.5865, 0.6230, 0.5986, 0.6360, 0.2934, 0.5220, 0.5532, 0.6358, 0.6730, 0.5759, 0.6118, 0.5880,
+    0.6248, 0.3003, 0.6236, 0.6599, 0.5628, 0.5977, 0.5749, 0.6107, 0.3223, 0.6446, 0.6847, 0.6606,
+    0.7019, 0.4562, 0.6116, 0.6496, 0.4660,
+    0.5251, 0.5283, 0.5568, 0.3130, 0.5333, 0.5588, 0.5469, 0.5730, 0.5603, 0.5871, 0.5741, 0.6017,
+    0.6895, 0.7307, 0.6199, 0.6492, 0.3221, 0.5409, 0.5666, 0.5544, 0.5807, 0.5682, 0.5953, 0.6766,
+    0.7167, 0.6235, 0.6530, 0.3289, 0.5468, 0.5727, 0.5606, 0.5873, 0.6635, 0.7026, 0.6137, 0.6426,
+    0.3355, 0.5526, 0.5788, 0.6494, 0.6873, 0.6010, 0.6292, 0.3452, 0.6536, 0.6920, 0.6054, 0.6339,
+    0.3661, 0.6735, 0.7133, 0.4917,
+    0.5028, 0.5317, 0.2767, 0.5045, 0.5349, 0.5185, 0.5497, 0.5324, 0.5644, 0.5466, 0.5795, 0.6785,
+    0.7191, 0.5938, 0.6309, 0.2864, 0.5124, 0.5431, 0.5264, 0.5579, 0.5405, 0.5729, 0.6645, 0.7040,
+    0.5975, 0.6349, 0.2934, 0.5185, 0.5495, 0.5326, 0.5646, 0.6502, 0.6886, 0.5874, 0.6241, 0.3004,
+    0.5245, 0.5558, 0.6349, 0.6721, 0.5744, 0.6101, 0.3106, 0.6394, 0.6770, 0.5788, 0.6151, 0.3276,
+    0.6605, 0.7019, 0.4659,
+    0.5251, 0.3130, 0.5333, 0.5588, 0.5469, 0.5730, 0.5603, 0.5871, 0.5741, 0.6017, 0.5862, 0.6145,
+    0.6895, 0.7308, 0.3169, 0.5326, 0.5582, 0.5465, 0.5727, 0.5602, 0.5873, 0.5723, 0.6000, 0.6885,
+    0.7298, 0.3266, 0.5410, 0.5668, 0.5547, 0.5813, 0.5664, 0.5936, 0.6754, 0.7156, 0.3338, 0.5471,
+    0.5732, 0.5561, 0.5827, 0.6613, 0.7003, 0.3434, 0.5605, 0.5874, 0.6655, 0.7050, 0.3483, 0.6661,
+    0.7056, 0.3702,
+    0.2766, 0.5044, 0.5348, 0.5184, 0.5497, 0.5324, 0.5644, 0.5465, 0.5795, 0.5592, 0.5932, 0.6784,
+    0.7191, 0.2807, 0.5033, 0.5336, 0.5177, 0.5487, 0.5318, 0.5638, 0.5445, 0.5775, 0.6772, 0.7178,
+    0.2909, 0.5121, 0.5427, 0.5262, 0.5577, 0.5384, 0.5709, 0.6630, 0.7025, 0.2984, 0.5185, 0.5494,
+    0.5278, 0.5596, 0.6476, 0.6860, 0.3087, 0.5323, 0.5645, 0.6521, 0.6909, 0.3138, 0.6527, 0.6915,
+    0.3318,
+    0.8197, 0.8681, 0.8327, 0.8822, 0.8456, 0.8961, 0.8628, 0.9147, 0.8799, 0.9333, 0.8799, 0.9334,
+    0.8182, 0.8150, 0.8559, 0.8156, 0.8565, 0.8210, 0.8622, 0.8290, 0.8706, 0.8449, 0.8878, 0.8144,
+    0.8006, 0.8405, 0.8060, 0.8462, 0.8139, 0.8546, 0.8299, 0.8718, 0.8104, 0.7871, 0.8261, 0.7950,
+    0.8345, 0.8105, 0.8511, 0.8114, 0.8003, 0.8401, 0.8124, 0.8531, 0.8162, 0.8176, 0.8588, 0.8165,
+    0.5249, 0.6172, 0.6513, 0.6198, 0.6544, 0.6245, 0.6600, 0.6315, 0.6679, 0.6322, 0.6688, 0.3924,
+    0.6661, 0.7043, 0.6673, 0.7059, 0.6743, 0.7139, 0.6919, 0.7333, 0.6929, 0.7344, 0.4919, 0.6328,
+    0.6607, 0.6431, 0.6717, 0.6557, 0.6849, 0.6674, 0.6975, 0.5024, 0.6250, 0.6529, 0.6375, 0.6661,
+    0.6489, 0.6782, 0.5143, 0.6396, 0.6686, 0.6485, 0.6781, 0.5297, 0.6595, 0.6900, 0.5337,
+    0.6003, 0.6351, 0.6026, 0.6379, 0.6072, 0.6432, 0.6142, 0.6512, 0.6150, 0.6521, 0.3570, 0.6548,
+    0.6947, 0.6556, 0.6958, 0.6627, 0.7038, 0.6812, 0.7242, 0.6823, 0.7253, 0.4662, 0.6088, 0.6451,
+    0.6195, 0.6565, 0.6324, 0.6703, 0.6444, 0.6832, 0.4776, 0.6004, 0.6362, 0.6133, 0.6500, 0.6249,
+    0.6625, 0.4905, 0.6152, 0.6524, 0.6244, 0.6623, 0.5071, 0.6355, 0.6747, 0.5113,
+    0.5249, 0.5848, 0.6167, 0.5920, 0.6250, 0.5970, 0.6308, 0.5978, 0.6316, 0.3346, 0.6591, 0.6969,
+    0.6106, 0.6381, 0.6226, 0.6508, 0.6348, 0.6636, 0.6465, 0.6762, 0.3849, 0.6520, 0.6894, 0.6590,
+    0.6974, 0.6766, 0.7168, 0.6776, 0.7179, 0.4904, 0.6206, 0.6482, 0.6328, 0.6611, 0.6442, 0.6732,
+    0.5023, 0.6322, 0.6608, 0.6412, 0.6704, 0.5173, 0.6433, 0.6729, 0.5213,
+    0.5649, 0.5975, 0.5723, 0.6058, 0.5772, 0.6115, 0.5779, 0.6122, 0.2994, 0.6474, 0.6845, 0.5851,
+    0.6204, 0.5975, 0.6335, 0.6100, 0.6469, 0.6220, 0.6598, 0.3487, 0.6390, 0.6780, 0.6461, 0.6860,
+    0.6646, 0.7064, 0.6657, 0.7075, 0.4647, 0.5960, 0.6317, 0.6085, 0.6451, 0.6202, 0.6576, 0.4777,
+    0.6077, 0.6445, 0.6169, 0.6544, 0.4938, 0.6189, 0.6569, 0.4980,
+    0.5250, 0.5572, 0.5874, 0.5658, 0.5971, 0.5665, 0.5979, 0.3308, 0.5578, 0.5836, 0.6585, 0.6966,
+    0.6145, 0.6425, 0.6267, 0.6554, 0.6384, 0.6679, 0.3368, 0.6456, 0.6827, 0.6043, 0.6318, 0.6165,
+    0.6446, 0.6282, 0.6572, 0.3782, 0.6416, 0.6785, 0.6592, 0.6980, 0.6602, 0.6991, 0.4901, 0.6292,
+    0.6575, 0.6381, 0.6671, 0.5051, 0.6371, 0.6663, 0.5091,
+    0.5347, 0.5654, 0.5435, 0.5753, 0.5442, 0.5760, 0.2955, 0.5306, 0.5619, 0.6463, 0.6837, 0.5890,
+    0.6248, 0.6015, 0.6382, 0.6135, 0.6511, 0.3018, 0.6322, 0.6686, 0.5785, 0.6136, 0.5910, 0.6270,
+    0.6030, 0.6399, 0.3412, 0.6272, 0.6657, 0.6457, 0.6861, 0.6468, 0.6872, 0.4646, 0.6048, 0.6417,
+    0.6140, 0.6515, 0.4807, 0.6127, 0.6505, 0.4849,
+    0.5250, 0.5353, 0.5642, 0.5359, 0.5649, 0.3231, 0.5445, 0.5699, 0.5579, 0.5840, 0.6595, 0.6982,
+    0.6150, 0.6435, 0.6268, 0.6560, 0.3291, 0.5495, 0.5752, 0.6467, 0.6843, 0.6049, 0.6328, 0.6167,
+    0.6453, 0.3356, 0.6326, 0.6690, 0.5921, 0.6193, 0.6035, 0.6314, 0.3702, 0.6579, 0.6967, 0.6589,
+    0.6978, 0.4940, 0.6326, 0.6617, 0.4980,
+    0.5104, 0.5397, 0.5111, 0.5404, 0.2874, 0.5166, 0.5473, 0.5305, 0.5620, 0.6470, 0.6849, 0.5891,
+    0.6254, 0.6012, 0.6383, 0.2937, 0.5218, 0.5527, 0.6330, 0.6698, 0.5787, 0.6142, 0.5907, 0.6272,
+    0.3004, 0.6176, 0.6532, 0.5654, 0.6001, 0.5771, 0.6126, 0.3324, 0.6442, 0.6846, 0.6453, 0.6857,
+    0.4686, 0.6078, 0.6454, 0.4728,
+    0.5251, 0.5264, 0.5548, 0.3154, 0.5311, 0.5562, 0.5446, 0.5703, 0.5583, 0.5849, 0.6606, 0.6999,
+    0.6152, 0.6441, 0.3214, 0.5362, 0.5614, 0.5500, 0.5760, 0.6478, 0.6859, 0.6051, 0.6335, 0.3279,
+    0.5393, 0.5646, 0.6337, 0.6707, 0.5919, 0.6196, 0.3372, 0.6379, 0.6753, 0.5935, 0.6213, 0.3630,
+    0.6578, 0.6967, 0.4869,
+    0.5008, 0.5294, 0.2792, 0.5025, 0.5326, 0.5164, 0.5473, 0.5305, 0.5624, 0.6476, 0.6861, 0.5888,
+    0.6255, 0.2855, 0.5077, 0.5381, 0.5219, 0.5532, 0.6336, 0.6710, 0.5784, 0.6144, 0.2922, 0.5108,
+    0.5413, 0.6183, 0.6545, 0.5648, 0.5999, 0.3022, 0.6227, 0.6594, 0.5664, 0.6016, 0.3242, 0.6440,
+    0.6844, 0.4607,
+    0.5251, 0.3116, 0.5248, 0.5498, 0.5386, 0.5643, 0.5524, 0.5789, 0.5662, 0.5935, 0.6744, 0.7149,
+    0.3210, 0.5327, 0.5580, 0.5465, 0.5725, 0.5603, 0.5872, 0.6616, 0.7010, 0.3280, 0.5363, 0.5616,
+    0.5501, 0.5763, 0.6471, 0.6852, 0.3373, 0.5544, 0.5810, 0.6484, 0.6867, 0.3472, 0.6526, 0.6915,
+    0.3683,
+    0.2750, 0.4954, 0.5251, 0.5097, 0.5402, 0.5238, 0.5553, 0.5380, 0.5705, 0.6626, 0.7023, 0.2850,
+    0.5037, 0.5337, 0.5178, 0.5488, 0.5320, 0.5640, 0.6486, 0.6872, 0.2923, 0.5073, 0.5375, 0.5215,
+    0.5526, 0.6327, 0.6701, 0.3022, 0.5259, 0.5576, 0.6340, 0.6716, 0.3127, 0.6386, 0.6766, 0.3297,
+    0.8172, 0.8655, 0.8300, 0.8793, 0.8471, 0.8979, 0.8643, 0.9165, 0.8815, 0.9352, 0.8164, 0.8129,
+    0.8536, 0.8179, 0.8590, 0.8233, 0.8647, 0.8312, 0.8731, 0.8124, 0.7985, 0.8383, 0.8039, 0.8440,
+    0.8118, 0.8524, 0.8128, 0.8058, 0.8461, 0.8137, 0.8545, 0.8139, 0.8189, 0.8602, 0.8187,
+    0.5249, 0.5837, 0.6152, 0.5909, 0.6234, 0.5958, 0.6291, 0.6027, 0.6371, 0.3948, 0.6445, 0.6812,
+    0.6495, 0.6870, 0.6565, 0.6950, 0.6741, 0.7145, 0.4941, 0.6204, 0.6475, 0.6308, 0.6586, 0.6434,
+    0.6719, 0.5054, 0.6282, 0.6562, 0.6408, 0.6695, 0.5175, 0.6429, 0.6721, 0.5330,
+    0.5642, 0.5963, 0.5715, 0.6045, 0.5763, 0.6101, 0.5833, 0.6181, 0.3596, 0.6317, 0.6700, 0.6366,
+    0.6756, 0.6437, 0.6837, 0.6623, 0.7041, 0.4685, 0.5957, 0.6308, 0.6064, 0.6423, 0.6193, 0.6561,
+    0.4808, 0.6035, 0.6396, 0.6165, 0.6534, 0.4939, 0.6184, 0.6559, 0.5106,
+    0.5250, 0.5561, 0.5858, 0.5646, 0.5955, 0.5693, 0.6010, 0.3371, 0.6364, 0.6727, 0.6010, 0.6279,
+    0.6130, 0.6407, 0.6252, 0.6536, 0.3878, 0.6300, 0.6659, 0.6370, 0.6739, 0.6546, 0.6934, 0.4932,
+    0.6234, 0.6511, 0.6357, 0.6641, 0.5053, 0.6347, 0.6633, 0.5203,
+    0.5339, 0.5641, 0.5427, 0.5740, 0.5473, 0.5793, 0.3020, 0.6232, 0.6587, 0.5749, 0.6093, 0.5873,
+    0.6225, 0.5998, 0.6359, 0.3518, 0.6155, 0.6529, 0.6226, 0.6610, 0.6411, 0.6814, 0.4677, 0.5987,
+    0.6347, 0.6114, 0.6482, 0.4808, 0.6101, 0.6471, 0.4970,
+    0.5250, 0.5342, 0.5627, 0.5443, 0.5741, 0.3294, 0.5442, 0.5692, 0.6375, 0.6743, 0.6015, 0.6288,
+    0.6137, 0.6418, 0.3356, 0.6231, 0.6587, 0.5884, 0.6150, 0.6006, 0.6279, 0.3795, 0.6347, 0.6716,
+    0.6523, 0.6910, 0.4941, 0.6297, 0.6582, 0.5092,
+    0.5097, 0.5385, 0.5201, 0.5502, 0.2939, 0.5162, 0.5462, 0.6239, 0.6599, 0.5750, 0.6098, 0.5876,
+    0.6232, 0.3004, 0.6083, 0.6430, 0.5615, 0.5953, 0.5740, 0.6088, 0.3426, 0.6200, 0.6583, 0.6385,
+    0.6787, 0.4687, 0.6047, 0.6415, 0.4849,
+    0.5251, 0.5348, 0.5639, 0.3217, 0.5308, 0.5555, 0.5446, 0.5700, 0.6386, 0.6759, 0.6021, 0.6299,
+    0.3279, 0.5336, 0.5583, 0.6243, 0.6604, 0.5890, 0.6161, 0.3370, 0.6255, 0.6618, 0.5906, 0.6178,
+    0.3718, 0.6511, 0.6898, 0.4981,
+    0.5098, 0.5393, 0.2858, 0.5021, 0.5316, 0.5162, 0.5466, 0.6246, 0.6611, 0.5752, 0.6104, 0.2923,
+    0.5048, 0.5344, 0.6090, 0.6443, 0.5617, 0.5960, 0.3019, 0.6102, 0.6457, 0.5632, 0.5977, 0.3339,
+    0.6371, 0.6773, 0.4728,
+    0.5251, 0.3140, 0.5175, 0.5418, 0.5313, 0.5563, 0.5451, 0.5709, 0.6397, 0.6776, 0.3202, 0.5202,
+    0.5446, 0.5341, 0.5592, 0.6253, 0.6620, 0.3294, 0.5357, 0.5610, 0.6267, 0.6635, 0.3389, 0.6309,
+    0.6683, 0.3647,
+    0.2776, 0.4880, 0.5169, 0.5021, 0.5320, 0.5162, 0.5471, 0.6252, 0.6623, 0.2841, 0.4907, 0.5198,
+    0.5049, 0.5349, 0.6096, 0.6454, 0.2937, 0.5065, 0.5367, 0.6109, 0.6469, 0.3038, 0.6154, 0.6519,
+    0.3259,
+    0.8141, 0.8622, 0.8312, 0.8807, 0.8483, 0.8993, 0.8655, 0.9179, 0.8136, 0.8101, 0.8507, 0.8150,
+    0.8560, 0.8204, 0.8618, 0.8140, 0.8164, 0.8575, 0.8218, 0.8633, 0.8145, 0.8237, 0.8653, 0.8156,
+    0.5250, 0.5550, 0.5842, 0.5634, 0.5938, 0.5680, 0.5992, 0.3974, 0.6153, 0.6498, 0.6205, 0.6558,
+    0.6276, 0.6639, 0.4966, 0.6156, 0.6424, 0.6261, 0.6535, 0.5081, 0.6232, 0.6508, 0.5202,
+    0.5332, 0.5630, 0.5419, 0.5727, 0.5463, 0.5779, 0.3624, 0.6003, 0.6363, 0.6054, 0.6422, 0.6126,
+    0.6503, 0.4712, 0.5904, 0.6251, 0.6012, 0.6366, 0.4836, 0.5980, 0.6335, 0.4968,
+    0.5250, 0.5331, 0.5611, 0.5431, 0.5724, 0.3357, 0.6061, 0.6401, 0.5766, 0.6021, 0.5887, 0.6149,
+    0.3889, 0.6154, 0.6504, 0.6225, 0.6585, 0.4969, 0.6156, 0.6428, 0.5090,
+    0.5089, 0.5373, 0.5193, 0.5489, 0.3004, 0.5906, 0.6236, 0.5489, 0.5812, 0.5614, 0.5945, 0.3528,
+    0.5998, 0.6362, 0.6069, 0.6443, 0.4714, 0.5897, 0.6248, 0.4846,
+    0.5251, 0.5337, 0.5624, 0.3280, 0.5197, 0.5433, 0.6072, 0.6417, 0.5771, 0.6031, 0.3368, 0.6082,
+    0.6429, 0.5784, 0.6045, 0.3807, 0.6200, 0.6559, 0.4979,
+    0.5091, 0.5381, 0.2922, 0.4901, 0.5181, 0.5912, 0.6248, 0.5491, 0.5818, 0.3016, 0.5922, 0.6259,
+    0.5503, 0.5832, 0.3437, 0.6041, 0.6414, 0.4725,
+    0.5251, 0.3203, 0.5064, 0.5296, 0.5202, 0.5442, 0.6083, 0.6434, 0.3291, 0.5215, 0.5457, 0.6093,
+    0.6446, 0.3384, 0.6107, 0.6461, 0.3731,
+    0.2841, 0.4760, 0.5035, 0.4902, 0.5186, 0.5918, 0.6260, 0.2934, 0.4915, 0.5200, 0.5929, 0.6272,
+    0.3032, 0.5942, 0.6287, 0.3351,
+    0.8106, 0.8585, 0.8277, 0.8770, 0.8448, 0.8956, 0.8148, 0.8301, 0.8722, 0.8351, 0.8775, 0.8152,
+    0.8364, 0.8790, 0.8157,
+    0.5250, 0.5318, 0.5592, 0.5418, 0.5704, 0.4000, 0.5930, 0.6257, 0.5980, 0.6315, 0.5020, 0.5996,
+    0.6253, 0.5135,
+    0.5081, 0.5359, 0.5184, 0.5475, 0.3651, 0.5763, 0.6103, 0.5811, 0.6159, 0.4768, 0.5727, 0.6055,
+    0.4892,
+    0.5251, 0.5325, 0.5606, 0.3381, 0.5826, 0.6147, 0.5569, 0.5812, 0.3914, 0.5917, 0.6247, 0.5023,
+    0.5084, 0.5367, 0.3029, 0.5651, 0.5962, 0.5274, 0.5577, 0.3555, 0.5741, 0.6084, 0.4770,
+    0.5251, 0.3304, 0.4977, 0.5200, 0.5837, 0.6163, 0.3394, 0.5847, 0.6175, 0.3834,
+    0.2948, 0.4662, 0.4921, 0.5658, 0.5974, 0.3042, 0.5667, 0.5985, 0.3464,
+    0.8262, 0.8755, 0.8432, 0.8940, 0.8101, 0.8319, 0.8742, 0.8106,
+    0.5251, 0.5325, 0.5606, 0.3861, 0.5405, 0.5689, 0.4973,
+    0.5083, 0.5367, 0.3498, 0.5182, 0.5477, 0.4717,
+    0.5251, 0.3340, 0.5286, 0.5562, 0.3777,
+    0.2984, 0.5054, 0.5318, 0.3404,
+    0.8413, 0.8920, 0.8043,
+    0.5252, 0.3721,
+    0.3342,
+];
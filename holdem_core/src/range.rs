@@ -0,0 +1,468 @@
+//! # Range Module
+//!
+//! Weighted ranges of two-card holdings. A range stores specific combos, each
+//! with a weight in `0.0..=1.0` giving how often the holding is played, so
+//! mixed strategies and card removal are represented exactly.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::range::{top_percent_range, WeightedRange};
+//!
+//! let mut range = WeightedRange::new();
+//! range.add_hand("AKs", 1.0).unwrap();
+//! range.add_hand("QQ", 0.5).unwrap();
+//! assert_eq!(range.len(), 4 + 6);
+//! assert_eq!(range.total_weight(), 4.0 + 3.0);
+//!
+//! let tight = top_percent_range(5.0);
+//! assert!(tight.contains_hand("AA"));
+//! assert!(!tight.contains_hand("72o"));
+//! ```
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::equity::live_cards;
+use crate::errors::PokerError;
+use crate::evaluator::{Evaluator, HandValue};
+use crate::hole_cards::HoleCards;
+use crate::preflop::{hand_combos, EQUITY_VS_RANDOM};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Total number of two-card combinations in a deck
+pub const TOTAL_COMBOS: usize = 1326;
+
+/// Seed for the bucketing equity sampler, fixed so that buckets are reproducible
+const BUCKET_SEED: u64 = 0x42_55_43_4b;
+
+/// A set of hole-card combinations, each with a play frequency
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightedRange {
+    combos: Vec<(HoleCards, f64)>,
+}
+
+impl WeightedRange {
+    /// Creates an empty range
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a combo, replacing its weight if it is already in the range
+    ///
+    /// Non-positive weights remove the combo.
+    pub fn add(&mut self, hole: HoleCards, weight: f64) {
+        match self.position(&hole) {
+            Some(index) if weight <= 0.0 => {
+                self.combos.remove(index);
+            }
+            Some(index) => self.combos[index].1 = weight,
+            None if weight > 0.0 => self.combos.push((hole, weight)),
+            None => {}
+        }
+    }
+
+    /// Adds every combo of a canonical hand such as "AKs", "QQ" or "T9o"
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notation is not a valid canonical hand.
+    pub fn add_hand(&mut self, notation: &str, weight: f64) -> Result<(), PokerError> {
+        for hole in hand_combos(notation)? {
+            self.add(hole, weight);
+        }
+        Ok(())
+    }
+
+    /// Weight of a combo, or `0.0` if it is not in the range
+    pub fn weight(&self, hole: &HoleCards) -> f64 {
+        self.position(hole)
+            .map_or(0.0, |index| self.combos[index].1)
+    }
+
+    /// Returns true if the combo is in the range
+    pub fn contains(&self, hole: &HoleCards) -> bool {
+        self.position(hole).is_some()
+    }
+
+    /// Returns true if any combo of the canonical hand is in the range
+    pub fn contains_hand(&self, notation: &str) -> bool {
+        self.combos
+            .iter()
+            .any(|(hole, _)| hole.notation() == notation)
+    }
+
+    /// The combos in the range with their weights
+    pub fn combos(&self) -> &[(HoleCards, f64)] {
+        &self.combos
+    }
+
+    /// Number of combos in the range, ignoring weights
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    /// Returns true if the range has no combos
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Sum of the weights of all combos
+    pub fn total_weight(&self) -> f64 {
+        self.combos.iter().map(|(_, weight)| weight).sum()
+    }
+
+    /// Weighted combo count left once combos using a dead card are removed
+    ///
+    /// Dead cards are typically the hero's hole cards and the board; the
+    /// result is the normalizing total for range-versus-range work.
+    pub fn combos_remaining(&self, dead: &[Card]) -> f64 {
+        self.combos
+            .iter()
+            .filter(|(hole, _)| !hole.cards.iter().any(|card| dead.contains(card)))
+            .map(|(_, weight)| weight)
+            .sum()
+    }
+
+    /// Weighted sampler over the combos with positive weight that pass `keep`
+    pub(crate) fn sampler(&self, mut keep: impl FnMut(&HoleCards) -> bool) -> RangeSampler<'_> {
+        let mut combos = Vec::new();
+        let mut cumulative = Vec::new();
+        let mut total_weight = 0.0;
+        for (hole, weight) in &self.combos {
+            if *weight > 0.0 && keep(hole) {
+                total_weight += weight;
+                combos.push(hole);
+                cumulative.push(total_weight);
+            }
+        }
+        RangeSampler { combos, cumulative }
+    }
+
+    fn position(&self, hole: &HoleCards) -> Option<usize> {
+        let key = combo_key(hole);
+        self.combos
+            .iter()
+            .position(|(existing, _)| combo_key(existing) == key)
+    }
+}
+
+/// Draws combos from part of a range in proportion to their weights
+pub(crate) struct RangeSampler<'a> {
+    combos: Vec<&'a HoleCards>,
+    /// Running total of the weights, one entry per combo
+    cumulative: Vec<f64>,
+}
+
+impl<'a> RangeSampler<'a> {
+    /// Returns true if no combo passed the filter
+    pub(crate) fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// A combo picked by weight, or `None` if the sampler is empty
+    pub(crate) fn sample(&self, rng: &mut impl rand::Rng) -> Option<&'a HoleCards> {
+        let total_weight = *self.cumulative.last()?;
+        let pick = rng.random::<f64>() * total_weight;
+        let index = self
+            .cumulative
+            .partition_point(|&weight| weight <= pick)
+            .min(self.combos.len() - 1);
+        Some(self.combos[index])
+    }
+}
+
+/// The strongest `percent` of starting hands, each at full weight
+///
+/// Canonical hands are taken in order of their equity against a random hand
+/// until the range holds `percent` of all 1326 combos. The hand that crosses
+/// the threshold is included whole, so the result can be slightly wider than
+/// requested. Values are clamped to `0.0..=100.0`.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn top_percent_range(percent: f64) -> WeightedRange {
+    let target = percent.clamp(0.0, 100.0) / 100.0 * TOTAL_COMBOS as f64;
+    let mut range = WeightedRange::new();
+    for (notation, _) in EQUITY_VS_RANDOM {
+        if range.len() as f64 >= target {
+            break;
+        }
+        for hole in hand_combos(notation).expect("table holds canonical hands") {
+            range.add(hole, 1.0);
+        }
+    }
+    range
+}
+
+/// Evaluates every combo of `range` on the board, strongest first
+///
+/// Combos that share a card with the board are impossible and left out.
+/// Equally strong holdings keep their order from `range`. Before the flop no
+/// hand is made yet, so the result is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::range::{rank_range_on_board, top_percent_range};
+/// use holdem_core::{Board, HandRank, HoleCards};
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Kc 7d 2s").unwrap();
+/// let range: Vec<HoleCards> = top_percent_range(10.0)
+///     .combos()
+///     .iter()
+///     .map(|(hole, _)| *hole)
+///     .collect();
+///
+/// let ranked = rank_range_on_board(&range, &board);
+/// assert_eq!(ranked[0].1.rank, HandRank::ThreeOfAKind);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn rank_range_on_board(range: &[HoleCards], board: &Board) -> Vec<(HoleCards, HandValue)> {
+    let board_cards = board.visible_cards();
+    if board_cards.len() < 3 {
+        return Vec::new();
+    }
+
+    let evaluator = Evaluator::instance();
+    let mut cards = board_cards.to_vec();
+    cards.extend_from_slice(&board_cards[..2]);
+    let hole_start = board_cards.len();
+
+    let mut ranked: Vec<(HoleCards, HandValue)> = range
+        .iter()
+        .filter(|hole| !hole.cards.iter().any(|card| board_cards.contains(card)))
+        .map(|hole| {
+            cards[hole_start..].copy_from_slice(&hole.cards);
+            (*hole, evaluator.evaluate_cards(&cards))
+        })
+        .collect();
+    ranked.sort_by(|(_, a), (_, b)| b.cmp(a));
+    ranked
+}
+
+/// Partitions `range` into equal-width buckets of equity against a random hand
+///
+/// Each combo's equity against one random opponent is estimated from
+/// `iterations` sampled (opponent holding, runout) pairs, at least one, and
+/// bucket `i` holds the combos with equity in `[i / n, (i + 1) / n)`, the
+/// last bucket also taking equity `1.0`. Combos that share a card with the
+/// board are left out. Returns no buckets when `num_buckets` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::range::{bucket_hands, top_percent_range};
+/// use holdem_core::{Board, HoleCards};
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Kc 7d 2s").unwrap();
+/// let range: Vec<HoleCards> = top_percent_range(20.0)
+///     .combos()
+///     .iter()
+///     .map(|(hole, _)| *hole)
+///     .collect();
+///
+/// let buckets = bucket_hands(&range, &board, 10, 500);
+/// assert_eq!(buckets.len(), 10);
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn bucket_hands(
+    range: &[HoleCards],
+    board: &Board,
+    num_buckets: usize,
+    iterations: usize,
+) -> Vec<Vec<HoleCards>> {
+    let mut buckets = vec![Vec::new(); num_buckets];
+    if num_buckets == 0 {
+        return buckets;
+    }
+
+    let evaluator = Evaluator::instance();
+    let board_cards = board.visible_cards();
+    let to_come = 5 - board_cards.len();
+    let iterations = iterations.max(1);
+    let mut rng = StdRng::seed_from_u64(BUCKET_SEED);
+
+    for hole in range {
+        if hole.cards.iter().any(|card| board_cards.contains(card)) {
+            continue;
+        }
+
+        let mut live: Vec<Card> = live_cards(&hole.cards)
+            .into_iter()
+            .filter(|card| !board_cards.contains(card))
+            .collect();
+
+        let mut points = 0u64;
+        for _ in 0..iterations {
+            let (drawn, _) = live.partial_shuffle(&mut rng, 2 + to_come);
+            let mut ours = hole.cards.to_vec();
+            let mut theirs = drawn[..2].to_vec();
+            for cards in [&mut ours, &mut theirs] {
+                cards.extend_from_slice(board_cards);
+                cards.extend_from_slice(&drawn[2..]);
+            }
+            // Two points for a win, one for a tie
+            points += match evaluator
+                .evaluate_cards(&ours)
+                .cmp(&evaluator.evaluate_cards(&theirs))
+            {
+                Ordering::Greater => 2,
+                Ordering::Equal => 1,
+                Ordering::Less => 0,
+            };
+        }
+
+        let equity = points as f64 / (2 * iterations) as f64;
+        let bucket = ((equity * num_buckets as f64) as usize).min(num_buckets - 1);
+        buckets[bucket].push(*hole);
+    }
+    buckets
+}
+
+/// Order-independent identity of a combo
+fn combo_key(hole: &HoleCards) -> (Card, Card) {
+    let [a, b] = hole.cards;
+    if (a.rank, a.suit) >= (b.rank, b.suit) {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_add_and_weights() {
+        let mut range = WeightedRange::new();
+        range.add_hand("AKo", 0.5).unwrap();
+        assert_eq!(range.len(), 12);
+        assert_eq!(range.total_weight(), 6.0);
+
+        let combo =
+            HoleCards::new(Card::from_str("Kd").unwrap(), Card::from_str("Ah").unwrap()).unwrap();
+        assert_eq!(range.weight(&combo), 0.5);
+
+        range.add(combo, 1.0);
+        assert_eq!(range.len(), 12);
+        assert_eq!(range.weight(&combo), 1.0);
+
+        range.add(combo, 0.0);
+        assert!(!range.contains(&combo));
+        assert_eq!(range.len(), 11);
+
+        assert!(range.add_hand("AKx", 1.0).is_err());
+    }
+
+    #[test]
+    fn test_combos_remaining_after_card_removal() {
+        let mut range = WeightedRange::new();
+        range.add_hand("AA", 1.0).unwrap();
+        range.add_hand("AKs", 0.5).unwrap();
+        range.add_hand("QQ", 1.0).unwrap();
+        assert_eq!(range.combos_remaining(&[]), 6.0 + 2.0 + 6.0);
+
+        // An ace on the board leaves three AA combos and three AKs combos
+        let ace = Card::from_str("As").unwrap();
+        assert_eq!(range.combos_remaining(&[ace]), 3.0 + 1.5 + 6.0);
+
+        let dead = [ace, Card::from_str("Ah").unwrap()];
+        assert_eq!(range.combos_remaining(&dead), 1.0 + 1.0 + 6.0);
+    }
+
+    #[test]
+    fn test_top_percent_range_full() {
+        let range = top_percent_range(100.0);
+        assert_eq!(range.len(), TOTAL_COMBOS);
+
+        let hands: HashSet<String> = range.combos().iter().map(|(h, _)| h.notation()).collect();
+        assert_eq!(hands.len(), 169);
+    }
+
+    #[test]
+    fn test_rank_range_on_board() {
+        let board = Board::from_str("Ah Kh 7h 7c 2d").unwrap();
+        let range: Vec<HoleCards> = top_percent_range(100.0)
+            .combos()
+            .iter()
+            .map(|(hole, _)| *hole)
+            .collect();
+
+        let ranked = rank_range_on_board(&range, &board);
+        let (_, nuts) = board.nuts().unwrap();
+        assert_eq!(ranked[0].1, nuts);
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        // Combos holding any of the five board cards are dropped
+        assert_eq!(ranked.len(), TOTAL_COMBOS - 5 * 51 + 10);
+        assert!(ranked.iter().all(|(hole, _)| hole
+            .cards
+            .iter()
+            .all(|card| !board.visible_cards().contains(card))));
+
+        assert!(rank_range_on_board(&range, &Board::new()).is_empty());
+    }
+
+    #[test]
+    fn test_bucket_hands_partitions_range() {
+        let board = Board::from_str("Ah Kh 7h 7c 2d").unwrap();
+        let range: Vec<HoleCards> = ["77", "QJs", "T9o", "32o", "AA"]
+            .iter()
+            .flat_map(|notation| hand_combos(notation).unwrap())
+            .collect();
+        let playable: Vec<HoleCards> = range
+            .iter()
+            .filter(|hole| {
+                !hole
+                    .cards
+                    .iter()
+                    .any(|card| board.visible_cards().contains(card))
+            })
+            .copied()
+            .collect();
+
+        let buckets = bucket_hands(&range, &board, 5, 200);
+        assert_eq!(buckets.len(), 5);
+
+        let mut bucketed: Vec<(Card, Card)> = buckets.iter().flatten().map(combo_key).collect();
+        let mut expected: Vec<(Card, Card)> = playable.iter().map(combo_key).collect();
+        bucketed.sort();
+        expected.sort();
+        assert_eq!(bucketed, expected);
+
+        // The only remaining sevens make quads, the nuts on this board
+        let quads =
+            HoleCards::new(Card::from_str("7s").unwrap(), Card::from_str("7d").unwrap()).unwrap();
+        assert!(buckets[4]
+            .iter()
+            .any(|hole| combo_key(hole) == combo_key(&quads)));
+        assert!(bucket_hands(&range, &board, 0, 200).is_empty());
+    }
+
+    #[test]
+    fn test_top_percent_range_small() {
+        let range = top_percent_range(3.0);
+        assert!(range.contains_hand("AA"));
+        assert!(range.contains_hand("KK"));
+        assert!(!range.contains_hand("72o"));
+        assert!(range.len() as f64 >= 0.03 * TOTAL_COMBOS as f64);
+
+        assert!(top_percent_range(0.0).is_empty());
+    }
+}
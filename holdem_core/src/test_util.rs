@@ -0,0 +1,14 @@
+//! Card and hole-card shorthands shared by unit tests
+
+use crate::{Card, HoleCards};
+use std::str::FromStr;
+
+/// Parse a single card such as `"As"`, panicking on bad notation
+pub(crate) fn card(s: &str) -> Card {
+    Card::from_str(s).unwrap()
+}
+
+/// Build hole cards from two card notations, panicking if they are invalid
+pub(crate) fn hole(first: &str, second: &str) -> HoleCards {
+    HoleCards::new(card(first), card(second)).unwrap()
+}
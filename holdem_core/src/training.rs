@@ -0,0 +1,211 @@
+//! # Training Data Module
+//!
+//! Generates random, labelled Texas Hold'em scenarios for machine learning
+//! datasets. Each sample deals hole cards and a complete board from a fresh
+//! shuffled deck, evaluates the resulting 7-card hand, and labels it with the
+//! fraction of all 7-card hands it beats.
+//!
+//! Quiz helpers such as [`weakest_winning_hand`] answer teaching questions
+//! like "what is the thinnest holding that still value bets here?".
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use holdem_core::training::generate_training_batch;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+//! let samples = generate_training_batch(100, &mut rng);
+//! assert_eq!(samples.len(), 100);
+//! ```
+
+use crate::card::Card;
+use crate::equity::for_each_runout;
+use crate::evaluator::{Evaluator, HandValue};
+use crate::{Board, Deck, HoleCards};
+use serde::{Deserialize, Serialize};
+
+/// A single labelled training example
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrainingSample {
+    /// The player's hole cards
+    pub hole: HoleCards,
+    /// A complete five-card board
+    pub board: Board,
+    /// Value of the best five cards from hole cards and board
+    pub hand_value: HandValue,
+    /// Fraction of all 7-card hands weaker than `hand_value`, in `[0, 1]`
+    pub rank_percentile: f64,
+}
+
+/// Deals and labels one random hand
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::training::generate_training_hand;
+/// use holdem_core::Street;
+///
+/// let sample = generate_training_hand(&mut rand::rng());
+/// assert_eq!(sample.board.street(), Street::River);
+/// assert!((0.0..=1.0).contains(&sample.rank_percentile));
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic; a fresh deck always holds enough cards.
+pub fn generate_training_hand(rng: &mut impl rand::Rng) -> TrainingSample {
+    let evaluator = Evaluator::instance();
+    deal_sample(&evaluator, rng)
+}
+
+/// Deals and labels `n` random hands
+///
+/// Each sample comes from its own freshly shuffled deck.
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn generate_training_batch(n: usize, rng: &mut impl rand::Rng) -> Vec<TrainingSample> {
+    let evaluator = Evaluator::instance();
+    (0..n).map(|_| deal_sample(&evaluator, rng)).collect()
+}
+
+/// Finds the weakest holding that still beats `villain` on the board
+///
+/// Every combo of cards unseen by the board and the villain is evaluated
+/// with the visible board; the lowest value strictly above the villain's
+/// wins, and among equally strong holdings the first found is returned.
+/// Returns `None` if no holding beats the villain, before the flop, or when
+/// the villain's cards collide with the board.
+///
+/// # Examples
+///
+/// ```rust
+/// use holdem_core::training::weakest_winning_hand;
+/// use holdem_core::{Board, Card, HoleCards};
+/// use std::str::FromStr;
+///
+/// let board = Board::from_str("Ac 9d 7s 4h 2c").unwrap();
+/// let villain = HoleCards::new(Card::from_str("Ad").unwrap(), Card::from_str("Ks").unwrap()).unwrap();
+/// let hero = weakest_winning_hand(&board, &villain).unwrap();
+/// assert_eq!(hero.notation()[..2], *"42");
+/// ```
+///
+/// # Panics
+///
+/// This function does not panic.
+pub fn weakest_winning_hand(board: &Board, villain: &HoleCards) -> Option<HoleCards> {
+    let board_cards = board.visible_cards();
+    if board_cards.len() < 3 || villain.cards.iter().any(|card| board_cards.contains(card)) {
+        return None;
+    }
+
+    let evaluator = Evaluator::instance();
+    let mut cards = board_cards.to_vec();
+    cards.extend_from_slice(&villain.cards);
+    let villain_value = evaluator.evaluate_cards(&cards);
+
+    let mut dead = board_cards.to_vec();
+    dead.extend_from_slice(&villain.cards);
+    let hole_start = board_cards.len();
+
+    let mut weakest: Option<([Card; 2], HandValue)> = None;
+    for_each_runout(&dead, 2, |hole| {
+        cards[hole_start..].copy_from_slice(hole);
+        let value = evaluator.evaluate_cards(&cards);
+        if value > villain_value && weakest.is_none_or(|(_, current)| value < current) {
+            weakest = Some(([hole[0], hole[1]], value));
+        }
+    });
+
+    weakest.map(|([first, second], _)| {
+        HoleCards::new(first, second).expect("runout cards are distinct")
+    })
+}
+
+/// Deals and labels one hand using an existing evaluator
+fn deal_sample(evaluator: &Evaluator, rng: &mut impl rand::Rng) -> TrainingSample {
+    let mut deck = Deck::new();
+    deck.shuffle(rng);
+
+    let hole_cards = deck.deal(2);
+    let hole = HoleCards::new(hole_cards[0], hole_cards[1])
+        .expect("cards dealt from one deck are distinct");
+    let board = deck
+        .deal_full_board()
+        .expect("a fresh deck holds a full board");
+
+    let mut cards = hole.cards.to_vec();
+    cards.extend_from_slice(board.visible_cards());
+    let hand_value = evaluator.evaluate_cards(&cards);
+
+    TrainingSample {
+        hole,
+        board,
+        rank_percentile: evaluator.rank_percentile_7card(hand_value),
+        hand_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::HandRank;
+    use crate::test_util::hole;
+    use crate::Street;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_training_batch_is_valid() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let samples = generate_training_batch(1000, &mut rng);
+        assert_eq!(samples.len(), 1000);
+
+        let evaluator = Evaluator::instance();
+        for sample in &samples {
+            assert_eq!(sample.board.street(), Street::River);
+
+            let mut seen = HashSet::new();
+            for card in sample.hole.cards.iter().chain(sample.board.visible_cards()) {
+                assert!(seen.insert(*card), "Duplicate card {} in sample", card);
+            }
+            assert_eq!(seen.len(), 7);
+
+            assert!((0.0..=1.0).contains(&sample.rank_percentile));
+            assert_eq!(
+                sample.rank_percentile,
+                evaluator.rank_percentile_7card(sample.hand_value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_weakest_winning_hand_against_top_pair() {
+        let board = Board::from_str("Ac 9d 7s 4h 2c").unwrap();
+        // Top pair, top kicker: no overpair exists and other ace-kings chop
+        let villain = hole("Ad", "Ks");
+        let hero = weakest_winning_hand(&board, &villain).unwrap();
+
+        let mut cards = hero.cards.to_vec();
+        cards.extend_from_slice(board.visible_cards());
+        let value = Evaluator::instance().evaluate_cards(&cards);
+        assert_eq!(value.rank, HandRank::TwoPair);
+        // Fours and deuces is the lowest two pair this board allows
+        assert_eq!(hero.cards.map(|card| card.rank), [2, 0]);
+
+        let nuts = hole("Kh", "Kd");
+        let quads_board = Board::from_str("Ks Kc 7d 7h 2s").unwrap();
+        assert_eq!(weakest_winning_hand(&quads_board, &nuts), None);
+        assert_eq!(weakest_winning_hand(&Board::new(), &villain), None);
+    }
+
+    #[test]
+    fn test_training_hand_is_reproducible() {
+        let first = generate_training_hand(&mut rand::rngs::StdRng::seed_from_u64(3));
+        let second = generate_training_hand(&mut rand::rngs::StdRng::seed_from_u64(3));
+        assert_eq!(first, second);
+    }
+}
@@ -482,3 +482,70 @@ mod integration_properties {
         }
     }
 }
+
+/// Randomized round-trip and bounds properties driven by `proptest`
+#[cfg(test)]
+mod generated_properties {
+    use super::*;
+    use holdem_core::board::Board;
+    use holdem_core::evaluator::evaluator::{perfect_hash_5_cards, FIVE_CARD_TABLE_SIZE};
+    use proptest::prelude::*;
+    use proptest::sample::subsequence;
+
+    /// Any valid card
+    fn card_strategy() -> impl Strategy<Value = Card> {
+        (0..13u8, 0..4u8).prop_map(|(rank, suit)| Card::new(rank, suit).unwrap())
+    }
+
+    /// Between `min` and `max` distinct cards, in random order
+    fn distinct_cards_strategy(min: usize, max: usize) -> impl Strategy<Value = Vec<Card>> {
+        subsequence(Deck::new().cards().to_vec(), min..=max).prop_shuffle()
+    }
+
+    /// Any valid hand of 0 to 7 cards
+    fn hand_strategy() -> impl Strategy<Value = Hand> {
+        distinct_cards_strategy(0, 7).prop_map(|cards| Hand::new(cards).unwrap())
+    }
+
+    /// Any valid board on any street, with cards in deal order
+    fn board_strategy() -> impl Strategy<Value = Board> {
+        prop_oneof![Just(0usize), Just(3), Just(4), Just(5)]
+            .prop_flat_map(|len| distinct_cards_strategy(len, len))
+            .prop_map(|cards| {
+                let mut board = Board::new();
+                if cards.len() >= 3 {
+                    board.deal_flop(cards[..3].to_vec()).unwrap();
+                }
+                if let Some(&turn) = cards.get(3) {
+                    board.deal_turn(turn).unwrap();
+                }
+                if let Some(&river) = cards.get(4) {
+                    board.deal_river(river).unwrap();
+                }
+                board
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn card_display_round_trips(card in card_strategy()) {
+            prop_assert_eq!(Card::from_str(&card.to_string()).unwrap(), card);
+        }
+
+        #[test]
+        fn hand_display_round_trips(hand in hand_strategy()) {
+            prop_assert_eq!(Hand::from_str(&hand.to_string()).unwrap(), hand);
+        }
+
+        #[test]
+        fn board_display_round_trips(board in board_strategy()) {
+            prop_assert_eq!(Board::from_str(&board.to_string()).unwrap(), board);
+        }
+
+        #[test]
+        fn perfect_hash_stays_in_table(cards in distinct_cards_strategy(5, 5)) {
+            let cards = [cards[0], cards[1], cards[2], cards[3], cards[4]];
+            prop_assert!(perfect_hash_5_cards(&cards) < FIVE_CARD_TABLE_SIZE);
+        }
+    }
+}